@@ -1,5 +1,6 @@
 use near_sdk::store::LookupMap;
 use near_sdk::{env, near, require, AccountId, PanicOnDefault};
+use oracle_types::ownership::PendingOwner;
 
 /// Well-known interface names used by the DVM system.
 /// These are string constants that get hashed/used as keys.
@@ -12,6 +13,9 @@ pub mod interface_names {
     pub const SLASHING_LIBRARY: &str = "SlashingLibrary";
 }
 
+/// Delay between `unfreeze_interface` and the interface accepting changes again
+const UNFREEZE_DELAY_NS: u64 = 2 * 24 * 60 * 60 * 1_000_000_000; // 48 hours in nanoseconds
+
 /// Finder - Service discovery registry for DVM contracts.
 ///
 /// Maps interface names (strings) to contract account IDs.
@@ -25,8 +29,14 @@ pub struct Finder {
     /// Contract owner - can update interface implementations
     owner: AccountId,
 
+    /// Account proposed as the next owner, pending its `accept_ownership`
+    pending_owner: PendingOwner,
+
     /// Mapping from interface name to implementation contract address
     interfaces: LookupMap<String, AccountId>,
+
+    /// Frozen interfaces, with the time a scheduled unfreeze takes effect
+    frozen_interfaces: LookupMap<String, Option<u64>>,
 }
 
 /// Event emitted when an interface implementation is changed
@@ -36,6 +46,19 @@ pub struct InterfaceImplementationChanged {
     pub new_implementation: AccountId,
 }
 
+/// Event emitted when an interface is frozen
+#[near(serializers = [json])]
+pub struct InterfaceFrozen {
+    pub interface_name: String,
+}
+
+/// Event emitted when an interface is scheduled to unfreeze
+#[near(serializers = [json])]
+pub struct InterfaceUnfreezeScheduled {
+    pub interface_name: String,
+    pub unfreezes_at_ns: u64,
+}
+
 #[near]
 impl Finder {
     /// Initialize the Finder contract.
@@ -46,7 +69,9 @@ impl Finder {
     pub fn new(owner: AccountId) -> Self {
         Self {
             owner,
+            pending_owner: PendingOwner::default(),
             interfaces: LookupMap::new(b"i"),
+            frozen_interfaces: LookupMap::new(b"f"),
         }
     }
 
@@ -62,6 +87,7 @@ impl Finder {
         implementation_address: AccountId,
     ) {
         self.assert_owner();
+        self.assert_not_frozen(&interface_name);
 
         self.interfaces
             .insert(interface_name.clone(), implementation_address.clone());
@@ -82,6 +108,7 @@ impl Finder {
     /// * `interface_name` - Name of the interface to remove
     pub fn remove_implementation(&mut self, interface_name: String) {
         self.assert_owner();
+        self.assert_not_frozen(&interface_name);
         self.interfaces.remove(&interface_name);
         self.interfaces.flush();
     }
@@ -112,14 +139,75 @@ impl Finder {
         self.interfaces.contains_key(&interface_name)
     }
 
-    /// Transfer ownership to a new account.
+    /// Freeze an interface so its implementation cannot be changed or removed.
+    /// Freezing again cancels a scheduled unfreeze.
+    /// Only the owner can call this method.
+    ///
+    /// # Arguments
+    /// * `interface_name` - Name of the interface to freeze
+    pub fn freeze_interface(&mut self, interface_name: String) {
+        self.assert_owner();
+        self.frozen_interfaces.insert(interface_name.clone(), None);
+
+        let event = InterfaceFrozen { interface_name };
+        let event_json = near_sdk::serde_json::to_string(&event).unwrap();
+        env::log_str(&format!("EVENT_JSON:{{\"standard\":\"finder\",\"version\":\"1.0.0\",\"event\":\"interface_frozen\",\"data\":{}}}", event_json));
+    }
+
+    /// Schedule a frozen interface to accept changes again after a 48 hour timelock.
+    /// Only the owner can call this method.
+    ///
+    /// # Arguments
+    /// * `interface_name` - Name of the frozen interface
+    pub fn unfreeze_interface(&mut self, interface_name: String) {
+        self.assert_owner();
+        let unfreezes_at = self
+            .frozen_interfaces
+            .get_mut(&interface_name)
+            .expect("Interface is not frozen");
+        require!(unfreezes_at.is_none(), "Unfreeze already scheduled");
+        let unfreezes_at_ns = env::block_timestamp() + UNFREEZE_DELAY_NS;
+        *unfreezes_at = Some(unfreezes_at_ns);
+
+        let event = InterfaceUnfreezeScheduled {
+            interface_name,
+            unfreezes_at_ns,
+        };
+        let event_json = near_sdk::serde_json::to_string(&event).unwrap();
+        env::log_str(&format!("EVENT_JSON:{{\"standard\":\"finder\",\"version\":\"1.0.0\",\"event\":\"interface_unfreeze_scheduled\",\"data\":{}}}", event_json));
+    }
+
+    /// Check if an interface is frozen, including while a scheduled unfreeze is pending.
+    ///
+    /// # Arguments
+    /// * `interface_name` - Name of the interface
+    pub fn is_interface_frozen(&self, interface_name: String) -> bool {
+        match self.frozen_interfaces.get(&interface_name) {
+            Some(Some(unfreezes_at)) => env::block_timestamp() < *unfreezes_at,
+            Some(None) => true,
+            None => false,
+        }
+    }
+
+    /// Propose a new owner, who takes over once it calls `accept_ownership`.
     /// Only the current owner can call this method.
     ///
     /// # Arguments
-    /// * `new_owner` - The new owner account
-    pub fn set_owner(&mut self, new_owner: AccountId) {
+    /// * `new_owner` - The proposed owner account
+    pub fn propose_owner(&mut self, new_owner: AccountId) {
         self.assert_owner();
-        self.owner = new_owner;
+        self.pending_owner.propose(new_owner);
+    }
+
+    /// Accept a pending ownership proposal.
+    /// Only the proposed owner can call this method.
+    pub fn accept_ownership(&mut self) {
+        self.pending_owner.accept(&mut self.owner, "finder");
+    }
+
+    /// Get the account proposed as the next owner, if any.
+    pub fn get_pending_owner(&self) -> Option<AccountId> {
+        self.pending_owner.get()
     }
 
     /// Get the current owner.
@@ -129,6 +217,13 @@ impl Finder {
 
     // ==================== Internal ====================
 
+    fn assert_not_frozen(&self, interface_name: &str) {
+        require!(
+            !self.is_interface_frozen(interface_name.to_string()),
+            "Interface is frozen"
+        );
+    }
+
     fn assert_owner(&self) {
         require!(
             env::predecessor_account_id() == self.owner,
@@ -236,11 +331,14 @@ mod tests {
 
         let mut contract = Finder::new(accounts(0));
 
-        contract.set_owner(accounts(1));
-        assert_eq!(contract.get_owner(), accounts(1));
+        contract.propose_owner(accounts(1));
+        assert_eq!(contract.get_owner(), accounts(0));
+        assert_eq!(contract.get_pending_owner(), Some(accounts(1)));
 
-        // New owner can now make changes
+        // New owner can make changes once it accepts
         testing_env!(get_context(accounts(1)).build());
+        contract.accept_ownership();
+        assert_eq!(contract.get_owner(), accounts(1));
         contract.change_implementation_address("Oracle".to_string(), accounts(2));
         assert_eq!(
             contract.get_implementation_address("Oracle".to_string()),
@@ -275,4 +373,92 @@ mod tests {
             accounts(3)
         );
     }
+
+    #[test]
+    #[should_panic(expected = "Only the pending owner can accept ownership")]
+    fn test_accept_ownership_rejects_other_accounts() {
+        testing_env!(get_context(accounts(0)).build());
+        let mut contract = Finder::new(accounts(0));
+        contract.propose_owner(accounts(1));
+
+        testing_env!(get_context(accounts(4)).build());
+        contract.accept_ownership();
+    }
+
+    #[test]
+    #[should_panic(expected = "Interface is frozen")]
+    fn test_frozen_interface_cannot_be_changed() {
+        testing_env!(get_context(accounts(0)).build());
+        let mut contract = Finder::new(accounts(0));
+
+        contract.change_implementation_address(interface_names::ORACLE.to_string(), accounts(1));
+        contract.freeze_interface(interface_names::ORACLE.to_string());
+        assert!(contract.is_interface_frozen(interface_names::ORACLE.to_string()));
+
+        // Other interfaces are unaffected
+        contract.change_implementation_address(interface_names::STORE.to_string(), accounts(2));
+
+        contract.change_implementation_address(interface_names::ORACLE.to_string(), accounts(3));
+    }
+
+    #[test]
+    #[should_panic(expected = "Interface is frozen")]
+    fn test_frozen_interface_cannot_be_removed() {
+        testing_env!(get_context(accounts(0)).build());
+        let mut contract = Finder::new(accounts(0));
+
+        contract.change_implementation_address(interface_names::ORACLE.to_string(), accounts(1));
+        contract.freeze_interface(interface_names::ORACLE.to_string());
+        contract.remove_implementation(interface_names::ORACLE.to_string());
+    }
+
+    #[test]
+    fn test_unfreeze_interface_waits_for_timelock() {
+        testing_env!(get_context(accounts(0)).block_timestamp(1).build());
+        let mut contract = Finder::new(accounts(0));
+        let oracle = interface_names::ORACLE.to_string();
+
+        contract.change_implementation_address(oracle.clone(), accounts(1));
+        contract.freeze_interface(oracle.clone());
+        contract.unfreeze_interface(oracle.clone());
+
+        // Still frozen until the timelock elapses
+        testing_env!(get_context(accounts(0))
+            .block_timestamp(UNFREEZE_DELAY_NS)
+            .build());
+        assert!(contract.is_interface_frozen(oracle.clone()));
+
+        testing_env!(get_context(accounts(0))
+            .block_timestamp(1 + UNFREEZE_DELAY_NS)
+            .build());
+        assert!(!contract.is_interface_frozen(oracle.clone()));
+        contract.change_implementation_address(oracle.clone(), accounts(2));
+        assert_eq!(contract.get_implementation_address(oracle), accounts(2));
+    }
+
+    #[test]
+    fn test_freeze_cancels_scheduled_unfreeze() {
+        testing_env!(get_context(accounts(0)).build());
+        let mut contract = Finder::new(accounts(0));
+        let oracle = interface_names::ORACLE.to_string();
+
+        contract.freeze_interface(oracle.clone());
+        contract.unfreeze_interface(oracle.clone());
+        contract.freeze_interface(oracle.clone());
+
+        testing_env!(get_context(accounts(0))
+            .block_timestamp(UNFREEZE_DELAY_NS)
+            .build());
+        assert!(contract.is_interface_frozen(oracle));
+    }
+
+    #[test]
+    #[should_panic(expected = "Only owner can call this method")]
+    fn test_freeze_interface_unauthorized() {
+        testing_env!(get_context(accounts(0)).build());
+        let mut contract = Finder::new(accounts(0));
+
+        testing_env!(get_context(accounts(1)).build());
+        contract.freeze_interface(interface_names::ORACLE.to_string());
+    }
 }
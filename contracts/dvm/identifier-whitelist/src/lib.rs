@@ -1,5 +1,6 @@
 use near_sdk::store::LookupSet;
 use near_sdk::{env, near, require, PanicOnDefault};
+use oracle_types::ownership::PendingOwner;
 
 /// IdentifierWhitelist - Manages approved price identifiers for the oracle.
 ///
@@ -14,6 +15,9 @@ pub struct IdentifierWhitelist {
     /// Contract owner - can add/remove identifiers
     owner: near_sdk::AccountId,
 
+    /// Account proposed as the next owner, pending its `accept_ownership`
+    pending_owner: PendingOwner,
+
     /// Set of approved identifiers
     supported_identifiers: LookupSet<String>,
 }
@@ -40,6 +44,7 @@ impl IdentifierWhitelist {
     pub fn new(owner: near_sdk::AccountId) -> Self {
         Self {
             owner,
+            pending_owner: PendingOwner::default(),
             supported_identifiers: LookupSet::new(b"i"),
         }
     }
@@ -98,14 +103,26 @@ impl IdentifierWhitelist {
 
     // ==================== Role Management ====================
 
-    /// Transfer ownership to a new account.
+    /// Propose a new owner, who takes over once it calls `accept_ownership`.
     /// Only the current owner can call this method.
     ///
     /// # Arguments
-    /// * `new_owner` - The new owner account
-    pub fn set_owner(&mut self, new_owner: near_sdk::AccountId) {
+    /// * `new_owner` - The proposed owner account
+    pub fn propose_owner(&mut self, new_owner: near_sdk::AccountId) {
         self.assert_owner();
-        self.owner = new_owner;
+        self.pending_owner.propose(new_owner);
+    }
+
+    /// Accept a pending ownership proposal.
+    /// Only the proposed owner can call this method.
+    pub fn accept_ownership(&mut self) {
+        self.pending_owner
+            .accept(&mut self.owner, "identifier_whitelist");
+    }
+
+    /// Get the account proposed as the next owner, if any.
+    pub fn get_pending_owner(&self) -> Option<near_sdk::AccountId> {
+        self.pending_owner.get()
     }
 
     /// Get the current owner.
@@ -258,12 +275,26 @@ mod tests {
 
         let mut contract = IdentifierWhitelist::new(accounts(0));
 
-        contract.set_owner(accounts(1));
-        assert_eq!(contract.get_owner(), accounts(1));
+        contract.propose_owner(accounts(1));
+        assert_eq!(contract.get_owner(), accounts(0));
+        assert_eq!(contract.get_pending_owner(), Some(accounts(1)));
 
-        // New owner can add identifiers
+        // New owner can add identifiers once it accepts
         testing_env!(get_context(accounts(1)).build());
+        contract.accept_ownership();
+        assert_eq!(contract.get_owner(), accounts(1));
         contract.add_supported_identifier("YES_OR_NO_QUERY".to_string());
         assert!(contract.is_identifier_supported("YES_OR_NO_QUERY".to_string()));
     }
+
+    #[test]
+    #[should_panic(expected = "Only the pending owner can accept ownership")]
+    fn test_accept_ownership_rejects_other_accounts() {
+        testing_env!(get_context(accounts(0)).build());
+        let mut contract = IdentifierWhitelist::new(accounts(0));
+        contract.propose_owner(accounts(1));
+
+        testing_env!(get_context(accounts(4)).build());
+        contract.accept_ownership();
+    }
 }
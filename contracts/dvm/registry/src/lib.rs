@@ -1,5 +1,14 @@
 use near_sdk::store::LookupSet;
-use near_sdk::{env, near, require, AccountId, PanicOnDefault};
+use near_sdk::{
+    env, near, require, AccountId, Gas, NearToken, PanicOnDefault, Promise, PromiseError,
+};
+use oracle_types::ownership::PendingOwner;
+
+/// Gas for the `is_oracle_integrator` probe made when verifying a registration
+const GAS_FOR_VERIFY_PROBE: Gas = Gas::from_tgas(5);
+
+/// Gas for the callback completing a verified registration
+const GAS_FOR_VERIFY_CALLBACK: Gas = Gas::from_tgas(5);
 
 /// Registry - Manages contracts allowed to interact with the oracle.
 ///
@@ -14,8 +23,14 @@ pub struct Registry {
     /// Contract owner - can register/unregister contracts
     owner: AccountId,
 
+    /// Account proposed as the next owner, pending its `accept_ownership`
+    pending_owner: PendingOwner,
+
     /// Set of registered contract addresses
     registered_contracts: LookupSet<AccountId>,
+
+    /// Whether `register_contract` first checks the target answers `is_oracle_integrator`
+    verify_on_register: bool,
 }
 
 /// Event emitted when a contract is registered
@@ -31,6 +46,12 @@ pub struct ContractUnregistered {
     pub contract_address: AccountId,
 }
 
+/// Event emitted when a contract fails registration verification
+#[near(serializers = [json])]
+pub struct ContractVerificationFailed {
+    pub contract_address: AccountId,
+}
+
 #[near]
 impl Registry {
     /// Initialize the Registry contract.
@@ -41,7 +62,9 @@ impl Registry {
     pub fn new(owner: AccountId) -> Self {
         Self {
             owner,
+            pending_owner: PendingOwner::default(),
             registered_contracts: LookupSet::new(b"r"),
+            verify_on_register: false,
         }
     }
 
@@ -50,23 +73,62 @@ impl Registry {
     /// Register a contract with the oracle.
     /// Only the owner can call this method.
     ///
+    /// With `verify_on_register` set, the contract is only registered once it answers
+    /// `is_oracle_integrator` with `true`; otherwise a `contract_verification_failed`
+    /// event is emitted and nothing is registered.
+    ///
     /// # Arguments
     /// * `contract_address` - The contract address to register
     pub fn register_contract(&mut self, contract_address: AccountId) {
         self.assert_owner();
 
-        if self.registered_contracts.insert(contract_address.clone()) {
-            // Emit event only if it was newly registered
-            let event = ContractRegistered {
-                contract_address,
-                creator: env::predecessor_account_id(),
-            };
+        if !self.verify_on_register {
+            self.internal_register(contract_address, env::predecessor_account_id());
+            return;
+        }
+
+        let _ = Promise::new(contract_address.clone())
+            .function_call(
+                "is_oracle_integrator".to_string(),
+                b"{}".to_vec(),
+                NearToken::from_yoctonear(0),
+                GAS_FOR_VERIFY_PROBE,
+            )
+            .then(
+                Promise::new(env::current_account_id()).function_call(
+                    "on_contract_verified".to_string(),
+                    near_sdk::serde_json::json!({
+                        "contract_address": contract_address,
+                        "creator": env::predecessor_account_id(),
+                    })
+                    .to_string()
+                    .into_bytes(),
+                    NearToken::from_yoctonear(0),
+                    GAS_FOR_VERIFY_CALLBACK,
+                ),
+            );
+    }
+
+    /// Callback after the `is_oracle_integrator` probe of `register_contract`.
+    /// Registers the contract only if it answered `true`.
+    #[private]
+    pub fn on_contract_verified(
+        &mut self,
+        contract_address: AccountId,
+        creator: AccountId,
+        #[callback_result] is_integrator: Result<bool, PromiseError>,
+    ) -> bool {
+        if !matches!(is_integrator, Ok(true)) {
+            let event = ContractVerificationFailed { contract_address };
             let event_json = near_sdk::serde_json::to_string(&event).unwrap();
             env::log_str(&format!(
-                "EVENT_JSON:{{\"standard\":\"registry\",\"version\":\"1.0.0\",\"event\":\"contract_registered\",\"data\":{}}}",
+                "EVENT_JSON:{{\"standard\":\"registry\",\"version\":\"1.0.0\",\"event\":\"contract_verification_failed\",\"data\":{}}}",
                 event_json
             ));
+            return false;
         }
+        self.internal_register(contract_address, creator);
+        true
     }
 
     /// Unregister a contract from the oracle.
@@ -99,16 +161,42 @@ impl Registry {
         self.registered_contracts.contains(&contract_address)
     }
 
+    /// Set whether `register_contract` verifies the target before registering it.
+    /// Only the owner can call this method.
+    ///
+    /// # Arguments
+    /// * `verify_on_register` - Require an `is_oracle_integrator` answer of `true`
+    pub fn set_verify_on_register(&mut self, verify_on_register: bool) {
+        self.assert_owner();
+        self.verify_on_register = verify_on_register;
+    }
+
+    /// Get whether `register_contract` verifies the target before registering it.
+    pub fn get_verify_on_register(&self) -> bool {
+        self.verify_on_register
+    }
+
     // ==================== Role Management ====================
 
-    /// Transfer ownership to a new account.
+    /// Propose a new owner, who takes over once it calls `accept_ownership`.
     /// Only the current owner can call this method.
     ///
     /// # Arguments
-    /// * `new_owner` - The new owner account
-    pub fn set_owner(&mut self, new_owner: AccountId) {
+    /// * `new_owner` - The proposed owner account
+    pub fn propose_owner(&mut self, new_owner: AccountId) {
         self.assert_owner();
-        self.owner = new_owner;
+        self.pending_owner.propose(new_owner);
+    }
+
+    /// Accept a pending ownership proposal.
+    /// Only the proposed owner can call this method.
+    pub fn accept_ownership(&mut self) {
+        self.pending_owner.accept(&mut self.owner, "registry");
+    }
+
+    /// Get the account proposed as the next owner, if any.
+    pub fn get_pending_owner(&self) -> Option<AccountId> {
+        self.pending_owner.get()
     }
 
     /// Get the current owner.
@@ -118,6 +206,21 @@ impl Registry {
 
     // ==================== Internal ====================
 
+    fn internal_register(&mut self, contract_address: AccountId, creator: AccountId) {
+        if self.registered_contracts.insert(contract_address.clone()) {
+            // Emit event only if it was newly registered
+            let event = ContractRegistered {
+                contract_address,
+                creator,
+            };
+            let event_json = near_sdk::serde_json::to_string(&event).unwrap();
+            env::log_str(&format!(
+                "EVENT_JSON:{{\"standard\":\"registry\",\"version\":\"1.0.0\",\"event\":\"contract_registered\",\"data\":{}}}",
+                event_json
+            ));
+        }
+    }
+
     fn assert_owner(&self) {
         require!(
             env::predecessor_account_id() == self.owner,
@@ -254,11 +357,14 @@ mod tests {
 
         let mut contract = Registry::new(accounts(0));
 
-        contract.set_owner(accounts(1));
-        assert_eq!(contract.get_owner(), accounts(1));
+        contract.propose_owner(accounts(1));
+        assert_eq!(contract.get_owner(), accounts(0));
+        assert_eq!(contract.get_pending_owner(), Some(accounts(1)));
 
-        // New owner can register contracts
+        // New owner can register contracts once it accepts
         testing_env!(get_context(accounts(1)).build());
+        contract.accept_ownership();
+        assert_eq!(contract.get_owner(), accounts(1));
         contract.register_contract(accounts(2));
         assert!(contract.is_contract_registered(accounts(2)));
     }
@@ -280,4 +386,46 @@ mod tests {
         contract.register_contract(contract_addr.clone());
         assert!(contract.is_contract_registered(contract_addr));
     }
+
+    #[test]
+    #[should_panic(expected = "Only the pending owner can accept ownership")]
+    fn test_accept_ownership_rejects_other_accounts() {
+        testing_env!(get_context(accounts(0)).build());
+        let mut contract = Registry::new(accounts(0));
+        contract.propose_owner(accounts(1));
+
+        testing_env!(get_context(accounts(4)).build());
+        contract.accept_ownership();
+    }
+
+    #[test]
+    fn test_verify_on_register_defers_registration() {
+        testing_env!(get_context(accounts(0)).build());
+        let mut contract = Registry::new(accounts(0));
+        assert!(!contract.get_verify_on_register());
+        contract.set_verify_on_register(true);
+
+        // Registration waits for the probe's answer
+        contract.register_contract(accounts(1));
+        assert!(!contract.is_contract_registered(accounts(1)));
+
+        assert!(!contract.on_contract_verified(accounts(1), accounts(0), Ok(false)));
+        assert!(!contract.is_contract_registered(accounts(1)));
+        assert!(near_sdk::test_utils::get_logs()
+            .iter()
+            .any(|log| log.contains("contract_verification_failed")));
+
+        assert!(contract.on_contract_verified(accounts(1), accounts(0), Ok(true)));
+        assert!(contract.is_contract_registered(accounts(1)));
+    }
+
+    #[test]
+    #[should_panic(expected = "Only owner can call this method")]
+    fn test_set_verify_on_register_unauthorized() {
+        testing_env!(get_context(accounts(0)).build());
+        let mut contract = Registry::new(accounts(0));
+
+        testing_env!(get_context(accounts(1)).build());
+        contract.set_verify_on_register(true);
+    }
 }
@@ -75,3 +75,54 @@ async fn test_basics_on(contract_wasm: &[u8]) -> Result<(), Box<dyn std::error::
 
     Ok(())
 }
+
+#[tokio::test]
+async fn test_verify_on_register_rejects_non_integrator() -> Result<(), Box<dyn std::error::Error>>
+{
+    let contract_wasm = near_workspaces::compile_project("./").await?;
+    let sandbox = near_workspaces::sandbox().await?;
+    let contract = sandbox.dev_deploy(&contract_wasm).await?;
+
+    let owner = sandbox.dev_create_account().await?;
+    // A plain account with no contract cannot answer `is_oracle_integrator`
+    let non_integrator = sandbox.dev_create_account().await?;
+
+    let outcome = contract
+        .call("new")
+        .args_json(json!({
+            "owner": owner.id()
+        }))
+        .transact()
+        .await?;
+    assert!(outcome.is_success());
+
+    let outcome = owner
+        .call(contract.id(), "set_verify_on_register")
+        .args_json(json!({"verify_on_register": true}))
+        .transact()
+        .await?;
+    assert!(outcome.is_success());
+
+    let outcome = owner
+        .call(contract.id(), "register_contract")
+        .args_json(json!({
+            "contract_address": non_integrator.id()
+        }))
+        .max_gas()
+        .transact()
+        .await?;
+    assert!(outcome.is_success());
+    assert!(outcome
+        .logs()
+        .iter()
+        .any(|log| log.contains("contract_verification_failed")));
+
+    let is_registered: bool = contract
+        .view("is_contract_registered")
+        .args_json(json!({"contract_address": non_integrator.id()}))
+        .await?
+        .json()?;
+    assert!(!is_registered);
+
+    Ok(())
+}
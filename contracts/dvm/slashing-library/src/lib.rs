@@ -1,9 +1,20 @@
 use near_sdk::json_types::U128;
 use near_sdk::{env, near, require, AccountId, PanicOnDefault};
+use oracle_types::ownership::PendingOwner;
 
 /// Basis points denominator (100% = 10000 basis points)
 const BASIS_POINTS_DENOMINATOR: u128 = 10_000;
 
+/// A proposed slashing rate waiting for its timelock to pass
+#[near(serializers = [json, borsh])]
+#[derive(Clone, Debug, PartialEq)]
+pub struct PendingSlashingRate {
+    /// Proposed slashing rate in basis points
+    pub rate: u64,
+    /// Timestamp (nanoseconds) from which the rate applies
+    pub effective_at: u64,
+}
+
 /// SlashingLibrary - Calculates slashing penalties for incorrect votes.
 ///
 /// When a vote resolves in the DVM, voters who voted against the majority
@@ -20,9 +31,21 @@ pub struct SlashingLibrary {
     /// Contract owner - can configure slashing parameters
     owner: AccountId,
 
+    /// Account proposed as the next owner, pending its `accept_ownership`
+    pending_owner: PendingOwner,
+
     /// Base slashing percentage in basis points (e.g., 1000 = 10%)
     /// This is the percentage of wrong voters' stake that gets slashed
     base_slashing_rate: u64,
+
+    /// Delay between proposing a new slashing rate and it taking effect (nanoseconds)
+    rate_change_delay_ns: u64,
+
+    /// Proposed rate change, if any
+    pending_rate: Option<PendingSlashingRate>,
+
+    /// While true, every slashing calculation returns zero
+    paused: bool,
 }
 
 #[near]
@@ -41,7 +64,11 @@ impl SlashingLibrary {
 
         Self {
             owner,
+            pending_owner: PendingOwner::default(),
             base_slashing_rate,
+            rate_change_delay_ns: 0,
+            pending_rate: None,
+            paused: false,
         }
     }
 
@@ -56,10 +83,14 @@ impl SlashingLibrary {
     /// * `wrong_vote_total_stake` - Total stake that voted incorrectly
     ///
     /// # Returns
-    /// The amount to slash from wrong voters
+    /// The amount to slash from wrong voters, or zero while paused
     pub fn calculate_slashing(&self, wrong_vote_total_stake: U128) -> U128 {
+        if self.paused {
+            env::log_str("Slashing is paused; returning zero");
+            return U128(0);
+        }
         let stake = wrong_vote_total_stake.0;
-        let slashing_amount = (stake * self.base_slashing_rate as u128) / BASIS_POINTS_DENOMINATOR;
+        let slashing_amount = (stake * self.current_rate() as u128) / BASIS_POINTS_DENOMINATOR;
         U128(slashing_amount)
     }
 
@@ -74,7 +105,7 @@ impl SlashingLibrary {
     /// * `total_stake_at_snapshot` - Total stake at the time of vote snapshot
     ///
     /// # Returns
-    /// The amount to slash from wrong voters
+    /// The amount to slash from wrong voters, or zero while paused
     pub fn calculate_slashing_with_context(
         &self,
         wrong_vote_total_stake: U128,
@@ -89,7 +120,9 @@ impl SlashingLibrary {
 
     // ==================== Configuration ====================
 
-    /// Set the base slashing rate.
+    /// Set the base slashing rate immediately.
+    /// Only available while no rate change delay is configured; otherwise use
+    /// `propose_base_slashing_rate`.
     /// Only the owner can call this method.
     ///
     /// # Arguments
@@ -97,32 +130,137 @@ impl SlashingLibrary {
     pub fn set_base_slashing_rate(&mut self, new_rate: u64) {
         self.assert_owner();
         require!(
-            new_rate <= BASIS_POINTS_DENOMINATOR as u64,
-            "Slashing rate cannot exceed 100%"
+            self.rate_change_delay_ns == 0,
+            "Rate change timelock active; use propose_base_slashing_rate"
         );
-        self.base_slashing_rate = new_rate;
+        Self::assert_valid_rate(new_rate);
+        self.pending_rate = None;
+        self.activate_rate(new_rate);
+    }
+
+    /// Propose a new base slashing rate that takes effect after `rate_change_delay_ns`.
+    /// Replaces any previously pending proposal.
+    /// Only the owner can call this method.
+    ///
+    /// # Arguments
+    /// * `new_rate` - New slashing rate in basis points (max 10000)
+    pub fn propose_base_slashing_rate(&mut self, new_rate: u64) {
+        self.assert_owner();
+        Self::assert_valid_rate(new_rate);
+        self.apply_pending_rate();
+
+        let effective_at = env::block_timestamp().saturating_add(self.rate_change_delay_ns);
+        self.pending_rate = Some(PendingSlashingRate {
+            rate: new_rate,
+            effective_at,
+        });
 
         env::log_str(&format!(
-            "EVENT_JSON:{{\"standard\":\"slashing_library\",\"version\":\"1.0.0\",\"event\":\"slashing_rate_updated\",\"data\":{{\"new_rate\":{}}}}}",
-            new_rate
+            "EVENT_JSON:{{\"standard\":\"slashing_library\",\"version\":\"1.0.0\",\"event\":\"slashing_rate_proposed\",\"data\":{{\"new_rate\":{},\"effective_at\":{}}}}}",
+            new_rate, effective_at
         ));
     }
 
+    /// Persist the pending rate once its timelock has passed.
+    /// Callable by anyone; `calculate_slashing` already uses the pending rate once effective.
+    ///
+    /// # Returns
+    /// Whether a pending rate was applied
+    pub fn apply_pending_rate(&mut self) -> bool {
+        match self.pending_rate.clone() {
+            Some(pending) if env::block_timestamp() >= pending.effective_at => {
+                self.pending_rate = None;
+                self.activate_rate(pending.rate);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Set the delay applied to proposed slashing rate changes.
+    /// The delay can only be increased, so it cannot be used to bypass the timelock.
+    /// Only the owner can call this method.
+    ///
+    /// # Arguments
+    /// * `delay_ns` - New delay in nanoseconds
+    pub fn set_rate_change_delay(&mut self, delay_ns: u64) {
+        self.assert_owner();
+        require!(
+            delay_ns >= self.rate_change_delay_ns,
+            "Rate change delay can only be increased"
+        );
+        self.rate_change_delay_ns = delay_ns;
+    }
+
+    /// Get the delay applied to proposed slashing rate changes.
+    pub fn get_rate_change_delay(&self) -> u64 {
+        self.rate_change_delay_ns
+    }
+
+    /// Get the proposed slashing rate that has not yet taken effect, if any.
+    pub fn get_pending_rate(&self) -> Option<PendingSlashingRate> {
+        self.pending_rate
+            .clone()
+            .filter(|pending| env::block_timestamp() < pending.effective_at)
+    }
+
     /// Get the current base slashing rate.
     pub fn get_base_slashing_rate(&self) -> u64 {
-        self.base_slashing_rate
+        self.current_rate()
+    }
+
+    /// Pause slashing so every calculation returns zero, e.g. while a misconfiguration
+    /// is investigated.
+    /// Only the owner can call this method.
+    pub fn pause(&mut self) {
+        self.assert_owner();
+        require!(!self.paused, "Slashing already paused");
+        self.paused = true;
+
+        env::log_str(
+            "EVENT_JSON:{\"standard\":\"slashing_library\",\"version\":\"1.0.0\",\"event\":\"slashing_paused\",\"data\":{}}",
+        );
+    }
+
+    /// Resume normal slashing calculation.
+    /// Only the owner can call this method.
+    pub fn resume(&mut self) {
+        self.assert_owner();
+        require!(self.paused, "Slashing not paused");
+        self.paused = false;
+
+        env::log_str(
+            "EVENT_JSON:{\"standard\":\"slashing_library\",\"version\":\"1.0.0\",\"event\":\"slashing_resumed\",\"data\":{}}",
+        );
+    }
+
+    /// Whether slashing calculations are currently paused.
+    pub fn is_paused(&self) -> bool {
+        self.paused
     }
 
     // ==================== Role Management ====================
 
-    /// Transfer ownership to a new account.
+    /// Propose a new owner, who takes over once it calls `accept_ownership`.
     /// Only the current owner can call this method.
     ///
     /// # Arguments
-    /// * `new_owner` - The new owner account
-    pub fn set_owner(&mut self, new_owner: AccountId) {
+    /// * `new_owner` - The proposed owner account
+    pub fn propose_owner(&mut self, new_owner: AccountId) {
         self.assert_owner();
-        self.owner = new_owner;
+        self.pending_owner.propose(new_owner);
+    }
+
+    /// Accept a pending ownership proposal.
+    /// Only the proposed owner can call this method.
+    pub fn accept_ownership(&mut self) {
+        self.pending_owner
+            .accept(&mut self.owner, "slashing_library");
+    }
+
+    /// Get the account proposed as the next owner, if any.
+    pub fn get_pending_owner(&self) -> Option<AccountId> {
+        self.pending_owner.get()
     }
 
     /// Get the current owner.
@@ -132,6 +270,30 @@ impl SlashingLibrary {
 
     // ==================== Internal ====================
 
+    /// Rate in effect now, including a pending rate whose timelock has passed.
+    fn current_rate(&self) -> u64 {
+        match &self.pending_rate {
+            Some(pending) if env::block_timestamp() >= pending.effective_at => pending.rate,
+            _ => self.base_slashing_rate,
+        }
+    }
+
+    fn activate_rate(&mut self, new_rate: u64) {
+        self.base_slashing_rate = new_rate;
+
+        env::log_str(&format!(
+            "EVENT_JSON:{{\"standard\":\"slashing_library\",\"version\":\"1.0.0\",\"event\":\"slashing_rate_updated\",\"data\":{{\"new_rate\":{}}}}}",
+            new_rate
+        ));
+    }
+
+    fn assert_valid_rate(rate: u64) {
+        require!(
+            rate <= BASIS_POINTS_DENOMINATOR as u64,
+            "Slashing rate cannot exceed 100%"
+        );
+    }
+
     fn assert_owner(&self) {
         require!(
             env::predecessor_account_id() == self.owner,
@@ -291,11 +453,14 @@ mod tests {
 
         let mut contract = SlashingLibrary::new(accounts(0), 1000);
 
-        contract.set_owner(accounts(1));
-        assert_eq!(contract.get_owner(), accounts(1));
+        contract.propose_owner(accounts(1));
+        assert_eq!(contract.get_owner(), accounts(0));
+        assert_eq!(contract.get_pending_owner(), Some(accounts(1)));
 
-        // New owner can change rate
+        // New owner can change rate once it accepts
         testing_env!(get_context(accounts(1)).build());
+        contract.accept_ownership();
+        assert_eq!(contract.get_owner(), accounts(1));
         contract.set_base_slashing_rate(2000);
         assert_eq!(contract.get_base_slashing_rate(), 2000);
     }
@@ -311,4 +476,96 @@ mod tests {
         let result = contract.calculate_slashing(U128(1000));
         assert_eq!(result.0, 0);
     }
+
+    #[test]
+    fn test_proposed_rate_applies_after_delay() {
+        testing_env!(get_context(accounts(0)).block_timestamp(100).build());
+        let mut contract = SlashingLibrary::new(accounts(0), 1000);
+        contract.set_rate_change_delay(1_000);
+        contract.propose_base_slashing_rate(3000);
+
+        assert_eq!(
+            contract.get_pending_rate(),
+            Some(PendingSlashingRate {
+                rate: 3000,
+                effective_at: 1_100,
+            })
+        );
+
+        // Old rate still applies before the delay elapses.
+        testing_env!(get_context(accounts(1)).block_timestamp(1_099).build());
+        assert_eq!(contract.calculate_slashing(U128(1000)).0, 100);
+        assert!(!contract.apply_pending_rate());
+
+        // New rate applies lazily once effective, and can be persisted by anyone.
+        testing_env!(get_context(accounts(1)).block_timestamp(1_100).build());
+        assert_eq!(contract.calculate_slashing(U128(1000)).0, 300);
+        assert_eq!(contract.get_pending_rate(), None);
+        assert!(contract.apply_pending_rate());
+        assert_eq!(contract.get_base_slashing_rate(), 3000);
+    }
+
+    #[test]
+    #[should_panic(expected = "Rate change timelock active; use propose_base_slashing_rate")]
+    fn test_set_rate_blocked_by_timelock() {
+        testing_env!(get_context(accounts(0)).build());
+        let mut contract = SlashingLibrary::new(accounts(0), 1000);
+        contract.set_rate_change_delay(1_000);
+        contract.set_base_slashing_rate(2000);
+    }
+
+    #[test]
+    #[should_panic(expected = "Rate change delay can only be increased")]
+    fn test_rate_change_delay_cannot_decrease() {
+        testing_env!(get_context(accounts(0)).build());
+        let mut contract = SlashingLibrary::new(accounts(0), 1000);
+        contract.set_rate_change_delay(1_000);
+        contract.set_rate_change_delay(0);
+    }
+
+    #[test]
+    fn test_pause_returns_zero_until_resumed() {
+        testing_env!(get_context(accounts(0)).build());
+        let mut contract = SlashingLibrary::new(accounts(0), 1000);
+        contract.pause();
+        assert!(contract.is_paused());
+
+        assert_eq!(contract.calculate_slashing(U128(1000)).0, 0);
+        assert_eq!(
+            contract
+                .calculate_slashing_with_context(U128(1000), U128(9000), U128(10000))
+                .0,
+            0
+        );
+        let paused_logs = near_sdk::test_utils::get_logs()
+            .iter()
+            .filter(|log| log.contains("Slashing is paused"))
+            .count();
+        assert_eq!(paused_logs, 2);
+
+        contract.resume();
+        assert!(!contract.is_paused());
+        assert_eq!(contract.calculate_slashing(U128(1000)).0, 100);
+    }
+
+    #[test]
+    #[should_panic(expected = "Only owner can call this method")]
+    fn test_pause_unauthorized() {
+        testing_env!(get_context(accounts(0)).build());
+        let mut contract = SlashingLibrary::new(accounts(0), 1000);
+
+        testing_env!(get_context(accounts(1)).build());
+        contract.pause();
+    }
+
+    #[test]
+    #[should_panic(expected = "Only the pending owner can accept ownership")]
+    fn test_accept_ownership_rejects_other_accounts() {
+        testing_env!(get_context(accounts(0)).build());
+        let mut contract = SlashingLibrary::new(accounts(0), 1000);
+        contract.propose_owner(accounts(1));
+
+        testing_env!(get_context(accounts(4)).build());
+        contract.accept_ownership();
+    }
 }
@@ -1,6 +1,7 @@
 use near_sdk::json_types::U128;
 use near_sdk::store::LookupMap;
 use near_sdk::{env, near, require, AccountId, NearToken, PanicOnDefault, Promise};
+use oracle_types::ownership::PendingOwner;
 
 /// Store - Oracle fee collection contract.
 ///
@@ -15,11 +16,17 @@ pub struct Store {
     /// Contract owner - can set fees
     owner: AccountId,
 
+    /// Account proposed as the next owner, pending its `accept_ownership`
+    pending_owner: PendingOwner,
+
     /// Account that can withdraw collected fees
     withdrawer: AccountId,
 
     /// Final fee per currency (token_id → fee amount in that token's smallest unit)
     final_fees: LookupMap<AccountId, u128>,
+
+    /// Fees received via `ft_on_transfer` and not yet withdrawn, per currency
+    collected_fees: LookupMap<AccountId, u128>,
 }
 
 /// Event emitted when a final fee is set
@@ -29,6 +36,14 @@ pub struct FinalFeeSet {
     pub fee: U128,
 }
 
+/// Event emitted when fee tokens are deposited
+#[near(serializers = [json])]
+pub struct FeesDeposited {
+    pub currency: AccountId,
+    pub sender_id: AccountId,
+    pub amount: U128,
+}
+
 #[near]
 impl Store {
     /// Initialize the Store contract.
@@ -40,8 +55,10 @@ impl Store {
     pub fn new(owner: AccountId, withdrawer: AccountId) -> Self {
         Self {
             owner,
+            pending_owner: PendingOwner::default(),
             withdrawer,
             final_fees: LookupMap::new(b"f"),
+            collected_fees: LookupMap::new(b"c"),
         }
     }
 
@@ -101,6 +118,51 @@ impl Store {
         self.final_fees.contains_key(&currency)
     }
 
+    // ==================== Fee Deposits ====================
+
+    /// NEP-141 receiver for fee deposits (e.g. the oracle's share of a disputed bond).
+    /// Only currencies with a final fee set are accepted; anything else is refunded.
+    ///
+    /// # Arguments
+    /// * `sender_id` - Account that sent the tokens
+    /// * `amount` - Amount of tokens received
+    /// * `msg` - Unused
+    ///
+    /// # Returns
+    /// Amount to refund to the sender
+    pub fn ft_on_transfer(&mut self, sender_id: AccountId, amount: U128, msg: String) -> U128 {
+        let _ = msg;
+        let currency = env::predecessor_account_id();
+        if !self.final_fees.contains_key(&currency) {
+            return amount;
+        }
+
+        let collected = self.collected_fees.get(&currency).copied().unwrap_or(0);
+        self.collected_fees
+            .insert(currency.clone(), collected.saturating_add(amount.0));
+
+        let event = FeesDeposited {
+            currency,
+            sender_id,
+            amount,
+        };
+        let event_json = near_sdk::serde_json::to_string(&event).unwrap();
+        env::log_str(&format!(
+            "EVENT_JSON:{{\"standard\":\"store\",\"version\":\"1.0.0\",\"event\":\"fees_deposited\",\"data\":{}}}",
+            event_json
+        ));
+
+        U128(0)
+    }
+
+    /// Get the fees collected in a currency and not yet withdrawn.
+    ///
+    /// # Arguments
+    /// * `currency` - Token contract account ID
+    pub fn get_collected_fees(&self, currency: AccountId) -> U128 {
+        U128(self.collected_fees.get(&currency).copied().unwrap_or(0))
+    }
+
     // ==================== Withdrawal ====================
 
     /// Withdraw NEAR from the contract.
@@ -121,7 +183,7 @@ impl Store {
         Promise::new(self.withdrawer.clone()).transfer(NearToken::from_yoctonear(amount.0))
     }
 
-    /// Withdraw NEP-141 tokens from the contract.
+    /// Withdraw NEP-141 tokens from the contract, reducing the collected fees for `token`.
     /// Only the withdrawer can call this method.
     ///
     /// # Arguments
@@ -131,6 +193,10 @@ impl Store {
         self.assert_withdrawer();
         require!(amount.0 > 0, "Amount must be positive");
 
+        let collected = self.collected_fees.get(&token).copied().unwrap_or(0);
+        self.collected_fees
+            .insert(token.clone(), collected.saturating_sub(amount.0));
+
         // Call ft_transfer on the token contract
         Promise::new(token).function_call(
             "ft_transfer".to_string(),
@@ -147,14 +213,25 @@ impl Store {
 
     // ==================== Role Management ====================
 
-    /// Set a new owner.
+    /// Propose a new owner, who takes over once it calls `accept_ownership`.
     /// Only the current owner can call this method.
     ///
     /// # Arguments
-    /// * `new_owner` - The new owner account
-    pub fn set_owner(&mut self, new_owner: AccountId) {
+    /// * `new_owner` - The proposed owner account
+    pub fn propose_owner(&mut self, new_owner: AccountId) {
         self.assert_owner();
-        self.owner = new_owner;
+        self.pending_owner.propose(new_owner);
+    }
+
+    /// Accept a pending ownership proposal.
+    /// Only the proposed owner can call this method.
+    pub fn accept_ownership(&mut self) {
+        self.pending_owner.accept(&mut self.owner, "store");
+    }
+
+    /// Get the account proposed as the next owner, if any.
+    pub fn get_pending_owner(&self) -> Option<AccountId> {
+        self.pending_owner.get()
     }
 
     /// Set a new withdrawer.
@@ -328,12 +405,50 @@ mod tests {
 
         let mut contract = Store::new(accounts(0), accounts(1));
 
-        contract.set_owner(accounts(2));
-        assert_eq!(contract.get_owner(), accounts(2));
+        contract.propose_owner(accounts(2));
+        assert_eq!(contract.get_owner(), accounts(0));
+        assert_eq!(contract.get_pending_owner(), Some(accounts(2)));
 
-        // New owner can set fees
+        // New owner can set fees once it accepts
         testing_env!(get_context(accounts(2)).build());
+        contract.accept_ownership();
+        assert_eq!(contract.get_owner(), accounts(2));
         contract.set_final_fee(accounts(3), U128(500));
         assert_eq!(contract.get_final_fee(accounts(3)).0, 500);
     }
+
+    #[test]
+    fn test_ft_on_transfer_credits_fee_currency() {
+        testing_env!(get_context(accounts(0)).build());
+        let mut contract = Store::new(accounts(0), accounts(1));
+        contract.set_final_fee(accounts(2), U128(1000));
+
+        testing_env!(get_context(accounts(2)).build());
+        let refund = contract.ft_on_transfer(accounts(3), U128(500), String::new());
+        assert_eq!(refund.0, 0);
+        contract.ft_on_transfer(accounts(3), U128(250), String::new());
+        assert_eq!(contract.get_collected_fees(accounts(2)).0, 750);
+    }
+
+    #[test]
+    fn test_ft_on_transfer_refunds_unknown_token() {
+        testing_env!(get_context(accounts(0)).build());
+        let mut contract = Store::new(accounts(0), accounts(1));
+
+        testing_env!(get_context(accounts(2)).build());
+        let refund = contract.ft_on_transfer(accounts(3), U128(500), String::new());
+        assert_eq!(refund.0, 500);
+        assert_eq!(contract.get_collected_fees(accounts(2)).0, 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Only the pending owner can accept ownership")]
+    fn test_accept_ownership_rejects_other_accounts() {
+        testing_env!(get_context(accounts(0)).build());
+        let mut contract = Store::new(accounts(0), accounts(1));
+        contract.propose_owner(accounts(2));
+
+        testing_env!(get_context(accounts(4)).build());
+        contract.accept_ownership();
+    }
 }
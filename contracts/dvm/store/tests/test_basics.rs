@@ -66,5 +66,42 @@ async fn test_basics_on(contract_wasm: &[u8]) -> Result<(), Box<dyn std::error::
         .json()?;
     assert_eq!(unset_fee, "0");
 
+    // Fee deposit from the USDC token is credited
+    let payer = sandbox.dev_create_account().await?;
+    let outcome = usdc_token
+        .call(contract.id(), "ft_on_transfer")
+        .args_json(json!({
+            "sender_id": payer.id(),
+            "amount": "5000000",
+            "msg": ""
+        }))
+        .transact()
+        .await?;
+    assert!(outcome.is_success());
+    let refund: String = outcome.json()?;
+    assert_eq!(refund, "0");
+
+    let collected: String = contract
+        .view("get_collected_fees")
+        .args_json(json!({"currency": usdc_token.id()}))
+        .await?
+        .json()?;
+    assert_eq!(collected, "5000000");
+
+    // Deposit from a token without a final fee is refunded in full
+    let unknown_token = sandbox.dev_create_account().await?;
+    let outcome = unknown_token
+        .call(contract.id(), "ft_on_transfer")
+        .args_json(json!({
+            "sender_id": payer.id(),
+            "amount": "5000000",
+            "msg": ""
+        }))
+        .transact()
+        .await?;
+    assert!(outcome.is_success());
+    let refund: String = outcome.json()?;
+    assert_eq!(refund, "5000000");
+
     Ok(())
 }
@@ -1,15 +1,27 @@
+use near_contract_standards::fungible_token::metadata::FungibleTokenMetadata;
 use near_contract_standards::fungible_token::receiver::FungibleTokenReceiver;
 use near_sdk::json_types::U128;
 use near_sdk::serde::{Deserialize, Serialize};
+use near_sdk::store::LookupMap;
 use near_sdk::{
     env, ext_contract, near, require, AccountId, Gas, NearToken, PanicOnDefault, Promise,
     PromiseOrValue, PromiseResult,
 };
+use oracle_types::ownership::PendingOwner;
+use oracle_types::types::Bytes32;
 
 const GAS_FOR_MINT: Gas = Gas::from_tgas(5);
 const GAS_FOR_BURN: Gas = Gas::from_tgas(5);
 const GAS_FOR_COLLATERAL_TRANSFER: Gas = Gas::from_tgas(10);
 const GAS_FOR_CALLBACK: Gas = Gas::from_tgas(5);
+const GAS_FOR_FT_METADATA: Gas = Gas::from_tgas(5);
+const GAS_FOR_ORACLE_PRICE: Gas = Gas::from_tgas(5);
+
+/// Cached collateral prices are stored with 18 decimals.
+const PRICE_DECIMALS: u8 = 18;
+const PRICE_SCALE: u128 = 10u128.pow(PRICE_DECIMALS as u32);
+/// Largest supported decimals for oracle results.
+const MAX_ORACLE_PRICE_DECIMALS: u8 = 36;
 
 #[derive(Serialize, Deserialize)]
 #[serde(crate = "near_sdk::serde")]
@@ -23,8 +35,10 @@ pub struct InvariantDiagnostics {
     pub total_locked_collateral: U128,
     pub total_minted_liability: U128,
     pub backing_ratio_bps: Option<U128>,
+    pub valued_backing_ratio_bps: Option<U128>,
     pub invariant_ok: bool,
     pub redemptions_paused: bool,
+    pub shutdown: bool,
 }
 
 #[near(serializers = [json])]
@@ -33,6 +47,18 @@ struct VaultEventData {
     amount: U128,
 }
 
+#[near(serializers = [json])]
+struct PriceEventData {
+    assertion_id: Bytes32,
+    price: U128,
+}
+
+#[near(serializers = [json])]
+struct InvariantEventData {
+    total_locked_collateral: U128,
+    total_minted_liability: U128,
+}
+
 #[ext_contract(ext_nest)]
 #[allow(dead_code)]
 trait ExtNestToken {
@@ -46,24 +72,58 @@ trait ExtCollateralToken {
     fn ft_transfer(&mut self, receiver_id: AccountId, amount: U128, memo: Option<String>);
 }
 
+#[ext_contract(ext_ft_metadata)]
+#[allow(dead_code)]
+trait ExtFtMetadata {
+    fn ft_metadata(&self) -> FungibleTokenMetadata;
+}
+
+#[ext_contract(ext_oracle)]
+#[allow(dead_code)]
+trait ExtOptimisticOracle {
+    fn get_assertion_numeric_result(&self, assertion_id: Bytes32) -> Option<i128>;
+}
+
 #[ext_contract(ext_self)]
 #[allow(dead_code)]
 trait ExtVaultCallbacks {
+    fn on_verify_tokens(&mut self) -> (u8, u8);
+    fn on_oracle_price(&mut self, assertion_id: Bytes32) -> U128;
     fn on_deposit_mint_complete(&mut self, depositor: AccountId, amount: U128) -> U128;
     fn on_redeem_burn_complete(&mut self, redeemer: AccountId, amount: U128);
     fn on_redeem_transfer_complete(&mut self, redeemer: AccountId, amount: U128) -> bool;
+    fn on_shutdown_burn_complete(&mut self, claimant: AccountId, amount: U128);
+    fn on_shutdown_transfer_complete(
+        &mut self,
+        claimant: AccountId,
+        amount: U128,
+        collateral: U128,
+    ) -> bool;
 }
 
 #[near(contract_state)]
 #[derive(PanicOnDefault)]
 pub struct Vault {
     owner: AccountId,
+    /// Account proposed as the next owner, pending its `accept_ownership`
+    pending_owner: PendingOwner,
     collateral_token: AccountId,
     nest_token: AccountId,
     emergency_recipient: AccountId,
+    /// Emergency recipient per collateral token, overriding `emergency_recipient`
+    collateral_emergency_recipients: LookupMap<AccountId, AccountId>,
     redemptions_paused: bool,
     total_locked_collateral: u128,
     total_minted_liability: u128,
+    collateral_decimals: Option<u8>,
+    nest_decimals: Option<u8>,
+    shutdown: bool,
+    /// Optimistic oracle whose numerical results price the collateral
+    price_oracle: Option<AccountId>,
+    /// Decimals of the oracle's numerical results
+    oracle_price_decimals: u8,
+    /// Last consumed collateral price, in NEST per collateral with `PRICE_DECIMALS` decimals
+    collateral_price: Option<u128>,
 }
 
 #[near]
@@ -77,16 +137,143 @@ impl Vault {
     ) -> Self {
         Self {
             emergency_recipient: emergency_recipient.unwrap_or_else(|| owner.clone()),
+            collateral_emergency_recipients: LookupMap::new(b"e"),
             owner,
+            pending_owner: PendingOwner::default(),
             collateral_token,
             nest_token,
             redemptions_paused: false,
             total_locked_collateral: 0,
             total_minted_liability: 0,
+            collateral_decimals: None,
+            nest_decimals: None,
+            shutdown: false,
+            price_oracle: None,
+            oracle_price_decimals: PRICE_DECIMALS,
+            collateral_price: None,
         }
     }
 
+    /// Fetch `ft_metadata` from both tokens and record their decimals.
+    /// Deposits and redemptions are rejected until this has succeeded.
+    pub fn verify_tokens(&mut self) -> Promise {
+        ext_ft_metadata::ext(self.collateral_token.clone())
+            .with_static_gas(GAS_FOR_FT_METADATA)
+            .ft_metadata()
+            .and(
+                ext_ft_metadata::ext(self.nest_token.clone())
+                    .with_static_gas(GAS_FOR_FT_METADATA)
+                    .ft_metadata(),
+            )
+            .then(
+                ext_self::ext(env::current_account_id())
+                    .with_static_gas(GAS_FOR_CALLBACK)
+                    .on_verify_tokens(),
+            )
+    }
+
+    #[allow(deprecated)]
+    #[private]
+    pub fn on_verify_tokens(&mut self) -> (u8, u8) {
+        require!(
+            env::promise_results_count() == 2,
+            "Expected two promise results"
+        );
+
+        let decimals = |index: u64| -> u8 {
+            match env::promise_result(index) {
+                PromiseResult::Successful(value) => {
+                    near_sdk::serde_json::from_slice::<FungibleTokenMetadata>(&value)
+                        .expect("Invalid token metadata")
+                        .decimals
+                }
+                _ => env::panic_str("Token metadata lookup failed"),
+            }
+        };
+        let collateral_decimals = decimals(0);
+        let nest_decimals = decimals(1);
+        require!(
+            collateral_decimals.abs_diff(nest_decimals) <= 38,
+            "Token decimals difference too large"
+        );
+
+        self.collateral_decimals = Some(collateral_decimals);
+        self.nest_decimals = Some(nest_decimals);
+        env::log_str(&format!(
+            "Vault token decimals verified: collateral={}, nest={}",
+            collateral_decimals, nest_decimals
+        ));
+        (collateral_decimals, nest_decimals)
+    }
+
+    /// Configure the oracle used to price collateral and the decimals of its numerical
+    /// results. Clears any cached price.
+    pub fn set_price_oracle(&mut self, price_oracle: Option<AccountId>, price_decimals: u8) {
+        self.assert_owner();
+        require!(
+            price_decimals <= MAX_ORACLE_PRICE_DECIMALS,
+            "Price decimals too large"
+        );
+        self.price_oracle = price_oracle;
+        self.oracle_price_decimals = price_decimals;
+        self.collateral_price = None;
+    }
+
+    /// Fetch the numerical result of a settled oracle assertion and cache it as the
+    /// collateral price (NEST per collateral) used by `get_valued_backing_ratio_bps`.
+    pub fn consume_oracle_price(&mut self, assertion_id: Bytes32) -> Promise {
+        self.assert_owner();
+        let price_oracle = self
+            .price_oracle
+            .clone()
+            .unwrap_or_else(|| env::panic_str("Price oracle not configured"));
+
+        ext_oracle::ext(price_oracle)
+            .with_static_gas(GAS_FOR_ORACLE_PRICE)
+            .get_assertion_numeric_result(assertion_id)
+            .then(
+                ext_self::ext(env::current_account_id())
+                    .with_static_gas(GAS_FOR_CALLBACK)
+                    .on_oracle_price(assertion_id),
+            )
+    }
+
+    #[allow(deprecated)]
+    #[private]
+    pub fn on_oracle_price(&mut self, assertion_id: Bytes32) -> U128 {
+        require!(
+            env::promise_results_count() == 1,
+            "Expected one promise result"
+        );
+
+        let price = match env::promise_result(0) {
+            PromiseResult::Successful(value) => {
+                near_sdk::serde_json::from_slice::<Option<i128>>(&value)
+                    .expect("Invalid oracle price")
+                    .unwrap_or_else(|| env::panic_str("Assertion has no numeric result"))
+            }
+            _ => env::panic_str("Oracle price lookup failed"),
+        };
+        require!(price > 0, "Oracle price must be positive");
+
+        let price = self.scale_oracle_price(price as u128);
+        require!(price > 0, "Oracle price too small for price precision");
+        self.collateral_price = Some(price);
+
+        let data = near_sdk::serde_json::to_string(&PriceEventData {
+            assertion_id,
+            price: U128(price),
+        })
+        .expect("Event serialization failed");
+        env::log_str(&format!(
+            "EVENT_JSON:{{\"standard\":\"nest_vault\",\"version\":\"1.0.0\",\"event\":\"collateral_price_updated\",\"data\":{}}}",
+            data
+        ));
+        U128(price)
+    }
+
     pub fn redeem_collateral(&mut self, amount: U128) -> Promise {
+        require!(!self.shutdown, "Vault is shut down");
         require!(!self.redemptions_paused, "Redemptions are paused");
         require!(amount.0 > 0, "Amount must be positive");
 
@@ -95,6 +282,10 @@ impl Vault {
             self.total_minted_liability >= amount.0,
             "Vault liability is below requested redemption"
         );
+        require!(
+            self.nest_to_collateral(amount.0) > 0,
+            "Redemption too small for collateral precision"
+        );
 
         ext_nest::ext(self.nest_token.clone())
             .with_static_gas(GAS_FOR_BURN)
@@ -116,12 +307,13 @@ impl Vault {
 
         match env::promise_result(0) {
             PromiseResult::Successful(_) => {
+                let minted = self.collateral_to_nest(amount.0);
                 self.total_locked_collateral =
                     self.total_locked_collateral.saturating_add(amount.0);
-                self.total_minted_liability = self.total_minted_liability.saturating_add(amount.0);
+                self.total_minted_liability = self.total_minted_liability.saturating_add(minted);
                 self.assert_invariant();
                 self.emit_event("collateral_deposit", &depositor, amount);
-                self.emit_event("nest_mint", &depositor, amount);
+                self.emit_event("nest_mint", &depositor, U128(minted));
                 U128(0)
             }
             _ => {
@@ -143,8 +335,9 @@ impl Vault {
 
         match env::promise_result(0) {
             PromiseResult::Successful(_) => {
+                let collateral = self.nest_to_collateral(amount.0);
                 require!(
-                    self.total_locked_collateral >= amount.0,
+                    self.total_locked_collateral >= collateral,
                     "Insufficient locked collateral"
                 );
                 require!(
@@ -152,7 +345,7 @@ impl Vault {
                     "Insufficient minted liability"
                 );
 
-                self.total_locked_collateral -= amount.0;
+                self.total_locked_collateral -= collateral;
                 self.total_minted_liability -= amount.0;
                 self.assert_invariant();
                 self.emit_event("nest_burn", &redeemer, amount);
@@ -160,7 +353,11 @@ impl Vault {
                 let _ = ext_collateral::ext(self.collateral_token.clone())
                     .with_attached_deposit(NearToken::from_yoctonear(1))
                     .with_static_gas(GAS_FOR_COLLATERAL_TRANSFER)
-                    .ft_transfer(redeemer.clone(), amount, Some("vault redeem".to_string()))
+                    .ft_transfer(
+                        redeemer.clone(),
+                        U128(collateral),
+                        Some("vault redeem".to_string()),
+                    )
                     .then(
                         ext_self::ext(env::current_account_id())
                             .with_static_gas(GAS_FOR_CALLBACK)
@@ -183,13 +380,18 @@ impl Vault {
 
         match env::promise_result(0) {
             PromiseResult::Successful(_) => {
-                self.emit_event("collateral_redeem", &redeemer, amount);
+                self.emit_event(
+                    "collateral_redeem",
+                    &redeemer,
+                    U128(self.nest_to_collateral(amount.0)),
+                );
                 true
             }
             _ => {
                 // Best-effort rollback: restore accounting and re-mint burned NEST.
-                self.total_locked_collateral =
-                    self.total_locked_collateral.saturating_add(amount.0);
+                self.total_locked_collateral = self
+                    .total_locked_collateral
+                    .saturating_add(self.nest_to_collateral(amount.0));
                 self.total_minted_liability = self.total_minted_liability.saturating_add(amount.0);
                 self.assert_invariant();
                 env::log_str(
@@ -203,6 +405,121 @@ impl Vault {
         }
     }
 
+    /// Permanently wind down the vault: mint and redeem are frozen and NEST
+    /// holders reclaim collateral pro-rata through `claim_on_shutdown`.
+    pub fn trigger_shutdown(&mut self) {
+        self.assert_owner();
+        require!(!self.shutdown, "Vault is already shut down");
+        self.shutdown = true;
+        self.emit_event(
+            "vault_shutdown",
+            &self.owner,
+            U128(self.total_locked_collateral),
+        );
+    }
+
+    /// Burn `amount` NEST and receive
+    /// `total_locked_collateral * amount / total_minted_liability` collateral.
+    pub fn claim_on_shutdown(&mut self, amount: U128) -> Promise {
+        require!(self.shutdown, "Vault is not shut down");
+        require!(amount.0 > 0, "Amount must be positive");
+        require!(
+            self.total_minted_liability >= amount.0,
+            "Vault liability is below requested claim"
+        );
+
+        let claimant = env::predecessor_account_id();
+        ext_nest::ext(self.nest_token.clone())
+            .with_static_gas(GAS_FOR_BURN)
+            .burn_from(claimant.clone(), amount)
+            .then(
+                ext_self::ext(env::current_account_id())
+                    .with_static_gas(GAS_FOR_CALLBACK.saturating_add(GAS_FOR_COLLATERAL_TRANSFER))
+                    .on_shutdown_burn_complete(claimant, amount),
+            )
+    }
+
+    #[allow(deprecated)]
+    #[private]
+    pub fn on_shutdown_burn_complete(&mut self, claimant: AccountId, amount: U128) {
+        require!(
+            env::promise_results_count() == 1,
+            "Expected one promise result"
+        );
+
+        match env::promise_result(0) {
+            PromiseResult::Successful(_) => {
+                require!(
+                    self.total_minted_liability >= amount.0,
+                    "Insufficient minted liability"
+                );
+                // Rounds down, so the final claimant receives exactly what is left.
+                let collateral = mul_div_floor(
+                    self.total_locked_collateral,
+                    amount.0,
+                    self.total_minted_liability,
+                );
+                self.total_locked_collateral -= collateral;
+                self.total_minted_liability -= amount.0;
+                self.emit_event("nest_burn", &claimant, amount);
+
+                if collateral == 0 {
+                    return;
+                }
+                let _ = ext_collateral::ext(self.collateral_token.clone())
+                    .with_attached_deposit(NearToken::from_yoctonear(1))
+                    .with_static_gas(GAS_FOR_COLLATERAL_TRANSFER)
+                    .ft_transfer(
+                        claimant.clone(),
+                        U128(collateral),
+                        Some("vault shutdown claim".to_string()),
+                    )
+                    .then(
+                        ext_self::ext(env::current_account_id())
+                            .with_static_gas(GAS_FOR_CALLBACK)
+                            .on_shutdown_transfer_complete(claimant, amount, U128(collateral)),
+                    );
+            }
+            _ => {
+                env::panic_str("NEST burn failed during shutdown claim");
+            }
+        }
+    }
+
+    #[allow(deprecated)]
+    #[private]
+    pub fn on_shutdown_transfer_complete(
+        &mut self,
+        claimant: AccountId,
+        amount: U128,
+        collateral: U128,
+    ) -> bool {
+        require!(
+            env::promise_results_count() == 1,
+            "Expected one promise result"
+        );
+
+        match env::promise_result(0) {
+            PromiseResult::Successful(_) => {
+                self.emit_event("shutdown_claim", &claimant, collateral);
+                true
+            }
+            _ => {
+                // Restore the claim and re-mint burned NEST so the holder can retry.
+                self.total_locked_collateral =
+                    self.total_locked_collateral.saturating_add(collateral.0);
+                self.total_minted_liability = self.total_minted_liability.saturating_add(amount.0);
+                env::log_str(
+                    "Collateral transfer failed during shutdown claim; attempting NEST re-mint rollback",
+                );
+                let _ = ext_nest::ext(self.nest_token.clone())
+                    .with_static_gas(GAS_FOR_MINT)
+                    .mint(claimant, amount);
+                false
+            }
+        }
+    }
+
     pub fn pause_redemptions(&mut self) {
         self.assert_owner();
         self.redemptions_paused = true;
@@ -215,6 +532,7 @@ impl Vault {
 
     pub fn emergency_withdraw_collateral(&mut self, amount: U128) -> Promise {
         self.assert_owner();
+        require!(!self.shutdown, "Vault is shut down");
         require!(
             self.redemptions_paused,
             "Pause redemptions before emergency withdrawal"
@@ -227,29 +545,43 @@ impl Vault {
 
         self.total_locked_collateral -= amount.0;
 
+        let recipient = self.get_emergency_recipient(self.collateral_token.clone());
+        self.emit_event("emergency_withdrawal", &recipient, amount);
         ext_collateral::ext(self.collateral_token.clone())
             .with_attached_deposit(NearToken::from_yoctonear(1))
             .with_static_gas(GAS_FOR_COLLATERAL_TRANSFER)
             .ft_transfer(
-                self.emergency_recipient.clone(),
+                recipient,
                 amount,
                 Some("vault emergency withdrawal".to_string()),
             )
     }
 
-    pub fn set_owner(&mut self, new_owner: AccountId) {
+    /// Propose a new owner, who takes over once it calls `accept_ownership`.
+    pub fn propose_owner(&mut self, new_owner: AccountId) {
         self.assert_owner();
-        self.owner = new_owner;
+        self.pending_owner.propose(new_owner);
+    }
+
+    pub fn accept_ownership(&mut self) {
+        self.pending_owner.accept(&mut self.owner, "nest_vault");
+    }
+
+    pub fn get_pending_owner(&self) -> Option<AccountId> {
+        self.pending_owner.get()
     }
 
     pub fn set_collateral_token(&mut self, collateral_token: AccountId) {
         self.assert_owner();
         self.collateral_token = collateral_token;
+        self.collateral_decimals = None;
+        self.collateral_price = None;
     }
 
     pub fn set_nest_token(&mut self, nest_token: AccountId) {
         self.assert_owner();
         self.nest_token = nest_token;
+        self.nest_decimals = None;
     }
 
     pub fn set_emergency_recipient(&mut self, emergency_recipient: AccountId) {
@@ -257,6 +589,34 @@ impl Vault {
         self.emergency_recipient = emergency_recipient;
     }
 
+    /// Route emergency withdrawals of `collateral_token` to `recipient`, or back to the
+    /// global emergency recipient when `None`.
+    pub fn set_collateral_emergency_recipient(
+        &mut self,
+        collateral_token: AccountId,
+        recipient: Option<AccountId>,
+    ) {
+        self.assert_owner();
+        match recipient {
+            Some(recipient) => {
+                self.collateral_emergency_recipients
+                    .insert(collateral_token, recipient);
+            }
+            None => {
+                self.collateral_emergency_recipients
+                    .remove(&collateral_token);
+            }
+        }
+    }
+
+    /// Account that receives emergency withdrawals of `collateral_token`.
+    pub fn get_emergency_recipient(&self, collateral_token: AccountId) -> AccountId {
+        self.collateral_emergency_recipients
+            .get(&collateral_token)
+            .cloned()
+            .unwrap_or_else(|| self.emergency_recipient.clone())
+    }
+
     pub fn get_owner(&self) -> AccountId {
         self.owner.clone()
     }
@@ -269,10 +629,32 @@ impl Vault {
         self.nest_token.clone()
     }
 
+    /// Verified (collateral, NEST) decimals, if `verify_tokens` has completed.
+    pub fn get_token_decimals(&self) -> Option<(u8, u8)> {
+        Some((self.collateral_decimals?, self.nest_decimals?))
+    }
+
+    pub fn get_price_oracle(&self) -> Option<AccountId> {
+        self.price_oracle.clone()
+    }
+
+    pub fn get_oracle_price_decimals(&self) -> u8 {
+        self.oracle_price_decimals
+    }
+
+    /// Last consumed collateral price in NEST per collateral, with 18 decimals.
+    pub fn get_collateral_price(&self) -> Option<U128> {
+        self.collateral_price.map(U128)
+    }
+
     pub fn get_redemptions_paused(&self) -> bool {
         self.redemptions_paused
     }
 
+    pub fn get_shutdown(&self) -> bool {
+        self.shutdown
+    }
+
     pub fn get_total_locked_collateral(&self) -> U128 {
         U128(self.total_locked_collateral)
     }
@@ -286,20 +668,60 @@ impl Vault {
             return None;
         }
         Some(U128(
-            self.total_locked_collateral.saturating_mul(10_000) / self.total_minted_liability,
+            self.locked_collateral_in_nest().saturating_mul(10_000) / self.total_minted_liability,
         ))
     }
 
+    /// Backing ratio with locked collateral valued at the cached oracle price.
+    /// None until a price has been consumed or while nothing is minted.
+    pub fn get_valued_backing_ratio_bps(&self) -> Option<U128> {
+        if self.total_minted_liability == 0 {
+            return None;
+        }
+        let valued_collateral = mul_div_floor(
+            self.locked_collateral_in_nest(),
+            self.collateral_price?,
+            PRICE_SCALE,
+        );
+        Some(U128(mul_div_floor(
+            valued_collateral,
+            10_000,
+            self.total_minted_liability,
+        )))
+    }
+
     pub fn get_invariant_diagnostics(&self) -> InvariantDiagnostics {
         InvariantDiagnostics {
             total_locked_collateral: U128(self.total_locked_collateral),
             total_minted_liability: U128(self.total_minted_liability),
             backing_ratio_bps: self.get_backing_ratio_bps(),
-            invariant_ok: self.total_minted_liability <= self.total_locked_collateral,
+            valued_backing_ratio_bps: self.get_valued_backing_ratio_bps(),
+            invariant_ok: self.total_minted_liability <= self.locked_collateral_in_nest(),
             redemptions_paused: self.redemptions_paused,
+            shutdown: self.shutdown,
         }
     }
 
+    /// Keeper check of the backing invariant. On a breach, pauses redemptions and emits
+    /// `invariant_breached` instead of panicking, so the vault fails safe.
+    /// Returns whether the invariant holds.
+    pub fn check_invariant(&mut self) -> bool {
+        if self.total_minted_liability <= self.locked_collateral_in_nest() {
+            return true;
+        }
+        self.redemptions_paused = true;
+        let data = near_sdk::serde_json::to_string(&InvariantEventData {
+            total_locked_collateral: U128(self.total_locked_collateral),
+            total_minted_liability: U128(self.total_minted_liability),
+        })
+        .expect("Event serialization failed");
+        env::log_str(&format!(
+            "EVENT_JSON:{{\"standard\":\"nest_vault\",\"version\":\"1.0.0\",\"event\":\"invariant_breached\",\"data\":{}}}",
+            data
+        ));
+        false
+    }
+
     fn assert_owner(&self) {
         require!(
             env::predecessor_account_id() == self.owner,
@@ -309,11 +731,70 @@ impl Vault {
 
     fn assert_invariant(&self) {
         require!(
-            self.total_minted_liability <= self.total_locked_collateral,
+            self.total_minted_liability <= self.locked_collateral_in_nest(),
             "Invariant violated: NEST liability exceeds locked collateral"
         );
     }
 
+    /// Returns (scale factor, whether NEST has more decimals than collateral).
+    fn decimal_scale(&self) -> (u128, bool) {
+        let (Some(collateral_decimals), Some(nest_decimals)) =
+            (self.collateral_decimals, self.nest_decimals)
+        else {
+            env::panic_str("Token decimals not verified");
+        };
+        let factor = 10u128.pow(collateral_decimals.abs_diff(nest_decimals) as u32);
+        (factor, nest_decimals >= collateral_decimals)
+    }
+
+    /// Convert a collateral amount to NEST units, rounding down.
+    fn collateral_to_nest(&self, amount: u128) -> u128 {
+        let (factor, scale_up) = self.decimal_scale();
+        if scale_up {
+            amount
+                .checked_mul(factor)
+                .unwrap_or_else(|| env::panic_str("Scaled amount overflow"))
+        } else {
+            amount / factor
+        }
+    }
+
+    /// Convert a NEST amount to collateral units, rounding down.
+    fn nest_to_collateral(&self, amount: u128) -> u128 {
+        let (factor, scale_up) = self.decimal_scale();
+        if scale_up {
+            amount / factor
+        } else {
+            amount
+                .checked_mul(factor)
+                .unwrap_or_else(|| env::panic_str("Scaled amount overflow"))
+        }
+    }
+
+    /// Rescale an oracle result from `oracle_price_decimals` to `PRICE_DECIMALS`.
+    fn scale_oracle_price(&self, price: u128) -> u128 {
+        let factor = 10u128.pow(self.oracle_price_decimals.abs_diff(PRICE_DECIMALS) as u32);
+        if self.oracle_price_decimals <= PRICE_DECIMALS {
+            price
+                .checked_mul(factor)
+                .unwrap_or_else(|| env::panic_str("Scaled amount overflow"))
+        } else {
+            price / factor
+        }
+    }
+
+    fn locked_collateral_in_nest(&self) -> u128 {
+        if self.collateral_decimals.is_none() || self.nest_decimals.is_none() {
+            return self.total_locked_collateral;
+        }
+        let (factor, scale_up) = self.decimal_scale();
+        if scale_up {
+            self.total_locked_collateral.saturating_mul(factor)
+        } else {
+            self.total_locked_collateral / factor
+        }
+    }
+
     fn emit_event(&self, event: &str, account_id: &AccountId, amount: U128) {
         let data = near_sdk::serde_json::to_string(&VaultEventData {
             account_id: account_id.clone(),
@@ -327,6 +808,39 @@ impl Vault {
     }
 }
 
+/// Computes `a * b / denominator` rounded down, using a 256-bit intermediate product.
+fn mul_div_floor(a: u128, b: u128, denominator: u128) -> u128 {
+    require!(denominator > 0, "Division by zero");
+    if let Some(product) = a.checked_mul(b) {
+        return product / denominator;
+    }
+
+    const LOW_MASK: u128 = u64::MAX as u128;
+    let (a_hi, a_lo) = (a >> 64, a & LOW_MASK);
+    let (b_hi, b_lo) = (b >> 64, b & LOW_MASK);
+    let lo_lo = a_lo * b_lo;
+    let lo_hi = a_lo * b_hi;
+    let hi_lo = a_hi * b_lo;
+    let mid = (lo_lo >> 64) + (lo_hi & LOW_MASK) + (hi_lo & LOW_MASK);
+    let lo = (lo_lo & LOW_MASK) | (mid << 64);
+    let hi = a_hi * b_hi + (lo_hi >> 64) + (hi_lo >> 64) + (mid >> 64);
+    require!(hi < denominator, "Multiplication overflow");
+
+    // Binary long division of (hi, lo) by denominator; remainder stays below it.
+    let mut remainder = hi;
+    let mut quotient = 0u128;
+    for bit in (0..128).rev() {
+        let carry = remainder >> 127;
+        remainder = (remainder << 1) | ((lo >> bit) & 1);
+        quotient <<= 1;
+        if carry == 1 || remainder >= denominator {
+            remainder = remainder.wrapping_sub(denominator);
+            quotient |= 1;
+        }
+    }
+    quotient
+}
+
 #[near]
 impl FungibleTokenReceiver for Vault {
     fn ft_on_transfer(
@@ -340,21 +854,26 @@ impl FungibleTokenReceiver for Vault {
             env::predecessor_account_id() == self.collateral_token,
             "Only collateral token can call ft_on_transfer"
         );
+        require!(!self.shutdown, "Vault is shut down");
 
         let parsed: VaultFtMessage =
             near_sdk::serde_json::from_str(&msg).expect("Invalid vault deposit message");
 
         match parsed {
-            VaultFtMessage::DepositCollateral => PromiseOrValue::Promise(
-                ext_nest::ext(self.nest_token.clone())
-                    .with_static_gas(GAS_FOR_MINT)
-                    .mint(sender_id.clone(), amount)
-                    .then(
-                        ext_self::ext(env::current_account_id())
-                            .with_static_gas(GAS_FOR_CALLBACK)
-                            .on_deposit_mint_complete(sender_id, amount),
-                    ),
-            ),
+            VaultFtMessage::DepositCollateral => {
+                let minted = self.collateral_to_nest(amount.0);
+                require!(minted > 0, "Deposit too small for NEST precision");
+                PromiseOrValue::Promise(
+                    ext_nest::ext(self.nest_token.clone())
+                        .with_static_gas(GAS_FOR_MINT)
+                        .mint(sender_id.clone(), U128(minted))
+                        .then(
+                            ext_self::ext(env::current_account_id())
+                                .with_static_gas(GAS_FOR_CALLBACK)
+                                .on_deposit_mint_complete(sender_id, amount),
+                        ),
+                )
+            }
         }
     }
 }
@@ -391,13 +910,43 @@ mod tests {
         );
     }
 
-    fn setup() -> Vault {
-        Vault::new(
+    fn metadata_result(decimals: u8) -> PromiseResult {
+        PromiseResult::Successful(
+            near_sdk::serde_json::to_vec(&FungibleTokenMetadata {
+                spec: "ft-1.0.0".to_string(),
+                name: "Token".to_string(),
+                symbol: "TKN".to_string(),
+                icon: None,
+                reference: None,
+                reference_hash: None,
+                decimals,
+            })
+            .unwrap(),
+        )
+    }
+
+    fn setup_with_decimals(collateral_decimals: u8, nest_decimals: u8) -> Vault {
+        let mut contract = Vault::new(
             accounts(0),
             account("collateral.testnet"),
             account("nest.testnet"),
             None,
-        )
+        );
+        let vault_account = account("vault.testnet");
+        set_context_with_results(
+            vault_account.clone(),
+            vault_account,
+            vec![
+                metadata_result(collateral_decimals),
+                metadata_result(nest_decimals),
+            ],
+        );
+        contract.on_verify_tokens();
+        contract
+    }
+
+    fn setup() -> Vault {
+        setup_with_decimals(24, 24)
     }
 
     #[test]
@@ -499,4 +1048,285 @@ mod tests {
         testing_env!(get_context(accounts(1), account("vault.testnet")).build());
         contract.pause_redemptions();
     }
+
+    #[test]
+    fn test_verify_tokens_records_decimals() {
+        let contract = setup_with_decimals(6, 24);
+        assert_eq!(contract.get_token_decimals(), Some((6, 24)));
+    }
+
+    #[test]
+    fn test_deposit_and_redeem_scale_across_decimals() {
+        let mut contract = setup_with_decimals(6, 24);
+        let vault_account = account("vault.testnet");
+
+        set_context_with_results(
+            vault_account.clone(),
+            vault_account.clone(),
+            vec![PromiseResult::Successful(vec![])],
+        );
+        let _ = contract.on_deposit_mint_complete(accounts(1), U128(2_000_000));
+        assert_eq!(contract.get_total_locked_collateral().0, 2_000_000);
+        assert_eq!(contract.get_total_minted_liability().0, 2 * 10u128.pow(24));
+        assert_eq!(contract.get_backing_ratio_bps().unwrap().0, 10_000);
+
+        set_context_with_results(
+            vault_account.clone(),
+            vault_account,
+            vec![PromiseResult::Successful(vec![])],
+        );
+        contract.on_redeem_burn_complete(accounts(1), U128(10u128.pow(24)));
+        assert_eq!(contract.get_total_locked_collateral().0, 1_000_000);
+        assert_eq!(contract.get_total_minted_liability().0, 10u128.pow(24));
+    }
+
+    #[test]
+    #[should_panic(expected = "Token decimals not verified")]
+    fn test_deposit_requires_verified_decimals() {
+        let mut contract = Vault::new(
+            accounts(0),
+            account("collateral.testnet"),
+            account("nest.testnet"),
+            None,
+        );
+        testing_env!(get_context(account("collateral.testnet"), account("vault.testnet")).build());
+        let msg = near_sdk::serde_json::to_string(&VaultFtMessage::DepositCollateral).unwrap();
+        let _ = contract.ft_on_transfer(accounts(1), U128(100), msg);
+    }
+
+    fn deposit(contract: &mut Vault, depositor: AccountId, amount: u128) {
+        let vault_account = account("vault.testnet");
+        set_context_with_results(
+            vault_account.clone(),
+            vault_account,
+            vec![PromiseResult::Successful(vec![])],
+        );
+        let _ = contract.on_deposit_mint_complete(depositor, U128(amount));
+    }
+
+    fn claim(contract: &mut Vault, claimant: AccountId, amount: u128) {
+        let vault_account = account("vault.testnet");
+        testing_env!(get_context(claimant.clone(), vault_account.clone()).build());
+        let _ = contract.claim_on_shutdown(U128(amount));
+        set_context_with_results(
+            vault_account.clone(),
+            vault_account,
+            vec![PromiseResult::Successful(vec![])],
+        );
+        contract.on_shutdown_burn_complete(claimant, U128(amount));
+    }
+
+    #[test]
+    fn test_shutdown_claim_is_pro_rata() {
+        let mut contract = setup();
+        let vault_account = account("vault.testnet");
+        deposit(&mut contract, accounts(1), 100);
+
+        // Backing drops to 90% before shutdown.
+        testing_env!(get_context(accounts(0), vault_account.clone()).build());
+        contract.pause_redemptions();
+        let _ = contract.emergency_withdraw_collateral(U128(10));
+        contract.trigger_shutdown();
+        assert!(contract.get_shutdown());
+
+        claim(&mut contract, accounts(1), 50);
+        assert_eq!(contract.get_total_locked_collateral().0, 45);
+        assert_eq!(contract.get_total_minted_liability().0, 50);
+    }
+
+    #[test]
+    fn test_shutdown_final_claimant_never_over_withdraws() {
+        let mut contract = setup();
+        let vault_account = account("vault.testnet");
+        deposit(&mut contract, accounts(1), 100);
+
+        testing_env!(get_context(accounts(0), vault_account).build());
+        contract.pause_redemptions();
+        let _ = contract.emergency_withdraw_collateral(U128(10));
+        contract.trigger_shutdown();
+
+        // 90 * 33 / 100 = 29.7 -> 29
+        claim(&mut contract, accounts(1), 33);
+        assert_eq!(contract.get_total_locked_collateral().0, 61);
+        // 61 * 33 / 67 = 30.04 -> 30
+        claim(&mut contract, accounts(2), 33);
+        assert_eq!(contract.get_total_locked_collateral().0, 31);
+        // Final claimant receives exactly the remaining collateral.
+        claim(&mut contract, accounts(3), 34);
+        assert_eq!(contract.get_total_locked_collateral().0, 0);
+        assert_eq!(contract.get_total_minted_liability().0, 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Vault is shut down")]
+    fn test_shutdown_freezes_redemptions() {
+        let mut contract = setup();
+        let vault_account = account("vault.testnet");
+        deposit(&mut contract, accounts(1), 100);
+
+        testing_env!(get_context(accounts(0), vault_account.clone()).build());
+        contract.trigger_shutdown();
+
+        testing_env!(get_context(accounts(1), vault_account).build());
+        let _ = contract.redeem_collateral(U128(10));
+    }
+
+    #[test]
+    fn test_mul_div_floor_wide_product() {
+        let locked = 10u128.pow(33);
+        let liability = 3 * 10u128.pow(33);
+        assert_eq!(mul_div_floor(locked, liability, liability), locked);
+        assert_eq!(mul_div_floor(locked, 10u128.pow(33), liability), locked / 3);
+    }
+
+    fn emergency_withdraw(contract: &mut Vault, amount: u128) {
+        testing_env!(get_context(accounts(0), account("vault.testnet")).build());
+        contract.pause_redemptions();
+        let _ = contract.emergency_withdraw_collateral(U128(amount));
+    }
+
+    #[test]
+    fn test_emergency_withdraw_uses_global_recipient_by_default() {
+        let mut contract = setup();
+        deposit(&mut contract, accounts(1), 100);
+        assert_eq!(
+            contract.get_emergency_recipient(account("collateral.testnet")),
+            accounts(0)
+        );
+
+        emergency_withdraw(&mut contract, 10);
+        let logs = near_sdk::test_utils::get_logs();
+        assert!(logs.iter().any(|log| log.contains("emergency_withdrawal")
+            && log.contains(&format!("\"account_id\":\"{}\"", accounts(0)))));
+    }
+
+    #[test]
+    fn test_emergency_withdraw_uses_collateral_override() {
+        let mut contract = setup();
+        deposit(&mut contract, accounts(1), 100);
+
+        testing_env!(get_context(accounts(0), account("vault.testnet")).build());
+        contract.set_collateral_emergency_recipient(
+            account("collateral.testnet"),
+            Some(account("safe.testnet")),
+        );
+        assert_eq!(
+            contract.get_emergency_recipient(account("collateral.testnet")),
+            account("safe.testnet")
+        );
+        assert_eq!(
+            contract.get_emergency_recipient(account("other.testnet")),
+            accounts(0)
+        );
+
+        emergency_withdraw(&mut contract, 10);
+        let logs = near_sdk::test_utils::get_logs();
+        assert!(logs.iter().any(|log| log.contains("emergency_withdrawal")
+            && log.contains("\"account_id\":\"safe.testnet\"")));
+    }
+
+    #[test]
+    fn test_check_invariant_auto_pauses_on_breach() {
+        let mut contract = setup();
+        deposit(&mut contract, accounts(1), 100);
+
+        testing_env!(get_context(accounts(2), account("vault.testnet")).build());
+        assert!(contract.check_invariant());
+        assert!(!contract.get_redemptions_paused());
+
+        // Simulate liability drifting above locked collateral
+        contract.total_minted_liability = 150;
+        assert!(!contract.check_invariant());
+        assert!(contract.get_redemptions_paused());
+        assert!(near_sdk::test_utils::get_logs()
+            .iter()
+            .any(|log| log.contains("invariant_breached")));
+    }
+
+    #[test]
+    fn test_two_step_ownership_transfer() {
+        let mut contract = setup();
+        let vault_account = account("vault.testnet");
+
+        testing_env!(get_context(accounts(0), vault_account.clone()).build());
+        contract.propose_owner(accounts(1));
+        assert_eq!(contract.get_owner(), accounts(0));
+
+        testing_env!(get_context(accounts(1), vault_account).build());
+        contract.accept_ownership();
+        assert_eq!(contract.get_owner(), accounts(1));
+        assert_eq!(contract.get_pending_owner(), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "Only the pending owner can accept ownership")]
+    fn test_accept_ownership_rejects_other_accounts() {
+        let mut contract = setup();
+        let vault_account = account("vault.testnet");
+
+        testing_env!(get_context(accounts(0), vault_account.clone()).build());
+        contract.propose_owner(accounts(1));
+
+        testing_env!(get_context(accounts(2), vault_account).build());
+        contract.accept_ownership();
+    }
+
+    fn price_result(price: Option<i128>) -> PromiseResult {
+        PromiseResult::Successful(near_sdk::serde_json::to_vec(&price).unwrap())
+    }
+
+    #[test]
+    fn test_oracle_price_scales_into_valued_backing() {
+        let mut contract = setup_with_decimals(6, 24);
+        let vault_account = account("vault.testnet");
+        deposit(&mut contract, accounts(1), 2_000_000);
+        assert_eq!(contract.get_valued_backing_ratio_bps(), None);
+
+        testing_env!(get_context(accounts(0), vault_account.clone()).build());
+        contract.set_price_oracle(Some(account("oracle.testnet")), 6);
+        let _ = contract.consume_oracle_price([1u8; 32]);
+
+        // 1.5 NEST per collateral at 6 decimals, cached with 18
+        set_context_with_results(
+            vault_account.clone(),
+            vault_account,
+            vec![price_result(Some(1_500_000))],
+        );
+        let price = contract.on_oracle_price([1u8; 32]);
+        assert_eq!(price.0, 15 * 10u128.pow(17));
+        assert_eq!(contract.get_collateral_price(), Some(price));
+        assert_eq!(contract.get_valued_backing_ratio_bps().unwrap().0, 15_000);
+        assert_eq!(contract.get_backing_ratio_bps().unwrap().0, 10_000);
+        assert!(near_sdk::test_utils::get_logs()
+            .iter()
+            .any(|log| log.contains("collateral_price_updated")));
+    }
+
+    #[test]
+    #[should_panic(expected = "Assertion has no numeric result")]
+    fn test_oracle_price_requires_numeric_result() {
+        let mut contract = setup();
+        let vault_account = account("vault.testnet");
+
+        testing_env!(get_context(accounts(0), vault_account.clone()).build());
+        contract.set_price_oracle(Some(account("oracle.testnet")), 18);
+
+        set_context_with_results(
+            vault_account.clone(),
+            vault_account,
+            vec![price_result(None)],
+        );
+        contract.on_oracle_price([1u8; 32]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Only owner can call this method")]
+    fn test_consume_oracle_price_unauthorized() {
+        let mut contract = setup();
+        testing_env!(get_context(accounts(0), account("vault.testnet")).build());
+        contract.set_price_oracle(Some(account("oracle.testnet")), 18);
+
+        testing_env!(get_context(accounts(1), account("vault.testnet")).build());
+        let _ = contract.consume_oracle_price([1u8; 32]);
+    }
 }
@@ -13,6 +13,7 @@ use near_sdk::json_types::U128;
 use near_sdk::{
     env, near, require, AccountId, BorshStorageKey, NearToken, PanicOnDefault, PromiseOrValue,
 };
+use oracle_types::ownership::PendingOwner;
 
 #[derive(BorshStorageKey, BorshSerialize)]
 #[borsh(crate = "near_sdk::borsh")]
@@ -37,11 +38,14 @@ pub struct VotingToken {
     token: FungibleToken,
     metadata: LazyOption<FungibleTokenMetadata>,
     owner: AccountId,
+    pending_owner: PendingOwner,
     minters: LookupSet<AccountId>,
     burners: LookupSet<AccountId>,
     transfer_whitelist: LookupSet<AccountId>,
     transfer_restricted: bool,
     vault_account: Option<AccountId>,
+    /// Voting contract whose balance counts as staked rather than circulating supply
+    voting_contract: Option<AccountId>,
 }
 
 #[near]
@@ -63,11 +67,13 @@ impl VotingToken {
                 }),
             ),
             owner: owner.clone(),
+            pending_owner: PendingOwner::default(),
             minters: LookupSet::new(StorageKey::Minters),
             burners: LookupSet::new(StorageKey::Burners),
             transfer_whitelist: LookupSet::new(StorageKey::TransferWhitelist),
             transfer_restricted: true,
             vault_account: None,
+            voting_contract: None,
         };
 
         this.token.internal_register_account(&owner);
@@ -143,14 +149,30 @@ impl VotingToken {
         self.transfer_whitelist.remove(&account_id);
     }
 
+    /// Sets or clears the voting contract whose balance is reported as locked supply.
+    /// Does not grant transfer routing; use `add_transfer_router` for that.
+    pub fn set_voting_contract(&mut self, voting_contract: Option<AccountId>) {
+        self.assert_owner();
+        self.voting_contract = voting_contract;
+    }
+
     pub fn set_transfer_restricted(&mut self, restricted: bool) {
         self.assert_owner();
         self.transfer_restricted = restricted;
     }
 
-    pub fn set_owner(&mut self, new_owner: AccountId) {
+    /// Propose a new owner, who takes over once it calls `accept_ownership`.
+    pub fn propose_owner(&mut self, new_owner: AccountId) {
         self.assert_owner();
-        self.owner = new_owner;
+        self.pending_owner.propose(new_owner);
+    }
+
+    pub fn accept_ownership(&mut self) {
+        self.pending_owner.accept(&mut self.owner, "voting_token");
+    }
+
+    pub fn get_pending_owner(&self) -> Option<AccountId> {
+        self.pending_owner.get()
     }
 
     // ==================== Minting & Burning ====================
@@ -212,6 +234,24 @@ impl VotingToken {
         self.vault_account.clone()
     }
 
+    pub fn get_voting_contract(&self) -> Option<AccountId> {
+        self.voting_contract.clone()
+    }
+
+    /// Tokens held by the voting contract (committed stake awaiting resolution and
+    /// unclaimed payouts); zero while no voting contract is set.
+    pub fn get_locked_supply(&self) -> U128 {
+        match &self.voting_contract {
+            Some(voting_contract) => self.token.ft_balance_of(voting_contract.clone()),
+            None => U128(0),
+        }
+    }
+
+    /// Total supply minus the supply locked in the voting contract.
+    pub fn ft_circulating_supply(&self) -> U128 {
+        U128(self.token.ft_total_supply().0 - self.get_locked_supply().0)
+    }
+
     pub fn is_minter(&self, account_id: AccountId) -> bool {
         self.minters.contains(&account_id)
     }
@@ -519,11 +559,48 @@ mod tests {
 
         let mut contract = VotingToken::new(accounts(0), U128(1000));
 
-        contract.set_owner(accounts(1));
-        assert_eq!(contract.get_owner(), accounts(1));
+        contract.propose_owner(accounts(1));
+        assert_eq!(contract.get_owner(), accounts(0));
 
         testing_env!(get_context(accounts(1), NearToken::from_yoctonear(0)).build());
+        contract.accept_ownership();
+        assert_eq!(contract.get_owner(), accounts(1));
         contract.add_minter(accounts(2));
         assert!(contract.is_minter(accounts(2)));
     }
+
+    #[test]
+    #[should_panic(expected = "Only the pending owner can accept ownership")]
+    fn test_accept_ownership_rejects_other_accounts() {
+        testing_env!(get_context(accounts(0), NearToken::from_yoctonear(0)).build());
+        let mut contract = VotingToken::new(accounts(0), U128(1000));
+        contract.propose_owner(accounts(1));
+
+        testing_env!(get_context(accounts(2), NearToken::from_yoctonear(0)).build());
+        contract.accept_ownership();
+    }
+
+    #[test]
+    fn test_circulating_supply_excludes_voting_stake() {
+        testing_env!(get_context(accounts(0), NearToken::from_yoctonear(0)).build());
+        let mut contract = VotingToken::new(accounts(0), U128(1_000));
+        register_account(&mut contract, accounts(0), accounts(1));
+        register_account(&mut contract, accounts(0), accounts(2));
+
+        contract.set_voting_contract(Some(accounts(2)));
+        contract.add_transfer_router(accounts(2));
+        assert_eq!(contract.ft_circulating_supply().0, 1_000);
+
+        testing_env!(get_context(accounts(0), NearToken::from_yoctonear(1)).build());
+        contract.ft_transfer(accounts(2), U128(300), None);
+        assert_eq!(contract.get_locked_supply().0, 300);
+        assert_eq!(contract.ft_circulating_supply().0, 700);
+        assert_eq!(contract.ft_total_supply().0, 1_000);
+
+        // Resolution pays the stake back out of the voting contract
+        testing_env!(get_context(accounts(2), NearToken::from_yoctonear(1)).build());
+        contract.ft_transfer(accounts(1), U128(300), None);
+        assert_eq!(contract.get_locked_supply().0, 0);
+        assert_eq!(contract.ft_circulating_supply().0, 1_000);
+    }
 }
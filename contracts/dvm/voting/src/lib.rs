@@ -1,11 +1,15 @@
 use near_sdk::json_types::U128;
 use near_sdk::serde::{Deserialize, Serialize};
-use near_sdk::store::LookupMap;
+use near_sdk::store::{IterableSet, LookupMap, LookupSet};
 use near_sdk::{
-    env, near, require, AccountId, CryptoHash, Gas, NearToken, PanicOnDefault, Promise,
+    env, near, require, AccountId, CryptoHash, Gas, GasWeight, NearToken, PanicOnDefault, Promise,
+    PromiseError, PromiseResult,
 };
+use std::collections::BTreeMap;
 
-use oracle_types::events::VotingEvent;
+use oracle_types::events::{VotingEvent, VOTING_EVENT_STANDARD};
+use oracle_types::ownership::PendingOwner;
+use oracle_types::types::dvm_request_id;
 
 /// Voting phases for commit-reveal mechanism
 #[near(serializers = [json, borsh])]
@@ -43,6 +47,43 @@ pub enum ResolvePriceOutcome {
     EmergencyRequired,
 }
 
+/// Action taken by `process_request`.
+#[near(serializers = [json, borsh])]
+#[derive(Clone, PartialEq, Debug)]
+pub enum ProcessRequestAction {
+    /// Commit phase had ended; the request moved to reveal phase.
+    AdvancedToReveal,
+    /// Reveal phase had ended; `resolve_price` ran with this outcome.
+    Resolved { outcome: ResolvePriceOutcome },
+    /// No phase transition is due yet, or the request needs no further keeper action.
+    NothingDue,
+}
+
+/// Economic summary of a resolved request's payout
+#[near(serializers = [json, borsh])]
+#[derive(Clone, PartialEq, Debug)]
+pub struct ResolutionOutcome {
+    /// Price the request resolved to
+    pub resolved_price: i128,
+    /// Stake forfeited by losing and unrevealed voters
+    pub total_slashed: U128,
+    /// Slashed stake credited to winning voters on top of their returned stake
+    pub total_rewarded: U128,
+    /// Amount sent to treasury: its slashed-stake share net of the resolver reward,
+    /// plus resolution fees and any reward-pool rounding remainder
+    pub treasury_cut: U128,
+}
+
+/// How far a revealed price may sit from the resolved price and still count as a winning vote
+#[near(serializers = [json, borsh])]
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum SlashTolerance {
+    /// Votes within this absolute distance of the resolved price win
+    Absolute { max_deviation: u128 },
+    /// Votes within this share of the resolved price (basis points) win
+    Bps { bps: u16 },
+}
+
 /// A price request that needs to be resolved by voting
 #[near(serializers = [json, borsh])]
 #[derive(Clone)]
@@ -71,13 +112,54 @@ pub struct PriceRequest {
     pub low_participation_extensions: u8,
     /// Whether this request is blocked pending emergency resolution
     pub emergency_required: bool,
+    /// Additional reveal time granted by the owner (nanoseconds)
+    pub reveal_extension_ns: u64,
+    /// When the most recent commitment was received (nanoseconds)
+    pub last_commit_time: u64,
+    /// Whether the request was resolved by the owner, leaving committed stake refundable
+    pub emergency_resolved: bool,
+    /// Commit phase duration for this request, overriding the contract default (nanoseconds)
+    pub commit_duration_ns: Option<u64>,
+    /// Reveal phase duration for this request, overriding the contract default (nanoseconds)
+    pub reveal_duration_ns: Option<u64>,
+    /// Whether this is a YES/NO request, whose votes must be 0 or NUMERICAL_TRUE
+    pub binary: bool,
+    /// Token stake on this request was committed in, pinned at the first commitment so
+    /// payouts stay in that token if the voting token is rotated
+    pub voting_token: Option<AccountId>,
+    /// `min_participation_rate` (basis points) in force when the request was created;
+    /// later changes to the contract-wide rate do not apply to this request
+    pub min_participation_rate: u64,
+    /// Account sent `price_resolved_callback(request_id, resolved_price)` when the request
+    /// resolves; the requester unless changed with `set_resolution_callback`
+    pub resolution_callback: Option<AccountId>,
+    /// Additional commit time granted by the owner (nanoseconds)
+    pub commit_extension_ns: u64,
+}
+
+/// A price request with its phase deadlines and open/closed flags precomputed for front-ends
+#[near(serializers = [json, borsh])]
+#[derive(Clone)]
+pub struct PriceRequestView {
+    /// The stored request
+    pub request: PriceRequest,
+    /// When the commit phase ends, using the request's own commit duration if set (nanoseconds)
+    pub commit_deadline_ns: u64,
+    /// When the reveal phase ends, including extensions; None until the reveal phase starts
+    pub reveal_deadline_ns: Option<u64>,
+    /// Whether `commit` would currently be accepted
+    pub is_commit_open: bool,
+    /// Whether `reveal_vote` would currently be accepted
+    pub is_reveal_open: bool,
+    /// Whether a keeper can call `resolve_price` now
+    pub is_resolvable: bool,
 }
 
 /// A voter's commitment for a specific request
 #[near(serializers = [json, borsh])]
 #[derive(Clone)]
 pub struct VoteCommitment {
-    /// Hash of (price, salt)
+    /// Hash of (request_id, price, salt)
     pub commit_hash: CryptoHash,
     /// The voter's staked amount at time of commitment
     pub staked_amount: u128,
@@ -87,6 +169,15 @@ pub struct VoteCommitment {
     pub revealed_price: Option<i128>,
 }
 
+/// Arguments of `price_resolved_callback`, serialized directly so prices outside the
+/// 64-bit range survive JSON encoding
+#[derive(Serialize)]
+#[serde(crate = "near_sdk::serde")]
+struct PriceResolvedCallbackArgs<'a> {
+    request_id: &'a CryptoHash,
+    resolved_price: i128,
+}
+
 #[derive(Serialize, Deserialize)]
 #[serde(crate = "near_sdk::serde")]
 #[serde(tag = "action")]
@@ -116,15 +207,39 @@ pub struct Voting {
     /// Contract owner
     owner: AccountId,
 
+    /// Account proposed as the next owner, pending its `accept_ownership`
+    pending_owner: PendingOwner,
+
     /// Duration of commit phase in nanoseconds
     commit_phase_duration: u64,
 
     /// Duration of reveal phase in nanoseconds
     reveal_phase_duration: u64,
 
+    /// Shortest per-request commit/reveal duration accepted by `request_price` (nanoseconds)
+    min_phase_duration: u64,
+
+    /// Longest per-request commit/reveal duration accepted by `request_price` (nanoseconds)
+    max_phase_duration: u64,
+
+    /// Longest `ancillary_data` accepted by `request_price` (bytes)
+    max_ancillary_data_len: u64,
+
+    /// Accounts allowed to call `request_price`; anyone may request while empty
+    authorized_requesters: LookupSet<AccountId>,
+
+    /// Number of accounts in `authorized_requesters`
+    authorized_requester_count: u64,
+
     /// Minimum participation required (basis points, e.g., 500 = 5%)
     min_participation_rate: u64,
 
+    /// Minimum number of distinct committers required, independent of stake
+    min_committers: u64,
+
+    /// Minimum number of distinct committers before `advance_to_reveal` is permitted
+    min_commits_to_advance: u64,
+
     /// Price requests by request_id (hash of identifier + timestamp + ancillary_data)
     requests: LookupMap<CryptoHash, PriceRequest>,
 
@@ -137,6 +252,9 @@ pub struct Voting {
     /// Ordered list of voters per request (required for deterministic resolution)
     request_voters: LookupMap<CryptoHash, Vec<AccountId>>,
 
+    /// Unresolved requests each voter has committed to, pruned on resolution
+    voter_requests: LookupMap<AccountId, Vec<CryptoHash>>,
+
     /// Voting token (NEP-141) used for stake locking
     voting_token: Option<AccountId>,
 
@@ -146,18 +264,90 @@ pub struct Voting {
     /// Portion of slashed stake routed to treasury (bps)
     slashing_treasury_bps: u16,
 
+    /// Portion of slashed stake permanently burned (bps)
+    slashing_burn_bps: u16,
+
+    /// Reward paid from the treasury cut to whoever resolves a request
+    resolver_reward: u128,
+
+    /// Fee taken from each winner's returned stake and routed to treasury (bps)
+    resolution_fee_bps: u16,
+
+    /// Per-identifier band around the resolved price within which votes are not slashed;
+    /// identifiers without an entry require an exact match
+    slash_tolerances: LookupMap<String, SlashTolerance>,
+
+    /// Payout summary per resolved request, recorded when stake is distributed or the
+    /// request is emergency-resolved; kept by `cleanup_request`
+    resolution_outcomes: LookupMap<CryptoHash, ResolutionOutcome>,
+
+    /// Stake and reward each winning voter can withdraw with `claim_reward`,
+    /// recorded at resolution
+    claimable_rewards: LookupMap<(CryptoHash, AccountId), u128>,
+
+    /// Requests flagged `emergency_required`, awaiting `emergency_resolve_price`
+    emergency_pending: IterableSet<CryptoHash>,
+
+    /// Payouts whose token transfer failed, per request and recipient, awaiting
+    /// `retry_transfer`: the token and amount owed, kept apart from the request so a
+    /// retry still works once the request is cleaned up
+    failed_transfers: LookupMap<(CryptoHash, AccountId), (AccountId, u128)>,
+
     /// Maximum automatic reveal extensions before emergency path
     max_low_participation_extensions: u8,
 
     /// Next request nonce for generating unique IDs
     request_nonce: u64,
+
+    /// Whether `advance_to_reveal` may run before the commit duration elapses
+    allow_early_reveal_advance: bool,
+
+    /// Stake expected to commit on a request, used for early advancement
+    early_reveal_expected_stake: u128,
+
+    /// Fraction of expected stake that must be committed to advance early (bps)
+    early_reveal_threshold_bps: u16,
+
+    /// Time without new commitments required before advancing early (nanoseconds)
+    early_reveal_quiet_window: u64,
+
+    /// Whether `resolve_price` may run before the reveal deadline once all committed
+    /// stake has revealed
+    allow_early_resolve_on_full_reveal: bool,
+
+    /// Stake committed and not yet paid back out, per voting token (requests stay pinned
+    /// to the token they were committed with); anything above this in the contract's
+    /// balance of that token is rescuable
+    locked_stake: LookupMap<AccountId, u128>,
 }
 
 /// Default phase durations
 const DEFAULT_COMMIT_DURATION: u64 = 24 * 60 * 60 * 1_000_000_000; // 24 hours in nanoseconds
 const DEFAULT_REVEAL_DURATION: u64 = 24 * 60 * 60 * 1_000_000_000; // 24 hours in nanoseconds
+/// Default bounds for per-request phase durations
+const DEFAULT_MIN_PHASE_DURATION: u64 = 60 * 60 * 1_000_000_000; // 1 hour in nanoseconds
+const DEFAULT_MAX_PHASE_DURATION: u64 = 7 * 24 * 60 * 60 * 1_000_000_000; // 7 days in nanoseconds
+/// Default ancillary data limit; oracle disputes send a 32-byte assertion id plus an
+/// optional evidence URI of up to 256 bytes
+const DEFAULT_MAX_ANCILLARY_DATA_LEN: u64 = 1024;
+/// Smallest ancillary data limit the owner may set, so oracle assertion ids always fit
+const MIN_ANCILLARY_DATA_LEN_LIMIT: u64 = 32;
 const BASIS_POINTS_DENOMINATOR: u64 = 10_000;
+/// Storage prefix tag for per-request commitment maps, followed by the request id.
+/// Reserved: no top-level collection uses it, so inner-map keys never share a prefix
+/// with outer-map keys whatever bytes a request id starts with
+const COMMITMENTS_PREFIX_TAG: u8 = b'n';
+/// Numerical representation of "true" in votes on binary requests
+const NUMERICAL_TRUE: i128 = 1_000_000_000_000_000_000; // 1e18
+/// Identifiers whose votes are 0 or NUMERICAL_TRUE, resolved by tallying instead of sorting
+const BINARY_IDENTIFIERS: [&str; 2] = ["ASSERT_TRUTH", "YES_OR_NO_QUERY"];
 const GAS_FOR_FT_TRANSFER: Gas = Gas::from_tgas(10);
+const GAS_FOR_TRANSFER_CALLBACK: Gas = Gas::from_tgas(10);
+const GAS_FOR_BURN: Gas = Gas::from_tgas(10);
+const GAS_FOR_BALANCE_CHECK: Gas = Gas::from_tgas(5);
+const GAS_FOR_RESCUE_CALLBACK: Gas = Gas::from_tgas(20);
+/// Minimum gas for the resolution callback; it also receives any gas left unused
+const GAS_FOR_RESOLUTION_CALLBACK: Gas = Gas::from_tgas(5);
 
 #[near]
 impl Voting {
@@ -169,18 +359,41 @@ impl Voting {
     pub fn new(owner: AccountId) -> Self {
         Self {
             owner,
+            pending_owner: PendingOwner::default(),
             commit_phase_duration: DEFAULT_COMMIT_DURATION,
             reveal_phase_duration: DEFAULT_REVEAL_DURATION,
+            min_phase_duration: DEFAULT_MIN_PHASE_DURATION,
+            max_phase_duration: DEFAULT_MAX_PHASE_DURATION,
+            max_ancillary_data_len: DEFAULT_MAX_ANCILLARY_DATA_LEN,
+            authorized_requesters: LookupSet::new(b"a"),
+            authorized_requester_count: 0,
             min_participation_rate: 500, // 5% default
+            min_committers: 1,
+            min_commits_to_advance: 0,
             requests: LookupMap::new(b"r"),
             commitments: LookupMap::new(b"c"),
             total_committed_stake: LookupMap::new(b"s"),
             request_voters: LookupMap::new(b"v"),
+            voter_requests: LookupMap::new(b"o"),
             voting_token: None,
             treasury: None,
             slashing_treasury_bps: 5_000, // 50%
+            slashing_burn_bps: 0,
+            resolver_reward: 0,
+            resolution_fee_bps: 0,
+            slash_tolerances: LookupMap::new(b"t"),
+            resolution_outcomes: LookupMap::new(b"u"),
+            claimable_rewards: LookupMap::new(b"w"),
+            emergency_pending: IterableSet::new(b"e"),
+            failed_transfers: LookupMap::new(b"f"),
             max_low_participation_extensions: 1,
             request_nonce: 0,
+            allow_early_reveal_advance: false,
+            early_reveal_expected_stake: 0,
+            early_reveal_threshold_bps: BASIS_POINTS_DENOMINATOR as u16,
+            early_reveal_quiet_window: 0,
+            allow_early_resolve_on_full_reveal: false,
+            locked_stake: LookupMap::new(b"l"),
         }
     }
 
@@ -193,6 +406,9 @@ impl Voting {
     /// * `identifier` - The price identifier (e.g., "YES_OR_NO_QUERY")
     /// * `timestamp` - The timestamp for the price
     /// * `ancillary_data` - Additional data (e.g., the assertion claim)
+    /// * `commit_duration_ns` - Optional commit phase duration for this request
+    /// * `reveal_duration_ns` - Optional reveal phase duration for this request
+    /// * `binary` - Restrict revealed prices to 0 or NUMERICAL_TRUE (default false)
     ///
     /// # Returns
     /// The request_id for tracking this vote
@@ -201,8 +417,28 @@ impl Voting {
         identifier: String,
         timestamp: u64,
         ancillary_data: Vec<u8>,
+        commit_duration_ns: Option<u64>,
+        reveal_duration_ns: Option<u64>,
+        binary: Option<bool>,
     ) -> CryptoHash {
         let requester = env::predecessor_account_id();
+        require!(
+            self.is_authorized_requester(requester.clone()),
+            "Requester not authorized"
+        );
+        require!(
+            ancillary_data.len() as u64 <= self.max_ancillary_data_len,
+            "Ancillary data too long"
+        );
+        for duration in [commit_duration_ns, reveal_duration_ns]
+            .into_iter()
+            .flatten()
+        {
+            require!(
+                duration >= self.min_phase_duration && duration <= self.max_phase_duration,
+                "Phase duration out of bounds"
+            );
+        }
 
         // Generate request ID
         let request_id = self.generate_request_id(&identifier, timestamp, &ancillary_data);
@@ -226,13 +462,25 @@ impl Voting {
             revealed_stake: 0,
             low_participation_extensions: 0,
             emergency_required: false,
+            reveal_extension_ns: 0,
+            last_commit_time: 0,
+            emergency_resolved: false,
+            commit_duration_ns,
+            reveal_duration_ns,
+            binary: binary.unwrap_or(false),
+            voting_token: None,
+            min_participation_rate: self.min_participation_rate,
+            resolution_callback: Some(requester.clone()),
+            commit_extension_ns: 0,
         };
 
         self.requests.insert(request_id, request);
 
         // Initialize commitments map for this request
-        self.commitments
-            .insert(request_id, LookupMap::new(request_id.as_ref()));
+        self.commitments.insert(
+            request_id,
+            LookupMap::new(Self::commitments_prefix(&request_id)),
+        );
         self.total_committed_stake.insert(request_id, 0);
         self.request_voters.insert(request_id, Vec::new());
 
@@ -251,11 +499,11 @@ impl Voting {
     }
 
     /// Commit a vote for a price request.
-    /// The vote is encrypted as hash(price, salt).
+    /// The vote is encrypted as hash(request_id, price, salt).
     ///
     /// # Arguments
     /// * `request_id` - The price request ID
-    /// * `commit_hash` - Hash of (price, salt)
+    /// * `commit_hash` - Hash of (request_id, price, salt)
     /// * `staked_amount` - Amount of voting tokens staked for this vote
     pub fn commit_vote(
         &mut self,
@@ -284,7 +532,7 @@ impl Voting {
                 request_id,
                 commit_hash,
             } => {
-                self.internal_commit_vote(request_id, sender_id, commit_hash, amount.0);
+                self.internal_commit_vote(request_id, sender_id, commit_hash, amount.0, token);
             }
         }
 
@@ -297,16 +545,30 @@ impl Voting {
         voter: AccountId,
         commit_hash: CryptoHash,
         staked_amount: u128,
+        token: AccountId,
     ) {
-        let request = self.requests.get(&request_id).expect("Request not found");
+        let request = self
+            .requests
+            .get_mut(&request_id)
+            .expect("Request not found");
         require!(request.phase == VotingPhase::Commit, "Not in commit phase");
+        let pinned_token = request.voting_token.get_or_insert_with(|| token.clone());
+        require!(
+            *pinned_token == token,
+            "Request is pinned to a different voting token"
+        );
 
         // Check commit phase hasn't expired
         let now = env::block_timestamp();
         require!(
-            now < request.commit_start_time + self.commit_phase_duration,
+            now < request.commit_start_time
+                + request
+                    .commit_duration_ns
+                    .unwrap_or(self.commit_phase_duration)
+                + request.commit_extension_ns,
             "Commit phase has ended"
         );
+        request.last_commit_time = now;
 
         // Get or create commitments map for this request
         let commitments = self
@@ -333,6 +595,12 @@ impl Voting {
             .get_mut(&request_id)
             .expect("Voter list not initialized");
         voters.push(voter.clone());
+        match self.voter_requests.get_mut(&voter) {
+            Some(open_requests) => open_requests.push(request_id),
+            None => {
+                self.voter_requests.insert(voter.clone(), vec![request_id]);
+            }
+        }
 
         // Update total stake
         let total = self
@@ -342,6 +610,7 @@ impl Voting {
             .unwrap_or(0);
         self.total_committed_stake
             .insert(request_id, total + staked_amount);
+        self.lock_stake(&token, staked_amount);
 
         VotingEvent::VoteCommitted {
             request_id: &request_id,
@@ -352,7 +621,8 @@ impl Voting {
     }
 
     /// Advance a request from commit phase to reveal phase.
-    /// Can be called by anyone after commit phase duration has passed.
+    /// Can be called by anyone after commit phase duration has passed, or earlier
+    /// when early advancement is enabled and its stake and quiet-window conditions hold.
     ///
     /// # Arguments
     /// * `request_id` - The price request ID
@@ -367,12 +637,36 @@ impl Voting {
 
         let now = env::block_timestamp();
         require!(
-            now >= request.commit_start_time + self.commit_phase_duration,
+            now >= request.commit_start_time + self.commit_duration(&request)
+                || self.can_advance_early(&request_id, &request, now),
             "Commit phase not yet ended"
         );
+        // Too few committers: the owner extends the commit phase or routes to emergency
+        require!(
+            self.committer_count(&request_id) >= self.min_commits_to_advance,
+            "Not enough commits to advance"
+        );
 
         request.phase = VotingPhase::Reveal;
         request.reveal_start_time = now;
+
+        // Nothing can be revealed or resolved without stake; hand the request to the
+        // emergency path rather than letting `resolve_price` fail on it later
+        let total_committed = self
+            .total_committed_stake
+            .get(&request_id)
+            .copied()
+            .unwrap_or(0);
+        if total_committed == 0 {
+            request.emergency_required = true;
+            self.requests.insert(request_id, request);
+            self.emergency_pending.insert(request_id);
+            VotingEvent::RequestExpiredNoCommits {
+                request_id: &request_id,
+            }
+            .emit();
+            return;
+        }
         self.requests.insert(request_id, request);
 
         VotingEvent::RevealPhaseStarted {
@@ -390,20 +684,52 @@ impl Voting {
     /// * `salt` - The salt used in the commitment
     pub fn reveal_vote(&mut self, request_id: CryptoHash, price: i128, salt: CryptoHash) {
         let voter = env::predecessor_account_id();
+        self.internal_reveal_vote(request_id, voter, price, salt);
+    }
+
+    /// Reveal votes for several requests in a single transaction.
+    /// Every reveal is made on behalf of the caller; any failing reveal aborts the batch.
+    ///
+    /// # Arguments
+    /// * `reveals` - List of (request_id, price, salt) tuples
+    pub fn reveal_votes(&mut self, reveals: Vec<(CryptoHash, i128, CryptoHash)>) {
+        require!(!reveals.is_empty(), "No reveals provided");
+        let voter = env::predecessor_account_id();
+        for (request_id, price, salt) in reveals {
+            self.internal_reveal_vote(request_id, voter.clone(), price, salt);
+        }
+    }
 
+    fn internal_reveal_vote(
+        &mut self,
+        request_id: CryptoHash,
+        voter: AccountId,
+        price: i128,
+        salt: CryptoHash,
+    ) {
         // Verify request exists and is in reveal phase
         let request = self.requests.get(&request_id).expect("Request not found");
         require!(request.phase == VotingPhase::Reveal, "Not in reveal phase");
+        require!(
+            !request.emergency_required,
+            "Request awaits emergency resolution"
+        );
 
         // Check reveal phase hasn't expired
         let now = env::block_timestamp();
         require!(
-            now < request.reveal_start_time + self.reveal_phase_duration,
+            now < self.reveal_deadline(request),
             "Reveal phase has ended"
         );
+        if request.binary {
+            require!(
+                price == 0 || price == NUMERICAL_TRUE,
+                "Binary requests only accept 0 or NUMERICAL_TRUE"
+            );
+        }
 
         // Compute the expected hash first (before borrowing commitments mutably)
-        let computed_hash = Self::compute_vote_hash_static(price, salt);
+        let computed_hash = Self::compute_vote_hash_static(&request_id, price, salt);
 
         // Get commitment
         let commitments = self
@@ -416,7 +742,11 @@ impl Voting {
             .expect("No commitment found")
             .clone();
 
-        require!(!commitment.revealed, "Already revealed");
+        // Either marker means the stake already counts toward `revealed_stake`
+        require!(
+            !commitment.revealed && commitment.revealed_price.is_none(),
+            "Already revealed"
+        );
 
         // Verify the commitment hash
         require!(
@@ -457,10 +787,14 @@ impl Voting {
             .clone();
 
         require!(request.phase == VotingPhase::Reveal, "Not in reveal phase");
+        require!(
+            !request.emergency_required,
+            "Request awaits emergency resolution"
+        );
 
         let now = env::block_timestamp();
         require!(
-            now >= request.reveal_start_time + self.reveal_phase_duration,
+            now >= self.reveal_deadline(&request) || self.can_resolve_early(&request_id, &request),
             "Reveal phase not yet ended"
         );
 
@@ -472,10 +806,15 @@ impl Voting {
         require!(total_committed > 0, "No committed stake");
 
         let required_participation = total_committed
-            .saturating_mul(self.min_participation_rate as u128)
+            .saturating_mul(request.min_participation_rate as u128)
             / BASIS_POINTS_DENOMINATOR as u128;
+        let committers = self
+            .request_voters
+            .get(&request_id)
+            .map(|voters| voters.len() as u64)
+            .unwrap_or(0);
 
-        if request.revealed_stake < required_participation {
+        if request.revealed_stake < required_participation || committers < self.min_committers {
             let committed_u128 = U128(total_committed);
             let revealed_u128 = U128(request.revealed_stake);
             let required_u128 = U128(required_participation);
@@ -488,6 +827,8 @@ impl Voting {
                     committed_stake: &committed_u128,
                     revealed_stake: &revealed_u128,
                     required_stake: &required_u128,
+                    committers,
+                    required_committers: self.min_committers,
                     emergency_required: false,
                 }
                 .emit();
@@ -495,47 +836,62 @@ impl Voting {
             }
             request.emergency_required = true;
             self.requests.insert(request_id, request);
+            self.emergency_pending.insert(request_id);
             VotingEvent::LowParticipationTriggered {
                 request_id: &request_id,
                 committed_stake: &committed_u128,
                 revealed_stake: &revealed_u128,
                 required_stake: &required_u128,
+                committers,
+                required_committers: self.min_committers,
                 emergency_required: true,
             }
             .emit();
             return ResolvePriceOutcome::EmergencyRequired;
         }
 
-        let commitments = self
-            .commitments
-            .get(&request_id)
-            .expect("Commitments not initialized");
-        let voters = self
-            .request_voters
-            .get(&request_id)
-            .expect("Voter list not initialized")
-            .clone();
-
-        let mut revealed_votes: Vec<(i128, u128, AccountId)> = Vec::new();
-        for voter in voters {
-            if let Some(commitment) = commitments.get(&voter) {
-                if commitment.revealed {
-                    if let Some(price) = commitment.revealed_price {
-                        revealed_votes.push((price, commitment.staked_amount, voter.clone()));
-                    }
-                }
+        let mut revealed_votes = self.revealed_votes(&request_id);
+        if revealed_votes.is_empty() {
+            // Participation can pass on committed stake alone (e.g. a zero rate) with no
+            // reveals; route to emergency resolution instead of leaving the request stuck.
+            request.emergency_required = true;
+            self.requests.insert(request_id, request);
+            self.emergency_pending.insert(request_id);
+            VotingEvent::LowParticipationTriggered {
+                request_id: &request_id,
+                committed_stake: &U128(total_committed),
+                revealed_stake: &U128(0),
+                required_stake: &U128(required_participation),
+                committers,
+                required_committers: self.min_committers,
+                emergency_required: true,
             }
+            .emit();
+            return ResolvePriceOutcome::EmergencyRequired;
+        }
+        let resolved_price = Self::resolve_votes(&request.identifier, &mut revealed_votes);
+        if let Some(total_claimable) =
+            self.distribute_rewards_and_slashing(&request_id, resolved_price, &revealed_votes)
+        {
+            // Winners' stake and rewards stay locked until claimed
+            let voting_token = self
+                .request_voting_token(&request)
+                .expect("Voting token not configured");
+            self.unlock_stake(
+                &voting_token,
+                total_committed.saturating_sub(total_claimable),
+            );
         }
-
-        require!(!revealed_votes.is_empty(), "No revealed votes");
-        let resolved_price = Self::stake_weighted_median(&mut revealed_votes);
-        self.distribute_rewards_and_slashing(&request_id, resolved_price, &revealed_votes);
 
         request.phase = VotingPhase::Resolved;
         request.status = RequestStatus::Resolved;
         request.resolved_price = Some(resolved_price);
         request.emergency_required = false;
+        let resolution_callback = request.resolution_callback.clone();
         self.requests.insert(request_id, request);
+        self.emergency_pending.remove(&request_id);
+        self.prune_voter_requests(&request_id);
+        Self::notify_resolution_callback(resolution_callback, &request_id, resolved_price);
 
         let total_stake = self.get_total_committed_stake(request_id);
         VotingEvent::PriceResolved {
@@ -550,6 +906,52 @@ impl Voting {
         }
     }
 
+    /// Keeper entry point: advance to reveal if the commit phase has ended, or resolve if
+    /// the reveal phase has ended. `advance_to_reveal` and `resolve_price` remain available.
+    ///
+    /// # Arguments
+    /// * `request_id` - The price request ID
+    ///
+    /// # Returns
+    /// The action taken, or `NothingDue` if no transition was due.
+    pub fn process_request(&mut self, request_id: CryptoHash) -> ProcessRequestAction {
+        let request = self
+            .requests
+            .get(&request_id)
+            .expect("Request not found")
+            .clone();
+        let now = env::block_timestamp();
+
+        match request.phase {
+            VotingPhase::Commit => {
+                // Requests short of `min_commits_to_advance` need the owner, not a keeper
+                if (now >= request.commit_start_time + self.commit_duration(&request)
+                    || self.can_advance_early(&request_id, &request, now))
+                    && self.committer_count(&request_id) >= self.min_commits_to_advance
+                {
+                    self.advance_to_reveal(request_id);
+                    ProcessRequestAction::AdvancedToReveal
+                } else {
+                    ProcessRequestAction::NothingDue
+                }
+            }
+            VotingPhase::Reveal => {
+                // Requests awaiting emergency resolution need the owner, not a keeper
+                if !request.emergency_required
+                    && (now >= self.reveal_deadline(&request)
+                        || self.can_resolve_early(&request_id, &request))
+                {
+                    ProcessRequestAction::Resolved {
+                        outcome: self.resolve_price(request_id),
+                    }
+                } else {
+                    ProcessRequestAction::NothingDue
+                }
+            }
+            VotingPhase::Resolved => ProcessRequestAction::NothingDue,
+        }
+    }
+
     // ==================== View Functions ====================
 
     /// Get a price request by ID.
@@ -557,11 +959,66 @@ impl Voting {
         self.requests.get(&request_id).cloned()
     }
 
+    /// Change (or clear with None) the account notified when a request resolves.
+    /// Only the request's requester can call, before the request resolves.
+    pub fn set_resolution_callback(&mut self, request_id: CryptoHash, callback: Option<AccountId>) {
+        let request = self
+            .requests
+            .get_mut(&request_id)
+            .expect("Request not found");
+        require!(
+            env::predecessor_account_id() == request.requester,
+            "Only the requester can set the resolution callback"
+        );
+        require!(
+            request.phase != VotingPhase::Resolved,
+            "Request already resolved"
+        );
+        request.resolution_callback = callback;
+    }
+
+    /// Get a price request with its deadlines and phase flags computed at the current block.
+    pub fn get_request_view(&self, request_id: CryptoHash) -> Option<PriceRequestView> {
+        let request = self.requests.get(&request_id)?;
+        let now = env::block_timestamp();
+        let commit_deadline_ns = request.commit_start_time + self.commit_duration(request);
+        let reveal_deadline_ns =
+            (request.phase != VotingPhase::Commit).then(|| self.reveal_deadline(request));
+        let in_reveal_window = request.phase == VotingPhase::Reveal
+            && reveal_deadline_ns.is_some_and(|deadline| now < deadline);
+
+        Some(PriceRequestView {
+            request: request.clone(),
+            commit_deadline_ns,
+            reveal_deadline_ns,
+            is_commit_open: request.phase == VotingPhase::Commit && now < commit_deadline_ns,
+            is_reveal_open: in_reveal_window,
+            is_resolvable: request.phase == VotingPhase::Reveal
+                && !request.emergency_required
+                && (!in_reveal_window || self.can_resolve_early(&request_id, request)),
+        })
+    }
+
     /// Get the resolved price for a request.
     pub fn get_price(&self, request_id: CryptoHash) -> Option<i128> {
         self.requests
             .get(&request_id)
             .and_then(|r| r.resolved_price)
+            .or_else(|| self.resolved_price_after_cleanup(&request_id))
+    }
+
+    /// Get a request's result with what a consumer needs to interpret it:
+    /// (resolved price, whether the request is binary, whether it has resolved).
+    /// The price is 0 until the request resolves. Returns None for unknown requests,
+    /// including those removed by `cleanup_request`.
+    pub fn get_request_result(&self, request_id: CryptoHash) -> Option<(i128, bool, bool)> {
+        self.requests.get(&request_id).map(|request| {
+            (
+                request.resolved_price.unwrap_or(0),
+                request.binary,
+                request.status == RequestStatus::Resolved,
+            )
+        })
     }
 
     /// Check if a price has been resolved.
@@ -569,7 +1026,7 @@ impl Voting {
         self.requests
             .get(&request_id)
             .map(|r| r.status == RequestStatus::Resolved)
-            .unwrap_or(false)
+            .unwrap_or_else(|| self.resolved_price_after_cleanup(&request_id).is_some())
     }
 
     /// Get the current phase for a request.
@@ -577,6 +1034,164 @@ impl Voting {
         self.requests.get(&request_id).map(|r| r.phase)
     }
 
+    /// Check whether `reveal_vote(request_id, price, salt)` would currently succeed for `voter`.
+    /// Lets clients verify a (price, salt) pair against the stored commitment before spending gas.
+    pub fn would_reveal_succeed(
+        &self,
+        request_id: CryptoHash,
+        voter: AccountId,
+        price: i128,
+        salt: CryptoHash,
+    ) -> bool {
+        let Some(request) = self.requests.get(&request_id) else {
+            return false;
+        };
+        if request.phase != VotingPhase::Reveal
+            || env::block_timestamp() >= self.reveal_deadline(request)
+        {
+            return false;
+        }
+        let Some(commitment) = self
+            .commitments
+            .get(&request_id)
+            .and_then(|commitments| commitments.get(&voter))
+        else {
+            return false;
+        };
+        !commitment.revealed
+            && Self::compute_vote_hash_static(&request_id, price, salt) == commitment.commit_hash
+    }
+
+    /// Preview resolution over the votes revealed so far: (projected price, stake that would
+    /// be slashed). Advisory only; later reveals and participation checks can change the outcome.
+    pub fn preview_resolution(&self, request_id: CryptoHash) -> (i128, U128) {
+        let request = self.requests.get(&request_id).expect("Request not found");
+        let mut revealed_votes = self.revealed_votes(&request_id);
+        require!(!revealed_votes.is_empty(), "No revealed votes");
+        let projected_price = Self::resolve_votes(&request.identifier, &mut revealed_votes);
+        (
+            projected_price,
+            U128(self.total_slashed(&request_id, projected_price)),
+        )
+    }
+
+    /// Get revealed stake as a share of committed stake, in basis points.
+    /// Returns 0 for unknown requests or when nothing has been committed.
+    pub fn get_participation_ratio_bps(&self, request_id: CryptoHash) -> u64 {
+        let Some(request) = self.requests.get(&request_id) else {
+            return 0;
+        };
+        let total_committed = self
+            .total_committed_stake
+            .get(&request_id)
+            .copied()
+            .unwrap_or(0);
+        if total_committed == 0 {
+            return 0;
+        }
+        (request
+            .revealed_stake
+            .saturating_mul(BASIS_POINTS_DENOMINATOR as u128)
+            / total_committed) as u64
+    }
+
+    /// Check whether current reveals would satisfy the participation checks in `resolve_price`
+    /// (`min_participation_rate` and `min_committers`), i.e. resolution would not be extended
+    /// or escalated to emergency resolution.
+    pub fn is_participation_sufficient(&self, request_id: CryptoHash) -> bool {
+        let Some(request) = self.requests.get(&request_id) else {
+            return false;
+        };
+        let total_committed = self
+            .total_committed_stake
+            .get(&request_id)
+            .copied()
+            .unwrap_or(0);
+        if total_committed == 0 {
+            return false;
+        }
+        let required_participation = total_committed
+            .saturating_mul(request.min_participation_rate as u128)
+            / BASIS_POINTS_DENOMINATOR as u128;
+        let committers = self
+            .request_voters
+            .get(&request_id)
+            .map(|voters| voters.len() as u64)
+            .unwrap_or(0);
+        request.revealed_stake >= required_participation && committers >= self.min_committers
+    }
+
+    /// Get unresolved requests `voter` has committed to, i.e. those still awaiting
+    /// the voter's reveal or the request's resolution.
+    pub fn get_voter_open_requests(
+        &self,
+        voter: AccountId,
+        from_index: u64,
+        limit: u64,
+    ) -> Vec<CryptoHash> {
+        let Some(open_requests) = self.voter_requests.get(&voter) else {
+            return Vec::new();
+        };
+        open_requests
+            .iter()
+            .filter(|request_id| {
+                self.requests
+                    .get(*request_id)
+                    .map(|r| r.status != RequestStatus::Resolved)
+                    .unwrap_or(false)
+            })
+            .skip(from_index as usize)
+            .take(limit as usize)
+            .copied()
+            .collect()
+    }
+
+    /// Get requests flagged for emergency resolution that the owner has not yet resolved.
+    pub fn get_emergency_pending(&self, from_index: u64, limit: u64) -> Vec<CryptoHash> {
+        self.emergency_pending
+            .iter()
+            .skip(from_index as usize)
+            .take(limit as usize)
+            .copied()
+            .collect()
+    }
+
+    /// Get revealed stake aggregated by price, ascending, over the request's voters
+    /// `[from_index, from_index + limit)` in commit order. Page through large voter sets and
+    /// sum the per-price totals across pages.
+    pub fn get_revealed_vote_distribution(
+        &self,
+        request_id: CryptoHash,
+        from_index: u64,
+        limit: u64,
+    ) -> Vec<(i128, U128)> {
+        let (Some(commitments), Some(voters)) = (
+            self.commitments.get(&request_id),
+            self.request_voters.get(&request_id),
+        ) else {
+            return Vec::new();
+        };
+        let mut stake_by_price: BTreeMap<i128, u128> = BTreeMap::new();
+        for voter in voters.iter().skip(from_index as usize).take(limit as usize) {
+            if let Some(commitment) = commitments.get(voter) {
+                if let (true, Some(price)) = (commitment.revealed, commitment.revealed_price) {
+                    let total = stake_by_price.entry(price).or_insert(0);
+                    *total = total.saturating_add(commitment.staked_amount);
+                }
+            }
+        }
+        stake_by_price
+            .into_iter()
+            .map(|(price, stake)| (price, U128(stake)))
+            .collect()
+    }
+
+    /// Get the payout summary of a resolved request, if its stake was distributed.
+    /// Emergency-resolved requests report zero slashed, rewarded and treasury amounts.
+    pub fn get_resolution_outcome(&self, request_id: CryptoHash) -> Option<ResolutionOutcome> {
+        self.resolution_outcomes.get(&request_id).cloned()
+    }
+
     /// Get total committed stake for a request.
     pub fn get_total_committed_stake(&self, request_id: CryptoHash) -> U128 {
         U128(
@@ -603,7 +1218,8 @@ impl Voting {
         self.reveal_phase_duration = duration_ns;
     }
 
-    /// Set minimum participation rate.
+    /// Set minimum participation rate for requests created from now on; open requests
+    /// keep the rate snapshotted when they were created.
     /// Only owner can call.
     pub fn set_min_participation_rate(&mut self, rate_bps: u64) {
         self.assert_owner();
@@ -614,48 +1230,365 @@ impl Voting {
         self.min_participation_rate = rate_bps;
     }
 
-    /// Get current configuration.
-    pub fn get_config(&self) -> (u64, u64, u64) {
-        (
-            self.commit_phase_duration,
-            self.reveal_phase_duration,
-            self.min_participation_rate,
-        )
+    /// Set the minimum number of distinct committers required to resolve.
+    /// Guards against a single large staker capturing resolution.
+    /// Only owner can call.
+    pub fn set_min_committers(&mut self, min_committers: u64) {
+        self.assert_owner();
+        self.min_committers = min_committers;
     }
 
-    pub fn set_voting_token(&mut self, voting_token: AccountId) {
-        self.assert_owner();
-        self.voting_token = Some(voting_token);
+    /// Get the minimum number of distinct committers required to resolve.
+    pub fn get_min_committers(&self) -> u64 {
+        self.min_committers
     }
 
-    pub fn set_treasury(&mut self, treasury: AccountId) {
+    /// Set the minimum number of distinct committers before `advance_to_reveal` is
+    /// permitted (0 disables the check).
+    /// Only owner can call.
+    pub fn set_min_commits_to_advance(&mut self, min_commits: u64) {
         self.assert_owner();
-        self.treasury = Some(treasury);
+        self.min_commits_to_advance = min_commits;
     }
 
-    pub fn set_slashing_treasury_bps(&mut self, bps: u16) {
+    /// Get the minimum number of distinct committers before `advance_to_reveal` is permitted.
+    pub fn get_min_commits_to_advance(&self) -> u64 {
+        self.min_commits_to_advance
+    }
+
+    /// Configure early advancement from commit to reveal phase.
+    /// When enabled, `advance_to_reveal` may proceed before the commit duration once
+    /// `threshold_bps` of `expected_stake` has committed and no commitment has arrived
+    /// for `quiet_window_ns`.
+    /// Only owner can call.
+    pub fn set_early_reveal_advance(
+        &mut self,
+        allow: bool,
+        expected_stake: U128,
+        threshold_bps: u16,
+        quiet_window_ns: u64,
+    ) {
         self.assert_owner();
         require!(
-            bps <= BASIS_POINTS_DENOMINATOR as u16,
+            threshold_bps <= BASIS_POINTS_DENOMINATOR as u16,
             "BPS cannot exceed 100%"
         );
-        self.slashing_treasury_bps = bps;
+        if allow {
+            require!(expected_stake.0 > 0, "Expected stake must be positive");
+        }
+        self.allow_early_reveal_advance = allow;
+        self.early_reveal_expected_stake = expected_stake.0;
+        self.early_reveal_threshold_bps = threshold_bps;
+        self.early_reveal_quiet_window = quiet_window_ns;
     }
 
-    pub fn set_max_low_participation_extensions(&mut self, max_extensions: u8) {
+    /// Get early advancement config: (allowed, expected_stake, threshold_bps, quiet_window_ns).
+    pub fn get_early_reveal_advance(&self) -> (bool, U128, u16, u64) {
+        (
+            self.allow_early_reveal_advance,
+            U128(self.early_reveal_expected_stake),
+            self.early_reveal_threshold_bps,
+            self.early_reveal_quiet_window,
+        )
+    }
+
+    /// Allow `resolve_price` before the reveal deadline once every committer has revealed.
+    /// Only owner can call.
+    pub fn set_allow_early_resolve_on_full_reveal(&mut self, allow: bool) {
         self.assert_owner();
-        self.max_low_participation_extensions = max_extensions;
+        self.allow_early_resolve_on_full_reveal = allow;
     }
 
-    pub fn emergency_resolve_price(
-        &mut self,
-        request_id: CryptoHash,
-        resolved_price: i128,
-        reason: String,
-    ) -> i128 {
+    /// Whether `resolve_price` may run before the reveal deadline on a full reveal.
+    pub fn get_allow_early_resolve_on_full_reveal(&self) -> bool {
+        self.allow_early_resolve_on_full_reveal
+    }
+
+    /// Set the bounds for per-request commit/reveal durations passed to `request_price`.
+    /// Only owner can call.
+    pub fn set_phase_duration_bounds(&mut self, min_ns: u64, max_ns: u64) {
         self.assert_owner();
-        let mut request = self
-            .requests
+        require!(min_ns <= max_ns, "Minimum duration cannot exceed maximum");
+        self.min_phase_duration = min_ns;
+        self.max_phase_duration = max_ns;
+    }
+
+    /// Get the bounds for per-request phase durations: (min_ns, max_ns).
+    pub fn get_phase_duration_bounds(&self) -> (u64, u64) {
+        (self.min_phase_duration, self.max_phase_duration)
+    }
+
+    /// Set the longest `ancillary_data` accepted by `request_price`, in bytes.
+    /// Only owner can call.
+    pub fn set_max_ancillary_data_len(&mut self, max_len: u64) {
+        self.assert_owner();
+        require!(
+            max_len >= MIN_ANCILLARY_DATA_LEN_LIMIT,
+            "Limit must fit a 32-byte assertion id"
+        );
+        self.max_ancillary_data_len = max_len;
+    }
+
+    /// Get the longest `ancillary_data` accepted by `request_price`, in bytes.
+    pub fn get_max_ancillary_data_len(&self) -> u64 {
+        self.max_ancillary_data_len
+    }
+
+    /// Allow an account (typically the oracle) to call `request_price`.
+    /// Once any requester is authorized, all other callers are rejected.
+    /// Only owner can call.
+    pub fn add_authorized_requester(&mut self, requester: AccountId) {
+        self.assert_owner();
+        if self.authorized_requesters.insert(requester) {
+            self.authorized_requester_count += 1;
+        }
+    }
+
+    /// Revoke an account's permission to call `request_price`.
+    /// Removing the last requester makes `request_price` permissionless again.
+    /// Only owner can call.
+    pub fn remove_authorized_requester(&mut self, requester: AccountId) {
+        self.assert_owner();
+        if self.authorized_requesters.remove(&requester) {
+            self.authorized_requester_count -= 1;
+        }
+    }
+
+    /// Whether `requester` may call `request_price`; true for everyone while no
+    /// requester is authorized.
+    pub fn is_authorized_requester(&self, requester: AccountId) -> bool {
+        self.authorized_requester_count == 0 || self.authorized_requesters.contains(&requester)
+    }
+
+    /// Get the nonce the next `request_price` call hashes into its request ID.
+    pub fn get_request_nonce(&self) -> u64 {
+        self.request_nonce
+    }
+
+    /// Get current configuration.
+    pub fn get_config(&self) -> (u64, u64, u64) {
+        (
+            self.commit_phase_duration,
+            self.reveal_phase_duration,
+            self.min_participation_rate,
+        )
+    }
+
+    /// Set the token accepted for new stake. Requests that already have commitments keep
+    /// paying out in the token they were committed in.
+    pub fn set_voting_token(&mut self, voting_token: AccountId) {
+        self.assert_owner();
+        self.voting_token = Some(voting_token);
+    }
+
+    pub fn set_treasury(&mut self, treasury: AccountId) {
+        self.assert_owner();
+        self.treasury = Some(treasury);
+    }
+
+    pub fn set_slashing_treasury_bps(&mut self, bps: u16) {
+        self.assert_owner();
+        require!(
+            bps as u64 + self.slashing_burn_bps as u64 <= BASIS_POINTS_DENOMINATOR,
+            "Treasury and burn BPS cannot exceed 100%"
+        );
+        self.slashing_treasury_bps = bps;
+    }
+
+    /// Set the portion of slashed stake burned via the voting token's `burn_from`.
+    /// The voting contract must be a burner on the voting token.
+    /// Only owner can call.
+    pub fn set_slashing_burn_bps(&mut self, bps: u16) {
+        self.assert_owner();
+        require!(
+            bps as u64 + self.slashing_treasury_bps as u64 <= BASIS_POINTS_DENOMINATOR,
+            "Treasury and burn BPS cannot exceed 100%"
+        );
+        self.slashing_burn_bps = bps;
+    }
+
+    /// Set the reward paid to the caller of a successful `resolve_price`.
+    /// Funded from the treasury cut of slashed stake and capped by it, so nothing is paid
+    /// when no stake is slashed.
+    /// Only owner can call.
+    pub fn set_resolver_reward(&mut self, reward: U128) {
+        self.assert_owner();
+        self.resolver_reward = reward.0;
+    }
+
+    /// Get the reward paid to the caller of a successful `resolve_price`.
+    pub fn get_resolver_reward(&self) -> U128 {
+        U128(self.resolver_reward)
+    }
+
+    /// Set the fee taken from each winning voter's returned stake at resolution and routed
+    /// to treasury, charged whether or not any stake is slashed.
+    /// Only owner can call.
+    pub fn set_resolution_fee_bps(&mut self, bps: u16) {
+        self.assert_owner();
+        require!(
+            bps as u64 <= BASIS_POINTS_DENOMINATOR,
+            "BPS cannot exceed 100%"
+        );
+        self.resolution_fee_bps = bps;
+    }
+
+    /// Get the resolution fee taken from winning voters' returned stake (bps).
+    pub fn get_resolution_fee_bps(&self) -> u16 {
+        self.resolution_fee_bps
+    }
+
+    /// Set the band around the resolved price within which votes on `identifier` count as
+    /// winning (stake returned, reward eligible). `None` restores exact matching.
+    /// Only owner can call.
+    pub fn set_slash_tolerance(&mut self, identifier: String, tolerance: Option<SlashTolerance>) {
+        self.assert_owner();
+        match tolerance {
+            Some(tolerance) => {
+                if let SlashTolerance::Bps { bps } = tolerance {
+                    require!(
+                        bps as u64 <= BASIS_POINTS_DENOMINATOR,
+                        "BPS cannot exceed 100%"
+                    );
+                }
+                self.slash_tolerances.insert(identifier, tolerance);
+            }
+            None => {
+                self.slash_tolerances.remove(&identifier);
+            }
+        }
+    }
+
+    /// Get the slash tolerance for an identifier, or None if votes must match exactly.
+    pub fn get_slash_tolerance(&self, identifier: String) -> Option<SlashTolerance> {
+        self.slash_tolerances.get(&identifier).copied()
+    }
+
+    /// Get the slashed stake split: (treasury_bps, burn_bps). Winners receive the rest.
+    pub fn get_slashing_split(&self) -> (u16, u16) {
+        (self.slashing_treasury_bps, self.slashing_burn_bps)
+    }
+
+    pub fn set_max_low_participation_extensions(&mut self, max_extensions: u8) {
+        self.assert_owner();
+        self.max_low_participation_extensions = max_extensions;
+    }
+
+    /// Extend the reveal window of a request currently in reveal phase.
+    /// Does not count towards `max_low_participation_extensions`.
+    /// Only owner can call.
+    ///
+    /// # Arguments
+    /// * `request_id` - The price request ID
+    /// * `additional_ns` - Extra reveal time in nanoseconds
+    pub fn extend_reveal_phase(&mut self, request_id: CryptoHash, additional_ns: u64) {
+        self.assert_owner();
+        require!(additional_ns > 0, "Extension must be positive");
+        let mut request = self
+            .requests
+            .get(&request_id)
+            .expect("Request not found")
+            .clone();
+        require!(request.phase == VotingPhase::Reveal, "Not in reveal phase");
+        require!(
+            env::block_timestamp() < self.reveal_deadline(&request),
+            "Reveal phase has ended"
+        );
+
+        request.reveal_extension_ns = request.reveal_extension_ns.saturating_add(additional_ns);
+        let new_reveal_deadline = self.reveal_deadline(&request);
+        self.requests.insert(request_id, request);
+
+        VotingEvent::RevealPhaseExtended {
+            request_id: &request_id,
+            additional_ns,
+            new_reveal_deadline,
+        }
+        .emit();
+    }
+
+    /// Extend the commit window of a request still in commit phase, e.g. when it ended
+    /// without `min_commits_to_advance` committers.
+    /// Only owner can call.
+    ///
+    /// # Arguments
+    /// * `request_id` - The price request ID
+    /// * `additional_ns` - Extra commit time in nanoseconds
+    pub fn extend_commit_phase(&mut self, request_id: CryptoHash, additional_ns: u64) {
+        self.assert_owner();
+        require!(additional_ns > 0, "Extension must be positive");
+        let mut request = self
+            .requests
+            .get(&request_id)
+            .expect("Request not found")
+            .clone();
+        require!(request.phase == VotingPhase::Commit, "Not in commit phase");
+
+        // Extending an elapsed commit phase reopens it from now, not from the old deadline
+        let now = env::block_timestamp();
+        let deadline = request.commit_start_time + self.commit_duration(&request);
+        let base_extension = request.commit_extension_ns + now.saturating_sub(deadline);
+        request.commit_extension_ns = base_extension.saturating_add(additional_ns);
+        let new_commit_deadline = request.commit_start_time + self.commit_duration(&request);
+        self.requests.insert(request_id, request);
+
+        VotingEvent::CommitPhaseExtended {
+            request_id: &request_id,
+            additional_ns,
+            new_commit_deadline,
+        }
+        .emit();
+    }
+
+    /// Move a request whose commit phase ended without `min_commits_to_advance`
+    /// committers to the emergency-only resolution path; it can no longer be revealed or
+    /// resolved by vote.
+    /// Only owner can call.
+    ///
+    /// # Arguments
+    /// * `request_id` - The price request ID
+    pub fn route_to_emergency(&mut self, request_id: CryptoHash) {
+        self.assert_owner();
+        let mut request = self
+            .requests
+            .get(&request_id)
+            .expect("Request not found")
+            .clone();
+        require!(request.phase == VotingPhase::Commit, "Not in commit phase");
+        let now = env::block_timestamp();
+        require!(
+            now >= request.commit_start_time + self.commit_duration(&request),
+            "Commit phase not yet ended"
+        );
+        let committers = self.committer_count(&request_id);
+        require!(
+            committers < self.min_commits_to_advance,
+            "Request has enough commits to advance"
+        );
+
+        request.phase = VotingPhase::Reveal;
+        request.reveal_start_time = now;
+        request.emergency_required = true;
+        self.requests.insert(request_id, request);
+        self.emergency_pending.insert(request_id);
+
+        VotingEvent::InsufficientCommits {
+            request_id: &request_id,
+            committers,
+            required_committers: self.min_commits_to_advance,
+        }
+        .emit();
+    }
+
+    pub fn emergency_resolve_price(
+        &mut self,
+        request_id: CryptoHash,
+        resolved_price: i128,
+        reason: String,
+    ) -> i128 {
+        self.assert_owner();
+        let mut request = self
+            .requests
             .get(&request_id)
             .expect("Request not found")
             .clone();
@@ -672,7 +1605,22 @@ impl Voting {
         request.status = RequestStatus::Resolved;
         request.resolved_price = Some(resolved_price);
         request.emergency_required = false;
+        request.emergency_resolved = true;
+        let resolution_callback = request.resolution_callback.clone();
         self.requests.insert(request_id, request);
+        self.emergency_pending.remove(&request_id);
+        self.prune_voter_requests(&request_id);
+        // Nothing is slashed; each voter reclaims their stake with `claim_emergency_refund`
+        self.resolution_outcomes.insert(
+            request_id,
+            ResolutionOutcome {
+                resolved_price,
+                total_slashed: U128(0),
+                total_rewarded: U128(0),
+                treasury_cut: U128(0),
+            },
+        );
+        Self::notify_resolution_callback(resolution_callback, &request_id, resolved_price);
 
         env::log_str(&format!(
             "EMERGENCY_RESOLUTION request_id={} resolved_price={} reason={}",
@@ -697,501 +1645,3943 @@ impl Voting {
         resolved_price
     }
 
-    // ==================== Role Management ====================
+    /// Reclaim the caller's full committed stake on an emergency-resolved request.
+    /// Emergency resolution bypasses voting, so no commitment is slashed or rewarded.
+    ///
+    /// # Returns
+    /// The refunded stake.
+    pub fn claim_emergency_refund(&mut self, request_id: CryptoHash) -> U128 {
+        let voter = env::predecessor_account_id();
+        let request = self.requests.get(&request_id).expect("Request not found");
+        require!(
+            request.emergency_resolved,
+            "Request was not emergency resolved"
+        );
+        let voting_token = self
+            .request_voting_token(request)
+            .expect("Voting token not configured");
 
-    /// Transfer ownership.
-    pub fn set_owner(&mut self, new_owner: AccountId) {
-        self.assert_owner();
-        self.owner = new_owner;
-    }
+        let commitment = self
+            .commitments
+            .get_mut(&request_id)
+            .expect("Commitments not initialized")
+            .remove(&voter)
+            .expect("No refundable commitment found");
+        self.unlock_stake(&voting_token, commitment.staked_amount);
+        self.transfer_ft(
+            voting_token,
+            &request_id,
+            voter.clone(),
+            commitment.staked_amount,
+        );
 
-    /// Get current owner.
-    pub fn get_owner(&self) -> AccountId {
-        self.owner.clone()
+        let amount = U128(commitment.staked_amount);
+        VotingEvent::EmergencyRefundClaimed {
+            request_id: &request_id,
+            voter: &voter,
+            amount: &amount,
+        }
+        .emit();
+
+        amount
     }
 
-    // ==================== Internal ====================
+    /// Withdraw the caller's returned stake and reward on a resolved request.
+    ///
+    /// # Returns
+    /// The claimed amount.
+    pub fn claim_reward(&mut self, request_id: CryptoHash) -> U128 {
+        let voter = env::predecessor_account_id();
+        let amount = self
+            .claimable_rewards
+            .remove(&(request_id, voter.clone()))
+            .expect("No reward to claim");
+        let request = self.requests.get(&request_id).expect("Request not found");
+        let voting_token = self
+            .request_voting_token(request)
+            .expect("Voting token not configured");
+        self.unlock_stake(&voting_token, amount);
+        self.transfer_ft(voting_token, &request_id, voter.clone(), amount);
+
+        let amount = U128(amount);
+        VotingEvent::RewardClaimed {
+            request_id: &request_id,
+            voter: &voter,
+            amount: &amount,
+        }
+        .emit();
 
-    fn assert_owner(&self) {
-        require!(
-            env::predecessor_account_id() == self.owner,
-            "Only owner can call this method"
-        );
+        amount
     }
 
-    /// Generate a unique request ID from the request parameters.
-    fn generate_request_id(
-        &self,
-        identifier: &str,
-        timestamp: u64,
-        ancillary_data: &[u8],
-    ) -> CryptoHash {
-        let mut data = Vec::new();
-        data.extend_from_slice(identifier.as_bytes());
-        data.extend_from_slice(&timestamp.to_le_bytes());
-        data.extend_from_slice(ancillary_data);
-        data.extend_from_slice(&self.request_nonce.to_le_bytes());
-        env::sha256(&data)
-            .try_into()
-            .expect("Hash should be 32 bytes")
-    }
+    /// Callback after a payout transfer. On failure the tokens are still held here, so the
+    /// amount is owed again: it is recorded for `retry_transfer` and counted as locked.
+    #[allow(deprecated)]
+    #[private]
+    pub fn on_transfer_ft_complete(
+        &mut self,
+        token: AccountId,
+        recipient: AccountId,
+        amount: U128,
+        request_id: CryptoHash,
+    ) {
+        if matches!(env::promise_result(0), PromiseResult::Successful(_)) {
+            return;
+        }
+        let key = (request_id, recipient.clone());
+        let owed = self
+            .failed_transfers
+            .get(&key)
+            .map(|(_, owed)| *owed)
+            .unwrap_or(0);
+        self.failed_transfers
+            .insert(key, (token.clone(), owed.saturating_add(amount.0)));
+        self.lock_stake(&token, amount.0);
 
-    /// Compute vote hash for commitment verification.
-    #[allow(dead_code)]
-    fn compute_vote_hash(&self, price: i128, salt: CryptoHash) -> CryptoHash {
-        Self::compute_vote_hash_static(price, salt)
+        VotingEvent::TransferFailed {
+            request_id: &request_id,
+            recipient: &recipient,
+            amount: &amount,
+        }
+        .emit();
     }
 
-    /// Static version of compute_vote_hash to avoid borrow issues.
-    fn compute_vote_hash_static(price: i128, salt: CryptoHash) -> CryptoHash {
-        let mut data = Vec::new();
-        data.extend_from_slice(&price.to_le_bytes());
-        data.extend_from_slice(&salt);
-        env::sha256(&data)
-            .try_into()
-            .expect("Hash should be 32 bytes")
+    /// Re-send a payout whose transfer failed, e.g. once the recipient has registered
+    /// with the voting token. Callable by anyone; the tokens only go to `recipient`.
+    ///
+    /// # Returns
+    /// The amount re-sent.
+    pub fn retry_transfer(&mut self, request_id: CryptoHash, recipient: AccountId) -> U128 {
+        let (voting_token, amount) = self
+            .failed_transfers
+            .remove(&(request_id, recipient.clone()))
+            .expect("No failed transfer to retry");
+        self.unlock_stake(&voting_token, amount);
+        self.transfer_ft(voting_token, &request_id, recipient.clone(), amount);
+
+        let amount = U128(amount);
+        VotingEvent::TransferRetried {
+            request_id: &request_id,
+            recipient: &recipient,
+            amount: &amount,
+        }
+        .emit();
+
+        amount
     }
 
-    fn stake_weighted_median(votes: &mut [(i128, u128, AccountId)]) -> i128 {
-        votes.sort_by(|a, b| a.0.cmp(&b.0));
-        let total: u128 = votes.iter().map(|(_, stake, _)| *stake).sum();
-        let midpoint = total / 2 + total % 2;
-        let mut running = 0u128;
-        for (price, stake, _) in votes.iter() {
-            running = running.saturating_add(*stake);
-            if running >= midpoint {
-                return *price;
-            }
-        }
-        votes.last().map(|(price, _, _)| *price).unwrap_or(0)
+    /// Get the amount owed to a recipient from a failed payout transfer on a request.
+    pub fn get_failed_transfer(&self, request_id: CryptoHash, recipient: AccountId) -> U128 {
+        U128(
+            self.failed_transfers
+                .get(&(request_id, recipient))
+                .map(|(_, amount)| *amount)
+                .unwrap_or(0),
+        )
     }
 
-    fn distribute_rewards_and_slashing(
-        &self,
+    /// Get the stake and reward a voter can still withdraw with `claim_reward`.
+    pub fn get_claimable_reward(&self, request_id: CryptoHash, voter: AccountId) -> U128 {
+        U128(
+            self.claimable_rewards
+                .get(&(request_id, voter))
+                .copied()
+                .unwrap_or(0),
+        )
+    }
+
+    /// Remove a resolved request's data and refund the freed storage cost to the caller.
+    /// Only allowed once its stake has been paid out: claimed by every winner, or for
+    /// emergency-resolved requests, refunded to every voter. `get_price` and
+    /// `get_resolution_outcome` keep answering from the retained resolution outcome.
+    /// Returns the refunded amount in yoctoNEAR.
+    pub fn cleanup_request(&mut self, request_id: CryptoHash) -> U128 {
+        let request = self.requests.get(&request_id).expect("Request not found");
+        require!(
+            request.phase == VotingPhase::Resolved,
+            "Request not resolved"
+        );
+        require!(
+            self.resolution_outcomes.contains_key(&request_id),
+            "Request stake not distributed"
+        );
+        let voters = self
+            .request_voters
+            .get(&request_id)
+            .cloned()
+            .unwrap_or_default();
+        if request.emergency_resolved {
+            let commitments = self
+                .commitments
+                .get(&request_id)
+                .expect("Commitments not initialized");
+            require!(
+                voters.iter().all(|voter| !commitments.contains_key(voter)),
+                "Emergency refunds not fully claimed"
+            );
+        }
+        require!(
+            voters.iter().all(|voter| !self
+                .claimable_rewards
+                .contains_key(&(request_id, voter.clone()))),
+            "Rewards not fully claimed"
+        );
+
+        let initial_storage_usage = env::storage_usage();
+
+        if let Some(mut commitments) = self.commitments.remove(&request_id) {
+            for voter in &voters {
+                commitments.remove(voter);
+            }
+            commitments.flush();
+        }
+        self.requests.remove(&request_id);
+        self.total_committed_stake.remove(&request_id);
+        self.request_voters.remove(&request_id);
+        self.requests.flush();
+        self.commitments.flush();
+        self.total_committed_stake.flush();
+        self.request_voters.flush();
+
+        let freed_bytes = initial_storage_usage.saturating_sub(env::storage_usage());
+        let refund = env::storage_byte_cost().saturating_mul(freed_bytes as u128);
+        if !refund.is_zero() {
+            let _ = Promise::new(env::predecessor_account_id()).transfer(refund);
+        }
+
+        U128(refund.as_yoctonear())
+    }
+
+    /// Burn part of a voter's committed stake on a request, e.g. after an external audit
+    /// finds the voter acted maliciously. The commitment keeps voting with what remains.
+    /// Only owner can call, and only while the request's stake is still held.
+    ///
+    /// # Arguments
+    /// * `request_id` - The request the stake was committed to
+    /// * `voter` - The voter to slash
+    /// * `amount` - Stake to burn, at most the voter's committed stake
+    /// * `reason` - Reason recorded in the `voter_slashed` event
+    pub fn slash_voter(
+        &mut self,
+        request_id: CryptoHash,
+        voter: AccountId,
+        amount: U128,
+        reason: String,
+    ) {
+        self.assert_owner();
+        require!(amount.0 > 0, "Slash amount must be positive");
+        let request = self.requests.get(&request_id).expect("Request not found");
+        require!(
+            request.status != RequestStatus::Resolved || request.emergency_resolved,
+            "Request stake already distributed"
+        );
+        let voting_token = self
+            .request_voting_token(request)
+            .expect("Voting token not configured");
+
+        let commitments = self
+            .commitments
+            .get_mut(&request_id)
+            .expect("Commitments not initialized");
+        let mut commitment = commitments
+            .get(&voter)
+            .cloned()
+            .expect("No commitment found");
+        require!(
+            amount.0 <= commitment.staked_amount,
+            "Slash amount exceeds committed stake"
+        );
+        commitment.staked_amount -= amount.0;
+        let revealed = commitment.revealed;
+        commitments.insert(voter.clone(), commitment);
+        // Keep the request totals in line with the remaining stake, so participation and
+        // resolution payouts only count stake that is still locked
+        if let Some(committed) = self.total_committed_stake.get_mut(&request_id) {
+            *committed = committed.saturating_sub(amount.0);
+        }
+        if revealed {
+            let request = self.requests.get_mut(&request_id).unwrap();
+            request.revealed_stake = request.revealed_stake.saturating_sub(amount.0);
+        }
+        self.unlock_stake(&voting_token, amount.0);
+        self.burn_stake(voting_token, &request_id, amount.0);
+
+        VotingEvent::VoterSlashed {
+            request_id: &request_id,
+            voter: &voter,
+            amount: &amount,
+            reason: &reason,
+        }
+        .emit();
+    }
+
+    /// Transfer out tokens that do not back any commitment, e.g. voting tokens sent with a
+    /// plain `ft_transfer`. For the voting token only the balance above the stake still owed
+    /// to voters can be rescued; other tokens are transferred as requested.
+    /// Only owner can call.
+    ///
+    /// # Arguments
+    /// * `token` - NEP-141 token to rescue
+    /// * `receiver_id` - Account receiving the tokens
+    /// * `amount` - Amount to transfer
+    pub fn rescue_tokens(
+        &mut self,
+        token: AccountId,
+        receiver_id: AccountId,
+        amount: U128,
+    ) -> Promise {
+        self.assert_owner();
+        self.transfer_surplus(token, receiver_id, amount)
+    }
+
+    /// Move tokens that do not back any commitment, e.g. slashed stake left behind by a
+    /// failed treasury transfer, to the current treasury. The same guard as `rescue_tokens`
+    /// applies: for the voting token only the balance above the stake still owed to voters
+    /// can be swept.
+    /// Only owner can call.
+    ///
+    /// # Arguments
+    /// * `token` - NEP-141 token to sweep
+    /// * `amount` - Amount to transfer
+    pub fn sweep_to_treasury(&mut self, token: AccountId, amount: U128) -> Promise {
+        self.assert_owner();
+        let treasury = self.treasury.clone().expect("Treasury not configured");
+        self.transfer_surplus(token, treasury, amount)
+    }
+
+    /// Transfer `amount` of `token` to `receiver_id`, first checking that a transfer of the
+    /// voting token, or of any former one still backing stake, stays within the balance not
+    /// owed to voters.
+    fn transfer_surplus(&self, token: AccountId, receiver_id: AccountId, amount: U128) -> Promise {
+        require!(amount.0 > 0, "Amount must be positive");
+        if self.voting_token.as_ref() != Some(&token) && self.locked_stake_of(&token) == 0 {
+            return Self::ft_transfer_promise(token, receiver_id, amount.0);
+        }
+
+        Promise::new(token.clone())
+            .function_call(
+                "ft_balance_of".to_string(),
+                near_sdk::serde_json::json!({
+                    "account_id": env::current_account_id(),
+                })
+                .to_string()
+                .into_bytes(),
+                NearToken::from_yoctonear(0),
+                GAS_FOR_BALANCE_CHECK,
+            )
+            .then(
+                Promise::new(env::current_account_id()).function_call(
+                    "on_rescue_balance_checked".to_string(),
+                    near_sdk::serde_json::json!({
+                        "token": token,
+                        "receiver_id": receiver_id,
+                        "amount": amount,
+                    })
+                    .to_string()
+                    .into_bytes(),
+                    NearToken::from_yoctonear(0),
+                    GAS_FOR_RESCUE_CALLBACK,
+                ),
+            )
+    }
+
+    /// Callback after a voting token balance is read for `rescue_tokens` or
+    /// `sweep_to_treasury`.
+    #[private]
+    pub fn on_rescue_balance_checked(
+        &mut self,
+        token: AccountId,
+        receiver_id: AccountId,
+        amount: U128,
+        #[callback_result] balance: Result<U128, PromiseError>,
+    ) -> Promise {
+        let Ok(balance) = balance else {
+            env::panic_str("Failed to read token balance");
+        };
+        let surplus = balance.0.saturating_sub(self.locked_stake_of(&token));
+        require!(amount.0 <= surplus, "Amount exceeds unaccounted balance");
+        Self::ft_transfer_promise(token, receiver_id, amount.0)
+    }
+
+    /// Get the current voting token stake committed and not yet paid back out.
+    pub fn get_locked_stake(&self) -> U128 {
+        U128(
+            self.voting_token
+                .as_ref()
+                .map(|token| self.locked_stake_of(token))
+                .unwrap_or(0),
+        )
+    }
+
+    /// Get the stake committed with `token` and not yet paid back out, e.g. for a former
+    /// voting token still backing open requests.
+    pub fn get_token_locked_stake(&self, token: AccountId) -> U128 {
+        U128(self.locked_stake_of(&token))
+    }
+
+    // ==================== Role Management ====================
+
+    /// Propose a new owner, who takes over once it calls `accept_ownership`.
+    pub fn propose_owner(&mut self, new_owner: AccountId) {
+        self.assert_owner();
+        self.pending_owner.propose(new_owner);
+    }
+
+    /// Accept a pending ownership proposal. Only callable by the proposed owner.
+    pub fn accept_ownership(&mut self) {
+        self.pending_owner
+            .accept(&mut self.owner, VOTING_EVENT_STANDARD);
+    }
+
+    /// Get the account proposed as the next owner, if any.
+    pub fn get_pending_owner(&self) -> Option<AccountId> {
+        self.pending_owner.get()
+    }
+
+    /// Get current owner.
+    pub fn get_owner(&self) -> AccountId {
+        self.owner.clone()
+    }
+
+    // ==================== Internal ====================
+
+    fn assert_owner(&self) {
+        require!(
+            env::predecessor_account_id() == self.owner,
+            "Only owner can call this method"
+        );
+    }
+
+    /// Whether a request may enter reveal before its commit deadline: enabled by the owner,
+    /// enough of the expected stake committed, and no new commitment for the quiet window.
+    fn can_advance_early(&self, request_id: &CryptoHash, request: &PriceRequest, now: u64) -> bool {
+        if !self.allow_early_reveal_advance || request.last_commit_time == 0 {
+            return false;
+        }
+        let committed = self
+            .total_committed_stake
+            .get(request_id)
+            .copied()
+            .unwrap_or(0);
+        let required = self
+            .early_reveal_expected_stake
+            .saturating_mul(self.early_reveal_threshold_bps as u128)
+            / BASIS_POINTS_DENOMINATOR as u128;
+        committed >= required
+            && now
+                >= request
+                    .last_commit_time
+                    .saturating_add(self.early_reveal_quiet_window)
+    }
+
+    /// Whether a request may resolve before its reveal deadline: enabled by the owner, not
+    /// awaiting emergency resolution, and all committed stake has revealed.
+    fn can_resolve_early(&self, request_id: &CryptoHash, request: &PriceRequest) -> bool {
+        if !self.allow_early_resolve_on_full_reveal || request.emergency_required {
+            return false;
+        }
+        let committed = self
+            .total_committed_stake
+            .get(request_id)
+            .copied()
+            .unwrap_or(0);
+        committed > 0 && request.revealed_stake == committed
+    }
+
+    /// Resolved price of a request removed by `cleanup_request`, kept in its outcome.
+    fn resolved_price_after_cleanup(&self, request_id: &CryptoHash) -> Option<i128> {
+        if self.requests.contains_key(request_id) {
+            return None;
+        }
+        self.resolution_outcomes
+            .get(request_id)
+            .map(|outcome| outcome.resolved_price)
+    }
+
+    /// Drop a resolved request from the open-request index of every voter who committed to it.
+    fn prune_voter_requests(&mut self, request_id: &CryptoHash) {
+        let voters = self
+            .request_voters
+            .get(request_id)
+            .cloned()
+            .unwrap_or_default();
+        for voter in voters {
+            let Some(open_requests) = self.voter_requests.get_mut(&voter) else {
+                continue;
+            };
+            open_requests.retain(|id| id != request_id);
+            if open_requests.is_empty() {
+                self.voter_requests.remove(&voter);
+            }
+        }
+    }
+
+    /// Commit phase duration for a request, falling back to the contract default and
+    /// including any owner extensions.
+    fn commit_duration(&self, request: &PriceRequest) -> u64 {
+        request
+            .commit_duration_ns
+            .unwrap_or(self.commit_phase_duration)
+            + request.commit_extension_ns
+    }
+
+    /// Number of distinct voters who committed to a request.
+    fn committer_count(&self, request_id: &CryptoHash) -> u64 {
+        self.request_voters
+            .get(request_id)
+            .map(|voters| voters.len() as u64)
+            .unwrap_or(0)
+    }
+
+    /// End of the reveal window for a request, including any owner extensions.
+    fn reveal_deadline(&self, request: &PriceRequest) -> u64 {
+        request.reveal_start_time
+            + request
+                .reveal_duration_ns
+                .unwrap_or(self.reveal_phase_duration)
+            + request.reveal_extension_ns
+    }
+
+    /// Notify a resolved request's callback account with `price_resolved_callback`.
+    /// Fire-and-forget: the resolution stands whether or not the callback succeeds.
+    fn notify_resolution_callback(
+        callback: Option<AccountId>,
         request_id: &CryptoHash,
         resolved_price: i128,
-        revealed_votes: &[(i128, u128, AccountId)],
     ) {
-        let Some(voting_token) = self.voting_token.clone() else {
-            return;
-        };
-        let Some(treasury) = self.treasury.clone() else {
+        let Some(callback) = callback else {
             return;
         };
+        let args = near_sdk::serde_json::to_vec(&PriceResolvedCallbackArgs {
+            request_id,
+            resolved_price,
+        })
+        .expect("Failed to serialize callback args");
+        let _ = Promise::new(callback).function_call_weight(
+            "price_resolved_callback".to_string(),
+            args,
+            NearToken::from_yoctonear(0),
+            GAS_FOR_RESOLUTION_CALLBACK,
+            GasWeight(1),
+        );
+    }
+
+    /// Storage prefix of a request's commitment map: the reserved tag, then the request id.
+    fn commitments_prefix(request_id: &CryptoHash) -> Vec<u8> {
+        let mut prefix = Vec::with_capacity(1 + request_id.len());
+        prefix.push(COMMITMENTS_PREFIX_TAG);
+        prefix.extend_from_slice(request_id);
+        prefix
+    }
+
+    /// Generate a unique request ID from the request parameters.
+    fn generate_request_id(
+        &self,
+        identifier: &str,
+        timestamp: u64,
+        ancillary_data: &[u8],
+    ) -> CryptoHash {
+        dvm_request_id(identifier, timestamp, ancillary_data, self.request_nonce)
+    }
+
+    /// Compute vote hash for commitment verification.
+    #[allow(dead_code)]
+    fn compute_vote_hash(
+        &self,
+        request_id: &CryptoHash,
+        price: i128,
+        salt: CryptoHash,
+    ) -> CryptoHash {
+        Self::compute_vote_hash_static(request_id, price, salt)
+    }
+
+    /// Static version of compute_vote_hash to avoid borrow issues.
+    /// Hashes `request_id || price (i128 little-endian) || salt`; including the request id
+    /// binds a commitment to its request so it cannot be replayed on another one.
+    fn compute_vote_hash_static(
+        request_id: &CryptoHash,
+        price: i128,
+        salt: CryptoHash,
+    ) -> CryptoHash {
+        let mut data = Vec::new();
+        data.extend_from_slice(request_id);
+        data.extend_from_slice(&price.to_le_bytes());
+        data.extend_from_slice(&salt);
+        env::sha256(&data)
+            .try_into()
+            .expect("Hash should be 32 bytes")
+    }
+
+    /// Revealed votes for a request as (price, stake, voter), in commit order.
+    fn revealed_votes(&self, request_id: &CryptoHash) -> Vec<(i128, u128, AccountId)> {
+        let commitments = self
+            .commitments
+            .get(request_id)
+            .expect("Commitments not initialized");
+        let voters = self
+            .request_voters
+            .get(request_id)
+            .expect("Voter list not initialized");
+
+        let mut revealed_votes: Vec<(i128, u128, AccountId)> = Vec::new();
+        for voter in voters {
+            if let Some(commitment) = commitments.get(voter) {
+                if commitment.revealed {
+                    if let Some(price) = commitment.revealed_price {
+                        revealed_votes.push((price, commitment.staked_amount, voter.clone()));
+                    }
+                }
+            }
+        }
+        revealed_votes
+    }
+
+    /// Stake of every committer that did not reveal a price within the identifier's slash
+    /// tolerance of `resolved_price`, i.e. the amount slashed.
+    fn total_slashed(&self, request_id: &CryptoHash, resolved_price: i128) -> u128 {
+        let identifier = &self
+            .requests
+            .get(request_id)
+            .expect("Request not found")
+            .identifier;
+        let commitments = self
+            .commitments
+            .get(request_id)
+            .expect("Commitments not initialized");
+        let voters = self
+            .request_voters
+            .get(request_id)
+            .expect("Voter list not initialized");
+
+        let mut total_slashed = 0u128;
+        for voter in voters {
+            if let Some(commitment) = commitments.get(voter) {
+                let is_winner = commitment.revealed
+                    && commitment.revealed_price.is_some_and(|price| {
+                        self.is_winning_vote(identifier, price, resolved_price)
+                    });
+                if !is_winner {
+                    total_slashed = total_slashed.saturating_add(commitment.staked_amount);
+                }
+            }
+        }
+        total_slashed
+    }
+
+    /// Whether a revealed price is close enough to the resolved price to avoid slashing.
+    fn is_winning_vote(&self, identifier: &str, price: i128, resolved_price: i128) -> bool {
+        let deviation = price.abs_diff(resolved_price);
+        match self.slash_tolerances.get(identifier) {
+            None => deviation == 0,
+            Some(SlashTolerance::Absolute { max_deviation }) => deviation <= *max_deviation,
+            Some(SlashTolerance::Bps { bps }) => {
+                let band = resolved_price.unsigned_abs().saturating_mul(*bps as u128)
+                    / BASIS_POINTS_DENOMINATOR as u128;
+                deviation <= band
+            }
+        }
+    }
+
+    /// Resolved price for a request's votes. Binary requests are tallied in two buckets;
+    /// anything else, or a binary request with an out-of-range vote, is sorted.
+    fn resolve_votes(identifier: &str, votes: &mut [(i128, u128, AccountId)]) -> i128 {
+        if BINARY_IDENTIFIERS.contains(&identifier) {
+            if let Some(price) = Self::binary_majority(votes) {
+                return price;
+            }
+        }
+        Self::stake_weighted_median(votes)
+    }
+
+    /// Stake-weighted median of votes that are all 0 or NUMERICAL_TRUE, without sorting.
+    /// Returns None if any vote falls outside the two buckets.
+    fn binary_majority(votes: &[(i128, u128, AccountId)]) -> Option<i128> {
+        let mut no_stake = 0u128;
+        let mut yes_stake = 0u128;
+        let mut has_no_vote = false;
+        for (price, stake, _) in votes {
+            match *price {
+                0 => {
+                    no_stake = no_stake.saturating_add(*stake);
+                    has_no_vote = true;
+                }
+                NUMERICAL_TRUE => yes_stake = yes_stake.saturating_add(*stake),
+                _ => return None,
+            }
+        }
+        // Same tie-breaking as the sorted median: the lower price wins at the midpoint
+        let total = no_stake.saturating_add(yes_stake);
+        let midpoint = total / 2 + total % 2;
+        if has_no_vote && no_stake >= midpoint {
+            Some(0)
+        } else {
+            Some(NUMERICAL_TRUE)
+        }
+    }
+
+    fn stake_weighted_median(votes: &mut [(i128, u128, AccountId)]) -> i128 {
+        votes.sort_by(|a, b| a.0.cmp(&b.0));
+        let total: u128 = votes.iter().map(|(_, stake, _)| *stake).sum();
+        let midpoint = total / 2 + total % 2;
+        let mut running = 0u128;
+        for (price, stake, _) in votes.iter() {
+            running = running.saturating_add(*stake);
+            if running >= midpoint {
+                return *price;
+            }
+        }
+        votes.last().map(|(price, _, _)| *price).unwrap_or(0)
+    }
+
+    /// Pay out a resolved request's slashed stake, record what each winner can claim and
+    /// its `ResolutionOutcome`. Returns the total left claimable by winners, or None,
+    /// leaving the stake in place, when the voting token or treasury is not configured.
+    fn distribute_rewards_and_slashing(
+        &mut self,
+        request_id: &CryptoHash,
+        resolved_price: i128,
+        revealed_votes: &[(i128, u128, AccountId)],
+    ) -> Option<u128> {
+        let request = self.requests.get(request_id).expect("Request not found");
+        let voting_token = self.request_voting_token(request)?;
+        let treasury = self.treasury.clone()?;
+
+        let identifier = &request.identifier;
+        let is_winner = |price: i128| self.is_winning_vote(identifier, price, resolved_price);
+        let winner_stake: u128 = revealed_votes
+            .iter()
+            .filter(|(price, _, _)| is_winner(*price))
+            .map(|(_, stake, _)| *stake)
+            .sum();
+        let total_slashed = self.total_slashed(request_id, resolved_price);
+        let resolution_fee = |stake: u128| {
+            stake.saturating_mul(self.resolution_fee_bps as u128) / BASIS_POINTS_DENOMINATOR as u128
+        };
+        let total_fee: u128 = revealed_votes
+            .iter()
+            .filter(|(price, _, _)| is_winner(*price))
+            .map(|(_, stake, _)| resolution_fee(*stake))
+            .sum();
+        if total_fee > 0 {
+            VotingEvent::ResolutionFeeCollected {
+                request_id,
+                amount: &U128(total_fee),
+            }
+            .emit();
+        }
+
+        let mut total_rewarded = 0u128;
+        let mut claimable = Vec::new();
+        let treasury_amount;
+        if total_slashed > 0 {
+            let (treasury_cut, burn_amount, reward_pool) = self.split_slashed_stake(total_slashed);
+            // Keepers are paid out of the treasury cut, never beyond it
+            let resolver_reward = self.resolver_reward.min(treasury_cut);
+            if resolver_reward > 0 {
+                let resolver = env::predecessor_account_id();
+                self.transfer_ft(
+                    voting_token.clone(),
+                    request_id,
+                    resolver.clone(),
+                    resolver_reward,
+                );
+                VotingEvent::ResolverRewarded {
+                    request_id,
+                    resolver: &resolver,
+                    amount: &U128(resolver_reward),
+                }
+                .emit();
+            }
+            self.burn_stake(voting_token.clone(), request_id, burn_amount);
+
+            for (price, stake, voter) in revealed_votes {
+                if is_winner(*price) {
+                    let reward = if winner_stake > 0 {
+                        reward_pool.saturating_mul(*stake) / winner_stake
+                    } else {
+                        0
+                    };
+                    total_rewarded += reward;
+                    claimable.push((
+                        voter.clone(),
+                        (stake - resolution_fee(*stake)).saturating_add(reward),
+                    ));
+                }
+            }
+
+            // Pro-rata rewards round down; the remainder (or the whole pool when nobody
+            // won) goes to treasury so no slashed stake is stranded in the contract
+            let reward_dust = reward_pool - total_rewarded;
+            require!(
+                treasury_cut + burn_amount + total_rewarded + reward_dust == total_slashed,
+                "Slashed stake not fully allocated"
+            );
+            treasury_amount = treasury_cut - resolver_reward + total_fee + reward_dust;
+            self.transfer_ft(voting_token.clone(), request_id, treasury, treasury_amount);
+        } else {
+            treasury_amount = total_fee;
+            self.transfer_ft(voting_token.clone(), request_id, treasury, treasury_amount);
+            for (price, stake, voter) in revealed_votes {
+                if is_winner(*price) {
+                    claimable.push((voter.clone(), stake - resolution_fee(*stake)));
+                }
+            }
+        }
+
+        // Winners withdraw with `claim_reward`, so resolution never pushes a transfer
+        // per voter
+        let mut total_claimable = 0u128;
+        for (voter, amount) in claimable {
+            if amount > 0 {
+                total_claimable += amount;
+                self.claimable_rewards.insert((*request_id, voter), amount);
+            }
+        }
+
+        self.resolution_outcomes.insert(
+            *request_id,
+            ResolutionOutcome {
+                resolved_price,
+                total_slashed: U128(total_slashed),
+                total_rewarded: U128(total_rewarded),
+                treasury_cut: U128(treasury_amount),
+            },
+        );
+        Some(total_claimable)
+    }
+
+    /// Token a request's stake is held in: the one pinned at its first commitment, or the
+    /// current voting token for requests without one.
+    fn request_voting_token(&self, request: &PriceRequest) -> Option<AccountId> {
+        request
+            .voting_token
+            .clone()
+            .or_else(|| self.voting_token.clone())
+    }
+
+    /// Split slashed stake into (treasury cut, burn amount, winner reward pool).
+    fn split_slashed_stake(&self, total_slashed: u128) -> (u128, u128, u128) {
+        let treasury_cut = total_slashed.saturating_mul(self.slashing_treasury_bps as u128)
+            / BASIS_POINTS_DENOMINATOR as u128;
+        let burn_amount = total_slashed.saturating_mul(self.slashing_burn_bps as u128)
+            / BASIS_POINTS_DENOMINATOR as u128;
+        let reward_pool = total_slashed - treasury_cut - burn_amount;
+        (treasury_cut, burn_amount, reward_pool)
+    }
+
+    /// Burn slashed stake held by this contract.
+    fn burn_stake(&self, token: AccountId, request_id: &CryptoHash, amount: u128) {
+        if amount == 0 {
+            return;
+        }
+        let _ = Promise::new(token).function_call(
+            "burn_from".to_string(),
+            near_sdk::serde_json::json!({
+                "account_id": env::current_account_id(),
+                "amount": U128(amount),
+            })
+            .to_string()
+            .into_bytes(),
+            NearToken::from_yoctonear(0),
+            GAS_FOR_BURN,
+        );
+        VotingEvent::StakeBurned {
+            request_id,
+            amount: &U128(amount),
+        }
+        .emit();
+    }
+
+    /// Pay out `amount` for a request; a failed transfer is recorded by
+    /// `on_transfer_ft_complete` for `retry_transfer`.
+    fn transfer_ft(
+        &self,
+        token: AccountId,
+        request_id: &CryptoHash,
+        receiver_id: AccountId,
+        amount: u128,
+    ) {
+        if amount == 0 {
+            return;
+        }
+        let _ = Self::ft_transfer_promise(token.clone(), receiver_id.clone(), amount).then(
+            Promise::new(env::current_account_id()).function_call(
+                "on_transfer_ft_complete".to_string(),
+                near_sdk::serde_json::json!({
+                    "token": token,
+                    "recipient": receiver_id,
+                    "amount": U128(amount),
+                    "request_id": request_id,
+                })
+                .to_string()
+                .into_bytes(),
+                NearToken::from_yoctonear(0),
+                GAS_FOR_TRANSFER_CALLBACK,
+            ),
+        );
+    }
+
+    fn locked_stake_of(&self, token: &AccountId) -> u128 {
+        self.locked_stake.get(token).copied().unwrap_or(0)
+    }
+
+    fn lock_stake(&mut self, token: &AccountId, amount: u128) {
+        let locked = self.locked_stake_of(token).saturating_add(amount);
+        self.locked_stake.insert(token.clone(), locked);
+    }
+
+    fn unlock_stake(&mut self, token: &AccountId, amount: u128) {
+        let locked = self.locked_stake_of(token).saturating_sub(amount);
+        if locked == 0 {
+            self.locked_stake.remove(token);
+        } else {
+            self.locked_stake.insert(token.clone(), locked);
+        }
+    }
+
+    fn ft_transfer_promise(token: AccountId, receiver_id: AccountId, amount: u128) -> Promise {
+        Promise::new(token).function_call(
+            "ft_transfer".to_string(),
+            near_sdk::serde_json::json!({
+                "receiver_id": receiver_id,
+                "amount": U128(amount),
+            })
+            .to_string()
+            .into_bytes(),
+            NearToken::from_yoctonear(1),
+            GAS_FOR_FT_TRANSFER,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use near_sdk::test_utils::{accounts, VMContextBuilder};
+    use near_sdk::testing_env;
+    const TOKEN_ACCOUNT: &str = "token.testnet";
+    const TREASURY_ACCOUNT: &str = "treasury.testnet";
+
+    fn account(id: &str) -> AccountId {
+        id.parse().unwrap()
+    }
+
+    fn get_context(predecessor: AccountId, block_timestamp: u64) -> VMContextBuilder {
+        let mut builder = VMContextBuilder::new();
+        builder
+            .predecessor_account_id(predecessor)
+            .block_timestamp(block_timestamp);
+        builder
+    }
+
+    fn setup_contract() -> Voting {
+        let mut contract = Voting::new(accounts(0));
+        contract.set_voting_token(account(TOKEN_ACCOUNT));
+        contract.set_treasury(account(TREASURY_ACCOUNT));
+        contract
+    }
+
+    fn commit(
+        contract: &mut Voting,
+        voter: AccountId,
+        request_id: CryptoHash,
+        stake: u128,
+        commit_hash: CryptoHash,
+    ) {
+        testing_env!(get_context(account(TOKEN_ACCOUNT), 1).build());
+        contract.ft_on_transfer(
+            voter,
+            U128(stake),
+            near_sdk::serde_json::to_string(&FtOnTransferMsg::CommitVote {
+                request_id,
+                commit_hash,
+            })
+            .unwrap(),
+        );
+    }
+
+    #[test]
+    fn test_new() {
+        let context = get_context(accounts(0), 0);
+        testing_env!(context.build());
+
+        let contract = Voting::new(accounts(0));
+        assert_eq!(contract.get_owner(), accounts(0));
+
+        let (commit_dur, reveal_dur, min_part) = contract.get_config();
+        assert_eq!(commit_dur, DEFAULT_COMMIT_DURATION);
+        assert_eq!(reveal_dur, DEFAULT_REVEAL_DURATION);
+        assert_eq!(min_part, 500);
+    }
+
+    #[test]
+    fn test_request_price() {
+        let context = get_context(accounts(0), 0);
+        testing_env!(context.build());
+
+        let mut contract = Voting::new(accounts(0));
+
+        let request_id = contract.request_price(
+            "YES_OR_NO_QUERY".to_string(),
+            1000,
+            b"test claim".to_vec(),
+            None,
+            None,
+            None,
+        );
+
+        let request = contract.get_request(request_id).unwrap();
+        assert_eq!(request.identifier, "YES_OR_NO_QUERY");
+        assert_eq!(request.timestamp, 1000);
+        assert_eq!(request.status, RequestStatus::Active);
+        assert_eq!(request.phase, VotingPhase::Commit);
+        assert_eq!(request.revealed_stake, 0);
+    }
+
+    #[test]
+    fn test_multiple_requests_same_params() {
+        // Each request gets a unique nonce, so same parameters can create
+        // multiple price requests (this is valid behavior)
+        let context = get_context(accounts(0), 0);
+        testing_env!(context.build());
+
+        let mut contract = Voting::new(accounts(0));
+
+        let request_id_1 = contract.request_price(
+            "YES_OR_NO_QUERY".to_string(),
+            1000,
+            b"test".to_vec(),
+            None,
+            None,
+            None,
+        );
+        let request_id_2 = contract.request_price(
+            "YES_OR_NO_QUERY".to_string(),
+            1000,
+            b"test".to_vec(),
+            None,
+            None,
+            None,
+        );
+
+        // They should have different IDs
+        assert_ne!(request_id_1, request_id_2);
+    }
+
+    #[test]
+    fn test_commit_vote_via_ft_transfer_call() {
+        let context = get_context(accounts(0), 0);
+        testing_env!(context.build());
+
+        let mut contract = setup_contract();
+
+        let request_id = contract.request_price(
+            "YES_OR_NO_QUERY".to_string(),
+            1000,
+            b"test".to_vec(),
+            None,
+            None,
+            None,
+        );
+
+        let salt = [7u8; 32];
+        let commit_hash = Voting::compute_vote_hash_static(&request_id, 1_000, salt);
+        testing_env!(get_context(account(TOKEN_ACCOUNT), 1).build());
+        let msg = near_sdk::serde_json::to_string(&FtOnTransferMsg::CommitVote {
+            request_id,
+            commit_hash,
+        })
+        .unwrap();
+        contract.ft_on_transfer(accounts(1), U128(1_000), msg);
+
+        assert_eq!(contract.get_total_committed_stake(request_id).0, 1000);
+    }
+
+    #[test]
+    #[should_panic(expected = "Only voting token can call ft_on_transfer")]
+    fn test_commit_wrong_token_rejected() {
+        let context = get_context(accounts(0), 0);
+        testing_env!(context.build());
+
+        let mut contract = setup_contract();
+
+        let request_id = contract.request_price(
+            "YES_OR_NO_QUERY".to_string(),
+            1000,
+            b"test".to_vec(),
+            None,
+            None,
+            None,
+        );
+
+        let salt = [9u8; 32];
+        let commit_hash = Voting::compute_vote_hash_static(&request_id, 1_000, salt);
+        testing_env!(get_context(accounts(1), 1).build());
+        let msg = near_sdk::serde_json::to_string(&FtOnTransferMsg::CommitVote {
+            request_id,
+            commit_hash,
+        })
+        .unwrap();
+        contract.ft_on_transfer(accounts(1), U128(1_000), msg);
+    }
+
+    #[test]
+    fn test_advance_to_reveal() {
+        let mut context = get_context(accounts(0), 0);
+        testing_env!(context.build());
+
+        let mut contract = Voting::new(accounts(0));
+
+        let request_id = contract.request_price(
+            "YES_OR_NO_QUERY".to_string(),
+            1000,
+            b"test".to_vec(),
+            None,
+            None,
+            None,
+        );
+
+        // Fast forward past commit phase
+        context.block_timestamp(DEFAULT_COMMIT_DURATION + 1);
+        testing_env!(context.build());
+
+        contract.advance_to_reveal(request_id);
+
+        let phase = contract.get_phase(request_id).unwrap();
+        assert_eq!(phase, VotingPhase::Reveal);
+    }
+
+    #[test]
+    #[should_panic(expected = "Commit phase not yet ended")]
+    fn test_advance_too_early() {
+        let mut context = get_context(accounts(0), 0);
+        testing_env!(context.build());
+
+        let mut contract = Voting::new(accounts(0));
+
+        let request_id = contract.request_price(
+            "YES_OR_NO_QUERY".to_string(),
+            1000,
+            b"test".to_vec(),
+            None,
+            None,
+            None,
+        );
+
+        // Try to advance before commit phase ends
+        context.block_timestamp(1000);
+        testing_env!(context.build());
+
+        contract.advance_to_reveal(request_id);
+    }
+
+    #[test]
+    fn test_set_config() {
+        let context = get_context(accounts(0), 0);
+        testing_env!(context.build());
+
+        let mut contract = Voting::new(accounts(0));
+
+        contract.set_commit_phase_duration(100);
+        contract.set_reveal_phase_duration(200);
+        contract.set_min_participation_rate(1000);
+
+        let (commit_dur, reveal_dur, min_part) = contract.get_config();
+        assert_eq!(commit_dur, 100);
+        assert_eq!(reveal_dur, 200);
+        assert_eq!(min_part, 1000);
+    }
+
+    #[test]
+    #[should_panic(expected = "Only owner can call this method")]
+    fn test_set_config_unauthorized() {
+        let context = get_context(accounts(0), 0);
+        testing_env!(context.build());
+
+        let mut contract = Voting::new(accounts(0));
+
+        testing_env!(get_context(accounts(1), 0).build());
+        contract.set_commit_phase_duration(100);
+    }
+
+    #[test]
+    fn test_transfer_ownership() {
+        let context = get_context(accounts(0), 0);
+        testing_env!(context.build());
+
+        let mut contract = Voting::new(accounts(0));
+
+        contract.propose_owner(accounts(1));
+        assert_eq!(contract.get_owner(), accounts(0));
+        assert_eq!(contract.get_pending_owner(), Some(accounts(1)));
+
+        testing_env!(get_context(accounts(1), 0).build());
+        contract.accept_ownership();
+        assert_eq!(contract.get_owner(), accounts(1));
+        assert_eq!(contract.get_pending_owner(), None);
+
+        // New owner can set config
+        contract.set_commit_phase_duration(100);
+    }
+
+    #[test]
+    #[should_panic(expected = "Only the pending owner can accept ownership")]
+    fn test_accept_ownership_rejects_other_accounts() {
+        testing_env!(get_context(accounts(0), 0).build());
+        let mut contract = Voting::new(accounts(0));
+        contract.propose_owner(accounts(1));
+
+        testing_env!(get_context(accounts(2), 0).build());
+        contract.accept_ownership();
+    }
+
+    #[test]
+    fn test_has_price() {
+        let context = get_context(accounts(0), 0);
+        testing_env!(context.build());
+
+        let mut contract = Voting::new(accounts(0));
+
+        let request_id = contract.request_price(
+            "YES_OR_NO_QUERY".to_string(),
+            1000,
+            b"test".to_vec(),
+            None,
+            None,
+            None,
+        );
+
+        // Not resolved yet
+        assert!(!contract.has_price(request_id));
+    }
+
+    #[test]
+    fn test_resolve_price_weighted_median() {
+        testing_env!(get_context(accounts(0), 0).build());
+        let mut contract = setup_contract();
+        contract.set_min_participation_rate(0);
+
+        let request_id = contract.request_price(
+            "YES_OR_NO_QUERY".to_string(),
+            1000,
+            b"test".to_vec(),
+            None,
+            None,
+            None,
+        );
+
+        let v1_salt = [1u8; 32];
+        let v2_salt = [2u8; 32];
+        let v3_salt = [3u8; 32];
+        let v1_hash = Voting::compute_vote_hash_static(&request_id, 0, v1_salt);
+        let v2_hash = Voting::compute_vote_hash_static(&request_id, 1, v2_salt);
+        let v3_hash = Voting::compute_vote_hash_static(&request_id, 1, v3_salt);
+
+        testing_env!(get_context(account(TOKEN_ACCOUNT), 1).build());
+        contract.ft_on_transfer(
+            accounts(1),
+            U128(100),
+            near_sdk::serde_json::to_string(&FtOnTransferMsg::CommitVote {
+                request_id,
+                commit_hash: v1_hash,
+            })
+            .unwrap(),
+        );
+        testing_env!(get_context(account(TOKEN_ACCOUNT), 1).build());
+        contract.ft_on_transfer(
+            accounts(2),
+            U128(400),
+            near_sdk::serde_json::to_string(&FtOnTransferMsg::CommitVote {
+                request_id,
+                commit_hash: v2_hash,
+            })
+            .unwrap(),
+        );
+        testing_env!(get_context(account(TOKEN_ACCOUNT), 1).build());
+        contract.ft_on_transfer(
+            accounts(3),
+            U128(500),
+            near_sdk::serde_json::to_string(&FtOnTransferMsg::CommitVote {
+                request_id,
+                commit_hash: v3_hash,
+            })
+            .unwrap(),
+        );
+
+        testing_env!(get_context(accounts(0), DEFAULT_COMMIT_DURATION + 2).build());
+        contract.advance_to_reveal(request_id);
+
+        testing_env!(get_context(accounts(1), DEFAULT_COMMIT_DURATION + 3).build());
+        contract.reveal_vote(request_id, 0, v1_salt);
+        testing_env!(get_context(accounts(2), DEFAULT_COMMIT_DURATION + 4).build());
+        contract.reveal_vote(request_id, 1, v2_salt);
+        testing_env!(get_context(accounts(3), DEFAULT_COMMIT_DURATION + 5).build());
+        contract.reveal_vote(request_id, 1, v3_salt);
+
+        testing_env!(get_context(
+            accounts(0),
+            DEFAULT_COMMIT_DURATION + DEFAULT_REVEAL_DURATION + 10
+        )
+        .build());
+        let outcome = contract.resolve_price(request_id);
+        assert_eq!(outcome, ResolvePriceOutcome::Resolved { price: 1 });
+        assert!(contract.has_price(request_id));
+    }
+
+    #[test]
+    fn test_would_reveal_succeed_precheck() {
+        testing_env!(get_context(accounts(0), 0).build());
+        let mut contract = setup_contract();
+
+        let request_id = contract.request_price(
+            "YES_OR_NO_QUERY".to_string(),
+            1000,
+            b"test".to_vec(),
+            None,
+            None,
+            None,
+        );
+        let salt = [4u8; 32];
+        commit(
+            &mut contract,
+            accounts(1),
+            request_id,
+            100,
+            Voting::compute_vote_hash_static(&request_id, 1, salt),
+        );
+
+        testing_env!(get_context(accounts(0), DEFAULT_COMMIT_DURATION + 2).build());
+        contract.advance_to_reveal(request_id);
+
+        assert!(contract.would_reveal_succeed(request_id, accounts(1), 1, salt));
+        assert!(!contract.would_reveal_succeed(request_id, accounts(1), 0, salt));
+        assert!(!contract.would_reveal_succeed(request_id, accounts(1), 1, [5u8; 32]));
+        assert!(!contract.would_reveal_succeed(request_id, accounts(2), 1, salt));
+    }
+
+    #[test]
+    fn test_reveal_votes_batch() {
+        testing_env!(get_context(accounts(0), 0).build());
+        let mut contract = setup_contract();
+
+        let request_a = contract.request_price(
+            "YES_OR_NO_QUERY".to_string(),
+            1000,
+            b"a".to_vec(),
+            None,
+            None,
+            None,
+        );
+        let request_b = contract.request_price(
+            "YES_OR_NO_QUERY".to_string(),
+            1000,
+            b"b".to_vec(),
+            None,
+            None,
+            None,
+        );
+        let salt_a = [6u8; 32];
+        let salt_b = [7u8; 32];
+        commit(
+            &mut contract,
+            accounts(1),
+            request_a,
+            100,
+            Voting::compute_vote_hash_static(&request_a, 1, salt_a),
+        );
+        commit(
+            &mut contract,
+            accounts(1),
+            request_b,
+            200,
+            Voting::compute_vote_hash_static(&request_b, 0, salt_b),
+        );
+
+        testing_env!(get_context(accounts(0), DEFAULT_COMMIT_DURATION + 2).build());
+        contract.advance_to_reveal(request_a);
+        contract.advance_to_reveal(request_b);
+
+        testing_env!(get_context(accounts(1), DEFAULT_COMMIT_DURATION + 3).build());
+        contract.reveal_votes(vec![(request_a, 1, salt_a), (request_b, 0, salt_b)]);
+
+        assert_eq!(contract.get_request(request_a).unwrap().revealed_stake, 100);
+        assert_eq!(contract.get_request(request_b).unwrap().revealed_stake, 200);
+        assert!(!contract.would_reveal_succeed(request_a, accounts(1), 1, salt_a));
+    }
+
+    #[test]
+    fn test_extend_reveal_phase_allows_late_reveal() {
+        testing_env!(get_context(accounts(0), 0).build());
+        let mut contract = setup_contract();
+        contract.set_min_participation_rate(0);
+
+        let request_id = contract.request_price(
+            "YES_OR_NO_QUERY".to_string(),
+            1000,
+            b"test".to_vec(),
+            None,
+            None,
+            None,
+        );
+        let salt = [8u8; 32];
+        commit(
+            &mut contract,
+            accounts(1),
+            request_id,
+            100,
+            Voting::compute_vote_hash_static(&request_id, 1, salt),
+        );
+
+        testing_env!(get_context(accounts(0), DEFAULT_COMMIT_DURATION).build());
+        contract.advance_to_reveal(request_id);
+        contract.extend_reveal_phase(request_id, 1_000);
+
+        let original_deadline = DEFAULT_COMMIT_DURATION + DEFAULT_REVEAL_DURATION;
+        testing_env!(get_context(accounts(1), original_deadline + 500).build());
+        contract.reveal_vote(request_id, 1, salt);
+
+        let request = contract.get_request(request_id).unwrap();
+        assert_eq!(request.revealed_stake, 100);
+        assert_eq!(request.low_participation_extensions, 0);
+        assert_eq!(request.reveal_extension_ns, 1_000);
+    }
+
+    #[test]
+    #[should_panic(expected = "Reveal phase not yet ended")]
+    fn test_resolve_honors_reveal_extension() {
+        testing_env!(get_context(accounts(0), 0).build());
+        let mut contract = setup_contract();
+
+        let request_id = contract.request_price(
+            "YES_OR_NO_QUERY".to_string(),
+            1000,
+            b"test".to_vec(),
+            None,
+            None,
+            None,
+        );
+        commit(
+            &mut contract,
+            accounts(1),
+            request_id,
+            100,
+            Voting::compute_vote_hash_static(&request_id, 1, [9u8; 32]),
+        );
+
+        testing_env!(get_context(accounts(0), DEFAULT_COMMIT_DURATION).build());
+        contract.advance_to_reveal(request_id);
+        contract.extend_reveal_phase(request_id, 1_000);
+
+        testing_env!(get_context(
+            accounts(0),
+            DEFAULT_COMMIT_DURATION + DEFAULT_REVEAL_DURATION + 500
+        )
+        .build());
+        contract.resolve_price(request_id);
+    }
+
+    #[test]
+    #[should_panic(expected = "Commit phase not yet ended")]
+    fn test_early_advance_disabled_by_default() {
+        testing_env!(get_context(accounts(0), 0).build());
+        let mut contract = setup_contract();
+        let request_id = contract.request_price(
+            "YES_OR_NO_QUERY".to_string(),
+            1000,
+            b"test".to_vec(),
+            None,
+            None,
+            None,
+        );
+        commit(&mut contract, accounts(1), request_id, 1_000_000, [1u8; 32]);
+
+        testing_env!(get_context(accounts(0), DEFAULT_COMMIT_DURATION / 2).build());
+        contract.advance_to_reveal(request_id);
+    }
+
+    #[test]
+    fn test_early_advance_after_quiet_window() {
+        testing_env!(get_context(accounts(0), 0).build());
+        let mut contract = setup_contract();
+        contract.set_early_reveal_advance(true, U128(1_000_000), 8_000, 1_000);
+        let request_id = contract.request_price(
+            "YES_OR_NO_QUERY".to_string(),
+            1000,
+            b"test".to_vec(),
+            None,
+            None,
+            None,
+        );
+        commit(&mut contract, accounts(1), request_id, 500_000, [1u8; 32]);
+        commit(&mut contract, accounts(2), request_id, 300_000, [2u8; 32]);
+
+        // Threshold met, but the quiet window has not elapsed since the last commit.
+        testing_env!(get_context(accounts(0), 500).build());
+        let request = contract.get_request(request_id).unwrap();
+        assert!(!contract.can_advance_early(&request_id, &request, 500));
+
+        testing_env!(get_context(accounts(0), 1_001).build());
+        contract.advance_to_reveal(request_id);
+        let request = contract.get_request(request_id).unwrap();
+        assert_eq!(request.phase, VotingPhase::Reveal);
+        assert_eq!(request.reveal_start_time, 1_001);
+    }
+
+    #[test]
+    #[should_panic(expected = "Commit phase not yet ended")]
+    fn test_early_advance_requires_stake_threshold() {
+        testing_env!(get_context(accounts(0), 0).build());
+        let mut contract = setup_contract();
+        contract.set_early_reveal_advance(true, U128(1_000_000), 8_000, 1_000);
+        let request_id = contract.request_price(
+            "YES_OR_NO_QUERY".to_string(),
+            1000,
+            b"test".to_vec(),
+            None,
+            None,
+            None,
+        );
+        commit(&mut contract, accounts(1), request_id, 500_000, [1u8; 32]);
+
+        testing_env!(get_context(accounts(0), 10_000).build());
+        contract.advance_to_reveal(request_id);
+    }
+
+    #[test]
+    fn test_participation_ratio_below_and_above_threshold() {
+        testing_env!(get_context(accounts(0), 0).build());
+        let mut contract = setup_contract();
+        contract.set_min_participation_rate(5_000);
+
+        let request_id = contract.request_price(
+            "YES_OR_NO_QUERY".to_string(),
+            1000,
+            b"test".to_vec(),
+            None,
+            None,
+            None,
+        );
+        assert_eq!(contract.get_participation_ratio_bps(request_id), 0);
+        assert!(!contract.is_participation_sufficient(request_id));
+
+        let salt_1 = [1u8; 32];
+        let salt_2 = [2u8; 32];
+        commit(
+            &mut contract,
+            accounts(1),
+            request_id,
+            300_000,
+            Voting::compute_vote_hash_static(&request_id, 1, salt_1),
+        );
+        commit(
+            &mut contract,
+            accounts(2),
+            request_id,
+            700_000,
+            Voting::compute_vote_hash_static(&request_id, 1, salt_2),
+        );
+
+        testing_env!(get_context(accounts(0), DEFAULT_COMMIT_DURATION + 2).build());
+        contract.advance_to_reveal(request_id);
+
+        testing_env!(get_context(accounts(1), DEFAULT_COMMIT_DURATION + 3).build());
+        contract.reveal_vote(request_id, 1, salt_1);
+        assert_eq!(contract.get_participation_ratio_bps(request_id), 3_000);
+        assert!(!contract.is_participation_sufficient(request_id));
+
+        testing_env!(get_context(accounts(2), DEFAULT_COMMIT_DURATION + 4).build());
+        contract.reveal_vote(request_id, 1, salt_2);
+        assert_eq!(contract.get_participation_ratio_bps(request_id), 10_000);
+        assert!(contract.is_participation_sufficient(request_id));
+    }
+
+    #[test]
+    fn test_voter_open_requests_track_outstanding_obligations() {
+        testing_env!(get_context(accounts(0), 0).build());
+        let mut contract = setup_contract();
+        contract.set_min_participation_rate(0);
+
+        let request_a = contract.request_price(
+            "YES_OR_NO_QUERY".to_string(),
+            1000,
+            b"a".to_vec(),
+            None,
+            None,
+            None,
+        );
+        let request_b = contract.request_price(
+            "YES_OR_NO_QUERY".to_string(),
+            1000,
+            b"b".to_vec(),
+            None,
+            None,
+            None,
+        );
+        let salt = [3u8; 32];
+        commit(
+            &mut contract,
+            accounts(1),
+            request_a,
+            100,
+            Voting::compute_vote_hash_static(&request_a, 1, salt),
+        );
+        commit(
+            &mut contract,
+            accounts(1),
+            request_b,
+            100,
+            Voting::compute_vote_hash_static(&request_b, 1, salt),
+        );
+        assert_eq!(
+            contract.get_voter_open_requests(accounts(1), 0, 10),
+            vec![request_a, request_b]
+        );
+        assert_eq!(
+            contract.get_voter_open_requests(accounts(1), 1, 10),
+            vec![request_b]
+        );
+        assert!(contract
+            .get_voter_open_requests(accounts(2), 0, 10)
+            .is_empty());
+
+        testing_env!(get_context(accounts(0), DEFAULT_COMMIT_DURATION + 2).build());
+        contract.advance_to_reveal(request_a);
+        testing_env!(get_context(accounts(1), DEFAULT_COMMIT_DURATION + 3).build());
+        contract.reveal_vote(request_a, 1, salt);
+
+        testing_env!(get_context(
+            accounts(0),
+            DEFAULT_COMMIT_DURATION + DEFAULT_REVEAL_DURATION + 10
+        )
+        .build());
+        contract.resolve_price(request_a);
+        assert_eq!(
+            contract.get_voter_open_requests(accounts(1), 0, 10),
+            vec![request_b]
+        );
+    }
+
+    #[test]
+    fn test_min_committers_forces_extension() {
+        testing_env!(get_context(accounts(0), 0).build());
+        let mut contract = setup_contract();
+        contract.set_min_participation_rate(0);
+        contract.set_min_committers(2);
+
+        let request_id = contract.request_price(
+            "YES_OR_NO_QUERY".to_string(),
+            1000,
+            b"test".to_vec(),
+            None,
+            None,
+            None,
+        );
+        let salt = [1u8; 32];
+        commit(
+            &mut contract,
+            accounts(1),
+            request_id,
+            1_000_000,
+            Voting::compute_vote_hash_static(&request_id, 1, salt),
+        );
+
+        testing_env!(get_context(accounts(0), DEFAULT_COMMIT_DURATION + 2).build());
+        contract.advance_to_reveal(request_id);
+        testing_env!(get_context(accounts(1), DEFAULT_COMMIT_DURATION + 3).build());
+        contract.reveal_vote(request_id, 1, salt);
+
+        // Full stake revealed, but a single committer does not meet quorum.
+        testing_env!(get_context(
+            accounts(0),
+            DEFAULT_COMMIT_DURATION + DEFAULT_REVEAL_DURATION + 10
+        )
+        .build());
+        let outcome = contract.resolve_price(request_id);
+        assert_eq!(outcome, ResolvePriceOutcome::RevealExtended);
+        let request = contract.get_request(request_id).unwrap();
+        assert_eq!(request.low_participation_extensions, 1);
+        assert!(!contract.has_price(request_id));
+    }
+
+    #[test]
+    fn test_no_reveals_routes_to_emergency() {
+        testing_env!(get_context(accounts(0), 0).build());
+        let mut contract = setup_contract();
+        contract.set_min_participation_rate(0);
+
+        let request_id = contract.request_price(
+            "YES_OR_NO_QUERY".to_string(),
+            1000,
+            b"test".to_vec(),
+            None,
+            None,
+            None,
+        );
+        commit(
+            &mut contract,
+            accounts(1),
+            request_id,
+            100,
+            Voting::compute_vote_hash_static(&request_id, 1, [1u8; 32]),
+        );
+
+        testing_env!(get_context(accounts(0), DEFAULT_COMMIT_DURATION + 2).build());
+        contract.advance_to_reveal(request_id);
+
+        testing_env!(get_context(
+            accounts(0),
+            DEFAULT_COMMIT_DURATION + DEFAULT_REVEAL_DURATION + 10
+        )
+        .build());
+        let outcome = contract.resolve_price(request_id);
+        assert_eq!(outcome, ResolvePriceOutcome::EmergencyRequired);
+        let request = contract.get_request(request_id).unwrap();
+        assert!(request.emergency_required);
+        assert_eq!(request.phase, VotingPhase::Reveal);
+
+        let resolved = contract.emergency_resolve_price(request_id, 1, "No reveals".to_string());
+        assert_eq!(resolved, 1);
+        assert!(contract.has_price(request_id));
+    }
+
+    #[test]
+    fn test_low_participation_requires_emergency() {
+        testing_env!(get_context(accounts(0), 0).build());
+        let mut contract = setup_contract();
+        contract.set_min_participation_rate(9_000);
+        contract.set_max_low_participation_extensions(0);
+
+        let request_id = contract.request_price(
+            "YES_OR_NO_QUERY".to_string(),
+            1000,
+            b"test".to_vec(),
+            None,
+            None,
+            None,
+        );
+        let salt = [1u8; 32];
+        let hash = Voting::compute_vote_hash_static(&request_id, 1, salt);
+
+        testing_env!(get_context(account(TOKEN_ACCOUNT), 1).build());
+        contract.ft_on_transfer(
+            accounts(1),
+            U128(100),
+            near_sdk::serde_json::to_string(&FtOnTransferMsg::CommitVote {
+                request_id,
+                commit_hash: hash,
+            })
+            .unwrap(),
+        );
+        testing_env!(get_context(account(TOKEN_ACCOUNT), 1).build());
+        contract.ft_on_transfer(
+            accounts(2),
+            U128(900),
+            near_sdk::serde_json::to_string(&FtOnTransferMsg::CommitVote {
+                request_id,
+                commit_hash: Voting::compute_vote_hash_static(&request_id, 0, [2u8; 32]),
+            })
+            .unwrap(),
+        );
+
+        testing_env!(get_context(accounts(0), DEFAULT_COMMIT_DURATION + 2).build());
+        contract.advance_to_reveal(request_id);
+        testing_env!(get_context(accounts(1), DEFAULT_COMMIT_DURATION + 3).build());
+        contract.reveal_vote(request_id, 1, salt);
+
+        testing_env!(get_context(
+            accounts(0),
+            DEFAULT_COMMIT_DURATION + DEFAULT_REVEAL_DURATION + 10
+        )
+        .build());
+        let outcome = contract.resolve_price(request_id);
+        assert_eq!(outcome, ResolvePriceOutcome::EmergencyRequired);
+        let req = contract.get_request(request_id).unwrap();
+        assert!(req.emergency_required);
+
+        testing_env!(get_context(
+            accounts(0),
+            DEFAULT_COMMIT_DURATION + DEFAULT_REVEAL_DURATION + 11
+        )
+        .build());
+        let emergency =
+            contract.emergency_resolve_price(request_id, 0, "Low participation".to_string());
+        assert_eq!(emergency, 0);
+        assert!(contract.has_price(request_id));
+    }
+
+    #[test]
+    fn test_emergency_resolution_refunds_committed_stake() {
+        testing_env!(get_context(accounts(0), 0).build());
+        let mut contract = setup_contract();
+        contract.set_min_participation_rate(9_000);
+        contract.set_max_low_participation_extensions(0);
+
+        let request_id = contract.request_price(
+            "YES_OR_NO_QUERY".to_string(),
+            1000,
+            b"test".to_vec(),
+            None,
+            None,
+            None,
+        );
+        let salt = [1u8; 32];
+        commit(
+            &mut contract,
+            accounts(1),
+            request_id,
+            100,
+            Voting::compute_vote_hash_static(&request_id, 1, salt),
+        );
+        commit(
+            &mut contract,
+            accounts(2),
+            request_id,
+            900,
+            Voting::compute_vote_hash_static(&request_id, 0, [2u8; 32]),
+        );
+
+        testing_env!(get_context(accounts(0), DEFAULT_COMMIT_DURATION + 2).build());
+        contract.advance_to_reveal(request_id);
+        testing_env!(get_context(accounts(1), DEFAULT_COMMIT_DURATION + 3).build());
+        contract.reveal_vote(request_id, 1, salt);
+
+        let after_reveal = DEFAULT_COMMIT_DURATION + DEFAULT_REVEAL_DURATION + 10;
+        testing_env!(get_context(accounts(0), after_reveal).build());
+        contract.resolve_price(request_id);
+        contract.emergency_resolve_price(request_id, 1, "Low participation".to_string());
+
+        // Revealed and unrevealed voters alike get their full stake back, once.
+        testing_env!(get_context(accounts(1), after_reveal + 1).build());
+        assert_eq!(contract.claim_emergency_refund(request_id).0, 100);
+        testing_env!(get_context(accounts(2), after_reveal + 1).build());
+        assert_eq!(contract.claim_emergency_refund(request_id).0, 900);
+        assert!(near_sdk::test_utils::get_logs()
+            .iter()
+            .any(|log| log.contains("emergency_refund_claimed")));
+    }
+
+    #[test]
+    #[should_panic(expected = "No refundable commitment found")]
+    fn test_emergency_refund_cannot_be_claimed_twice() {
+        testing_env!(get_context(accounts(0), 0).build());
+        let mut contract = setup_contract();
+        contract.set_max_low_participation_extensions(0);
+
+        let request_id = contract.request_price(
+            "YES_OR_NO_QUERY".to_string(),
+            1000,
+            b"test".to_vec(),
+            None,
+            None,
+            None,
+        );
+        commit(&mut contract, accounts(1), request_id, 100, [1u8; 32]);
+
+        testing_env!(get_context(accounts(0), DEFAULT_COMMIT_DURATION + 2).build());
+        contract.advance_to_reveal(request_id);
+        let after_reveal = DEFAULT_COMMIT_DURATION + DEFAULT_REVEAL_DURATION + 10;
+        testing_env!(get_context(accounts(0), after_reveal).build());
+        contract.resolve_price(request_id);
+        contract.emergency_resolve_price(request_id, 0, "No reveals".to_string());
+
+        testing_env!(get_context(accounts(1), after_reveal + 1).build());
+        contract.claim_emergency_refund(request_id);
+        contract.claim_emergency_refund(request_id);
+    }
+
+    #[test]
+    fn test_slashed_stake_three_way_split() {
+        testing_env!(get_context(accounts(0), 0).build());
+        let mut contract = setup_contract();
+        contract.set_min_participation_rate(0);
+        contract.set_slashing_treasury_bps(3_000);
+        contract.set_slashing_burn_bps(2_000);
+        assert_eq!(contract.get_slashing_split(), (3_000, 2_000));
+
+        let (treasury_cut, burn_amount, reward_pool) = contract.split_slashed_stake(1_001);
+        assert_eq!((treasury_cut, burn_amount, reward_pool), (300, 200, 501));
+        assert_eq!(treasury_cut + burn_amount + reward_pool, 1_001);
+
+        let request_id = contract.request_price(
+            "YES_OR_NO_QUERY".to_string(),
+            1000,
+            b"test".to_vec(),
+            None,
+            None,
+            None,
+        );
+        let salt_1 = [1u8; 32];
+        let salt_2 = [2u8; 32];
+        commit(
+            &mut contract,
+            accounts(1),
+            request_id,
+            900,
+            Voting::compute_vote_hash_static(&request_id, 1, salt_1),
+        );
+        commit(
+            &mut contract,
+            accounts(2),
+            request_id,
+            1_000,
+            Voting::compute_vote_hash_static(&request_id, 0, salt_2),
+        );
+        commit(
+            &mut contract,
+            accounts(3),
+            request_id,
+            1_100,
+            Voting::compute_vote_hash_static(&request_id, 1, [3u8; 32]),
+        );
+
+        testing_env!(get_context(accounts(0), DEFAULT_COMMIT_DURATION + 2).build());
+        contract.advance_to_reveal(request_id);
+        testing_env!(get_context(accounts(1), DEFAULT_COMMIT_DURATION + 3).build());
+        contract.reveal_vote(request_id, 1, salt_1);
+        testing_env!(get_context(accounts(2), DEFAULT_COMMIT_DURATION + 4).build());
+        contract.reveal_vote(request_id, 0, salt_2);
+
+        // Revealed 900 @ 1 vs 1000 @ 0 resolves to 0; 2000 stake is slashed.
+        testing_env!(get_context(
+            accounts(0),
+            DEFAULT_COMMIT_DURATION + DEFAULT_REVEAL_DURATION + 10
+        )
+        .build());
+        let outcome = contract.resolve_price(request_id);
+        assert_eq!(outcome, ResolvePriceOutcome::Resolved { price: 0 });
+        assert!(near_sdk::test_utils::get_logs()
+            .iter()
+            .any(|log| log.contains("stake_burned") && log.contains("\"amount\":\"400\"")));
+    }
+
+    #[test]
+    #[should_panic(expected = "Treasury and burn BPS cannot exceed 100%")]
+    fn test_slashing_burn_bps_bounded_by_treasury_share() {
+        testing_env!(get_context(accounts(0), 0).build());
+        let mut contract = setup_contract();
+        contract.set_slashing_treasury_bps(6_000);
+        contract.set_slashing_burn_bps(5_000);
+    }
+
+    #[test]
+    fn test_preview_resolution_matches_resolve_price() {
+        testing_env!(get_context(accounts(0), 0).build());
+        let mut contract = setup_contract();
+        contract.set_min_participation_rate(0);
+
+        let request_id = contract.request_price(
+            "YES_OR_NO_QUERY".to_string(),
+            1000,
+            b"test".to_vec(),
+            None,
+            None,
+            None,
+        );
+        let salts = [[1u8; 32], [2u8; 32], [3u8; 32]];
+        let votes = [
+            (accounts(1), 1, 300),
+            (accounts(2), 0, 500),
+            (accounts(3), 1, 400),
+        ];
+        for ((voter, price, stake), salt) in votes.iter().zip(salts) {
+            commit(
+                &mut contract,
+                voter.clone(),
+                request_id,
+                *stake,
+                Voting::compute_vote_hash_static(&request_id, *price, salt),
+            );
+        }
+        commit(&mut contract, accounts(4), request_id, 200, [4u8; 32]);
+
+        testing_env!(get_context(accounts(0), DEFAULT_COMMIT_DURATION + 2).build());
+        contract.advance_to_reveal(request_id);
+        for ((voter, price, _), salt) in votes.iter().zip(salts) {
+            testing_env!(get_context(voter.clone(), DEFAULT_COMMIT_DURATION + 3).build());
+            contract.reveal_vote(request_id, *price, salt);
+        }
+
+        // Price 1 carries 700 of 1200 revealed stake; the 0 vote and the unrevealed 200 lose.
+        let (projected_price, projected_slash) = contract.preview_resolution(request_id);
+        assert_eq!(projected_price, 1);
+        assert_eq!(projected_slash.0, 500 + 200);
+        assert_eq!(contract.get_phase(request_id), Some(VotingPhase::Reveal));
+
+        testing_env!(get_context(
+            accounts(0),
+            DEFAULT_COMMIT_DURATION + DEFAULT_REVEAL_DURATION + 10
+        )
+        .build());
+        let outcome = contract.resolve_price(request_id);
+        assert_eq!(
+            outcome,
+            ResolvePriceOutcome::Resolved {
+                price: projected_price
+            }
+        );
+    }
+
+    #[test]
+    fn test_resolver_reward_paid_from_treasury_cut() {
+        testing_env!(get_context(accounts(0), 0).build());
+        let mut contract = setup_contract();
+        contract.set_min_participation_rate(0);
+        contract.set_resolver_reward(U128(30));
+        assert_eq!(contract.get_resolver_reward().0, 30);
+
+        let request_id = contract.request_price(
+            "YES_OR_NO_QUERY".to_string(),
+            1000,
+            b"test".to_vec(),
+            None,
+            None,
+            None,
+        );
+        let salt = [1u8; 32];
+        commit(
+            &mut contract,
+            accounts(1),
+            request_id,
+            100,
+            Voting::compute_vote_hash_static(&request_id, 1, salt),
+        );
+        // Unrevealed stake is slashed, funding the treasury cut the reward comes from.
+        commit(&mut contract, accounts(2), request_id, 100, [2u8; 32]);
+
+        testing_env!(get_context(accounts(0), DEFAULT_COMMIT_DURATION + 2).build());
+        contract.advance_to_reveal(request_id);
+        testing_env!(get_context(accounts(1), DEFAULT_COMMIT_DURATION + 3).build());
+        contract.reveal_vote(request_id, 1, salt);
+
+        let keeper = account("keeper.testnet");
+        testing_env!(get_context(
+            keeper.clone(),
+            DEFAULT_COMMIT_DURATION + DEFAULT_REVEAL_DURATION + 10
+        )
+        .build());
+        contract.resolve_price(request_id);
+        let expected = format!("\"resolver\":\"{}\",\"amount\":\"30\"", keeper);
+        assert!(near_sdk::test_utils::get_logs()
+            .iter()
+            .any(|log| log.contains("resolver_rewarded") && log.contains(&expected)));
+    }
+
+    #[test]
+    fn test_per_request_reveal_duration_is_honored() {
+        testing_env!(get_context(accounts(0), 0).build());
+        let mut contract = setup_contract();
+        contract.set_min_participation_rate(0);
+
+        let long_reveal = 2 * DEFAULT_REVEAL_DURATION;
+        let request_id = contract.request_price(
+            "YES_OR_NO_QUERY".to_string(),
+            1000,
+            b"test".to_vec(),
+            None,
+            Some(long_reveal),
+            None,
+        );
+        let salt = [5u8; 32];
+        commit(
+            &mut contract,
+            accounts(1),
+            request_id,
+            100,
+            Voting::compute_vote_hash_static(&request_id, 1, salt),
+        );
+
+        testing_env!(get_context(accounts(0), DEFAULT_COMMIT_DURATION).build());
+        contract.advance_to_reveal(request_id);
+
+        // Past the contract-wide reveal window, but within the request's own
+        let global_deadline = DEFAULT_COMMIT_DURATION + DEFAULT_REVEAL_DURATION;
+        testing_env!(get_context(accounts(1), global_deadline + 1).build());
+        contract.reveal_vote(request_id, 1, salt);
+        assert_eq!(
+            contract.get_request(request_id).unwrap().revealed_stake,
+            100
+        );
+
+        testing_env!(get_context(accounts(0), DEFAULT_COMMIT_DURATION + long_reveal).build());
+        assert_eq!(
+            contract.resolve_price(request_id),
+            ResolvePriceOutcome::Resolved { price: 1 }
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Reveal phase not yet ended")]
+    fn test_resolve_waits_for_per_request_reveal_duration() {
+        testing_env!(get_context(accounts(0), 0).build());
+        let mut contract = setup_contract();
+
+        let request_id = contract.request_price(
+            "YES_OR_NO_QUERY".to_string(),
+            1000,
+            b"test".to_vec(),
+            None,
+            Some(2 * DEFAULT_REVEAL_DURATION),
+            None,
+        );
+        commit(
+            &mut contract,
+            accounts(1),
+            request_id,
+            100,
+            Voting::compute_vote_hash_static(&request_id, 1, [6u8; 32]),
+        );
+
+        testing_env!(get_context(accounts(0), DEFAULT_COMMIT_DURATION).build());
+        contract.advance_to_reveal(request_id);
+
+        testing_env!(get_context(
+            accounts(0),
+            DEFAULT_COMMIT_DURATION + DEFAULT_REVEAL_DURATION
+        )
+        .build());
+        contract.resolve_price(request_id);
+    }
+
+    #[test]
+    #[should_panic(expected = "Phase duration out of bounds")]
+    fn test_request_price_rejects_duration_above_max() {
+        testing_env!(get_context(accounts(0), 0).build());
+        let mut contract = setup_contract();
+        contract.set_phase_duration_bounds(DEFAULT_MIN_PHASE_DURATION, DEFAULT_COMMIT_DURATION);
+
+        contract.request_price(
+            "YES_OR_NO_QUERY".to_string(),
+            1000,
+            b"test".to_vec(),
+            Some(DEFAULT_COMMIT_DURATION + 1),
+            None,
+            None,
+        );
+    }
+
+    #[test]
+    fn test_binary_majority_matches_sorted_median() {
+        let stake_splits: [&[(i128, u128)]; 5] = [
+            &[(0, 100), (NUMERICAL_TRUE, 50)],
+            &[(NUMERICAL_TRUE, 100), (0, 50), (0, 49)],
+            &[(0, 50), (NUMERICAL_TRUE, 50)],
+            &[(NUMERICAL_TRUE, 51), (0, 25), (0, 25)],
+            &[(NUMERICAL_TRUE, 10)],
+        ];
+        for split in stake_splits {
+            let mut votes: Vec<(i128, u128, AccountId)> = split
+                .iter()
+                .enumerate()
+                .map(|(i, (price, stake))| (*price, *stake, account(&format!("voter{}.near", i))))
+                .collect();
+            let bucketed = Voting::binary_majority(&votes);
+            assert_eq!(bucketed, Some(Voting::stake_weighted_median(&mut votes)));
+        }
+    }
+
+    #[test]
+    fn test_resolve_votes_sorts_numerical_requests() {
+        let mut votes = vec![
+            (300, 10, account("a.near")),
+            (100, 10, account("b.near")),
+            (200, 15, account("c.near")),
+        ];
+        assert_eq!(Voting::resolve_votes("NUMERICAL", &mut votes), 200);
+
+        // A binary identifier with an out-of-range vote falls back to sorting
+        let mut votes = vec![
+            (NUMERICAL_TRUE, 10, account("a.near")),
+            (5, 30, account("b.near")),
+        ];
+        assert_eq!(Voting::binary_majority(&votes), None);
+        assert_eq!(Voting::resolve_votes("YES_OR_NO_QUERY", &mut votes), 5);
+    }
+
+    #[test]
+    fn test_process_request_advances_then_resolves() {
+        testing_env!(get_context(accounts(0), 0).build());
+        let mut contract = setup_contract();
+        contract.set_min_participation_rate(0);
+
+        let request_id = contract.request_price(
+            "YES_OR_NO_QUERY".to_string(),
+            1000,
+            b"test".to_vec(),
+            None,
+            None,
+            None,
+        );
+        let salt = [12u8; 32];
+        commit(
+            &mut contract,
+            accounts(1),
+            request_id,
+            100,
+            Voting::compute_vote_hash_static(&request_id, 1, salt),
+        );
+
+        testing_env!(get_context(accounts(0), DEFAULT_COMMIT_DURATION).build());
+        assert_eq!(
+            contract.process_request(request_id),
+            ProcessRequestAction::AdvancedToReveal
+        );
+        assert_eq!(
+            contract.get_request(request_id).unwrap().phase,
+            VotingPhase::Reveal
+        );
+
+        testing_env!(get_context(accounts(1), DEFAULT_COMMIT_DURATION + 1).build());
+        contract.reveal_vote(request_id, 1, salt);
+
+        testing_env!(get_context(
+            accounts(0),
+            DEFAULT_COMMIT_DURATION + DEFAULT_REVEAL_DURATION
+        )
+        .build());
+        assert_eq!(
+            contract.process_request(request_id),
+            ProcessRequestAction::Resolved {
+                outcome: ResolvePriceOutcome::Resolved { price: 1 }
+            }
+        );
+        assert_eq!(
+            contract.process_request(request_id),
+            ProcessRequestAction::NothingDue
+        );
+    }
+
+    #[test]
+    fn test_process_request_nothing_due() {
+        testing_env!(get_context(accounts(0), 0).build());
+        let mut contract = setup_contract();
+
+        let request_id = contract.request_price(
+            "YES_OR_NO_QUERY".to_string(),
+            1000,
+            b"test".to_vec(),
+            None,
+            None,
+            None,
+        );
+        commit(
+            &mut contract,
+            accounts(1),
+            request_id,
+            100,
+            Voting::compute_vote_hash_static(&request_id, 1, [13u8; 32]),
+        );
+
+        testing_env!(get_context(accounts(0), DEFAULT_COMMIT_DURATION - 1).build());
+        assert_eq!(
+            contract.process_request(request_id),
+            ProcessRequestAction::NothingDue
+        );
+
+        testing_env!(get_context(accounts(0), DEFAULT_COMMIT_DURATION).build());
+        contract.advance_to_reveal(request_id);
+        testing_env!(get_context(
+            accounts(0),
+            DEFAULT_COMMIT_DURATION + DEFAULT_REVEAL_DURATION - 1
+        )
+        .build());
+        assert_eq!(
+            contract.process_request(request_id),
+            ProcessRequestAction::NothingDue
+        );
+        assert_eq!(
+            contract.get_request(request_id).unwrap().phase,
+            VotingPhase::Reveal
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Binary requests only accept 0 or NUMERICAL_TRUE")]
+    fn test_binary_request_rejects_out_of_range_price() {
+        testing_env!(get_context(accounts(0), 0).build());
+        let mut contract = setup_contract();
+
+        let request_id = contract.request_price(
+            "YES_OR_NO_QUERY".to_string(),
+            1000,
+            b"test".to_vec(),
+            None,
+            None,
+            Some(true),
+        );
+        let salt = [14u8; 32];
+        commit(
+            &mut contract,
+            accounts(1),
+            request_id,
+            100,
+            Voting::compute_vote_hash_static(&request_id, 500_000, salt),
+        );
+
+        testing_env!(get_context(accounts(0), DEFAULT_COMMIT_DURATION).build());
+        contract.advance_to_reveal(request_id);
+        testing_env!(get_context(accounts(1), DEFAULT_COMMIT_DURATION + 1).build());
+        contract.reveal_vote(request_id, 500_000, salt);
+    }
+
+    #[test]
+    fn test_binary_request_accepts_boolean_prices() {
+        testing_env!(get_context(accounts(0), 0).build());
+        let mut contract = setup_contract();
+
+        let request_id = contract.request_price(
+            "YES_OR_NO_QUERY".to_string(),
+            1000,
+            b"test".to_vec(),
+            None,
+            None,
+            Some(true),
+        );
+        assert!(contract.get_request(request_id).unwrap().binary);
+        let no_salt = [15u8; 32];
+        let yes_salt = [16u8; 32];
+        commit(
+            &mut contract,
+            accounts(1),
+            request_id,
+            100,
+            Voting::compute_vote_hash_static(&request_id, 0, no_salt),
+        );
+        commit(
+            &mut contract,
+            accounts(2),
+            request_id,
+            100,
+            Voting::compute_vote_hash_static(&request_id, NUMERICAL_TRUE, yes_salt),
+        );
+
+        testing_env!(get_context(accounts(0), DEFAULT_COMMIT_DURATION).build());
+        contract.advance_to_reveal(request_id);
+        testing_env!(get_context(accounts(1), DEFAULT_COMMIT_DURATION + 1).build());
+        contract.reveal_vote(request_id, 0, no_salt);
+        testing_env!(get_context(accounts(2), DEFAULT_COMMIT_DURATION + 2).build());
+        contract.reveal_vote(request_id, NUMERICAL_TRUE, yes_salt);
+
+        assert_eq!(
+            contract.get_request(request_id).unwrap().revealed_stake,
+            200
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Amount exceeds unaccounted balance")]
+    fn test_rescue_cannot_touch_committed_stake() {
+        testing_env!(get_context(accounts(0), 0).build());
+        let mut contract = setup_contract();
+
+        let request_id = contract.request_price(
+            "YES_OR_NO_QUERY".to_string(),
+            1000,
+            b"test".to_vec(),
+            None,
+            None,
+            None,
+        );
+        commit(
+            &mut contract,
+            accounts(1),
+            request_id,
+            100,
+            Voting::compute_vote_hash_static(&request_id, 1, [17u8; 32]),
+        );
+        assert_eq!(contract.get_locked_stake(), U128(100));
+
+        // 50 tokens arrived outside of a commitment; 60 would include committed stake
+        testing_env!(get_context(accounts(0), 2).build());
+        contract.on_rescue_balance_checked(
+            account(TOKEN_ACCOUNT),
+            accounts(0),
+            U128(60),
+            Ok(U128(150)),
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Amount exceeds unaccounted balance")]
+    fn test_rescue_guards_stake_of_rotated_voting_token() {
+        testing_env!(get_context(accounts(0), 0).build());
+        let mut contract = setup_contract();
+
+        let request_id = contract.request_price(
+            "YES_OR_NO_QUERY".to_string(),
+            1000,
+            b"test".to_vec(),
+            None,
+            None,
+            None,
+        );
+        commit(
+            &mut contract,
+            accounts(1),
+            request_id,
+            100,
+            Voting::compute_vote_hash_static(&request_id, 1, [17u8; 32]),
+        );
+
+        testing_env!(get_context(accounts(0), 2).build());
+        contract.set_voting_token("new-token.near".parse().unwrap());
+        assert_eq!(contract.get_locked_stake(), U128(0));
+        assert_eq!(
+            contract.get_token_locked_stake(account(TOKEN_ACCOUNT)),
+            U128(100)
+        );
+
+        // The old token's balance is still checked before rescuing it
+        let _ = contract.rescue_tokens(account(TOKEN_ACCOUNT), accounts(0), U128(60));
+        assert!(near_sdk::test_utils::get_created_receipts()
+            .iter()
+            .any(|receipt| receipt.receiver_id == account(TOKEN_ACCOUNT)));
+        contract.on_rescue_balance_checked(
+            account(TOKEN_ACCOUNT),
+            accounts(0),
+            U128(60),
+            Ok(U128(150)),
+        );
+    }
+
+    fn resolve_unanimous_request(contract: &mut Voting) {
+        contract.set_min_participation_rate(0);
+        let request_id = contract.request_price(
+            "YES_OR_NO_QUERY".to_string(),
+            1000,
+            b"test".to_vec(),
+            None,
+            None,
+            None,
+        );
+        let salt_1 = [18u8; 32];
+        let salt_2 = [19u8; 32];
+        commit(
+            contract,
+            accounts(1),
+            request_id,
+            100,
+            Voting::compute_vote_hash_static(&request_id, 1, salt_1),
+        );
+        commit(
+            contract,
+            accounts(2),
+            request_id,
+            300,
+            Voting::compute_vote_hash_static(&request_id, 1, salt_2),
+        );
+
+        testing_env!(get_context(accounts(0), DEFAULT_COMMIT_DURATION).build());
+        contract.advance_to_reveal(request_id);
+        testing_env!(get_context(accounts(1), DEFAULT_COMMIT_DURATION + 1).build());
+        contract.reveal_vote(request_id, 1, salt_1);
+        testing_env!(get_context(accounts(2), DEFAULT_COMMIT_DURATION + 2).build());
+        contract.reveal_vote(request_id, 1, salt_2);
+
+        testing_env!(get_context(
+            accounts(0),
+            DEFAULT_COMMIT_DURATION + DEFAULT_REVEAL_DURATION
+        )
+        .build());
+        assert_eq!(
+            contract.resolve_price(request_id),
+            ResolvePriceOutcome::Resolved { price: 1 }
+        );
+    }
+
+    #[test]
+    fn test_unanimous_resolution_without_fee_takes_nothing() {
+        testing_env!(get_context(accounts(0), 0).build());
+        let mut contract = setup_contract();
+        assert_eq!(contract.get_resolution_fee_bps(), 0);
+
+        resolve_unanimous_request(&mut contract);
+        assert!(!near_sdk::test_utils::get_logs()
+            .iter()
+            .any(|log| log.contains("resolution_fee_collected")));
+    }
+
+    #[test]
+    fn test_unanimous_resolution_with_fee_pays_treasury() {
+        testing_env!(get_context(accounts(0), 0).build());
+        let mut contract = setup_contract();
+        contract.set_resolution_fee_bps(100);
+
+        resolve_unanimous_request(&mut contract);
+        // 1% of 100 + 1% of 300
+        assert!(near_sdk::test_utils::get_logs().iter().any(|log| {
+            log.contains("resolution_fee_collected") && log.contains("\"amount\":\"4\"")
+        }));
+    }
+
+    #[test]
+    fn test_slash_tolerance_spares_near_median_voters() {
+        testing_env!(get_context(accounts(0), 0).build());
+        let mut contract = setup_contract();
+        contract.set_min_participation_rate(0);
+        assert_eq!(contract.get_slash_tolerance("PRICE".to_string()), None);
+
+        let request_id = contract.request_price(
+            "PRICE".to_string(),
+            1000,
+            b"test".to_vec(),
+            None,
+            None,
+            None,
+        );
+        let salts = [[1u8; 32], [2u8; 32], [3u8; 32]];
+        let votes = [
+            (accounts(1), 1_000, 100),
+            (accounts(2), 1_010, 100),
+            (accounts(3), 2_000, 100),
+        ];
+        for ((voter, price, stake), salt) in votes.iter().zip(salts) {
+            commit(
+                &mut contract,
+                voter.clone(),
+                request_id,
+                *stake,
+                Voting::compute_vote_hash_static(&request_id, *price, salt),
+            );
+        }
+        testing_env!(get_context(accounts(0), DEFAULT_COMMIT_DURATION + 2).build());
+        contract.advance_to_reveal(request_id);
+        for ((voter, price, _), salt) in votes.iter().zip(salts) {
+            testing_env!(get_context(voter.clone(), DEFAULT_COMMIT_DURATION + 3).build());
+            contract.reveal_vote(request_id, *price, salt);
+        }
+
+        // Exact match: only the median voter keeps their stake
+        assert_eq!(contract.preview_resolution(request_id), (1_010, U128(200)));
+
+        testing_env!(get_context(accounts(0), DEFAULT_COMMIT_DURATION + 4).build());
+        contract.set_slash_tolerance(
+            "PRICE".to_string(),
+            Some(SlashTolerance::Absolute { max_deviation: 10 }),
+        );
+        assert_eq!(contract.preview_resolution(request_id), (1_010, U128(100)));
+
+        // 1% of 1_010 rounds down to a band of 10
+        contract.set_slash_tolerance("PRICE".to_string(), Some(SlashTolerance::Bps { bps: 100 }));
+        assert_eq!(
+            contract.get_slash_tolerance("PRICE".to_string()),
+            Some(SlashTolerance::Bps { bps: 100 })
+        );
+        assert_eq!(contract.preview_resolution(request_id), (1_010, U128(100)));
+
+        contract.set_slash_tolerance("PRICE".to_string(), None);
+        assert_eq!(contract.preview_resolution(request_id), (1_010, U128(200)));
+    }
+
+    #[test]
+    fn test_revealed_vote_distribution_aggregates_by_price() {
+        testing_env!(get_context(accounts(0), 0).build());
+        let mut contract = setup_contract();
+
+        let request_id = contract.request_price(
+            "PRICE".to_string(),
+            1000,
+            b"test".to_vec(),
+            None,
+            None,
+            None,
+        );
+        let salts = [[1u8; 32], [2u8; 32], [3u8; 32], [4u8; 32]];
+        let votes = [
+            (accounts(1), 5, 300),
+            (accounts(2), 7, 100),
+            (accounts(3), 5, 400),
+            (accounts(4), 9, 200),
+        ];
+        for ((voter, price, stake), salt) in votes.iter().zip(salts) {
+            commit(
+                &mut contract,
+                voter.clone(),
+                request_id,
+                *stake,
+                Voting::compute_vote_hash_static(&request_id, *price, salt),
+            );
+        }
+
+        testing_env!(get_context(accounts(0), DEFAULT_COMMIT_DURATION + 2).build());
+        contract.advance_to_reveal(request_id);
+        // accounts(4) never reveals, so its stake is not part of the distribution
+        for ((voter, price, _), salt) in votes.iter().zip(salts).take(3) {
+            testing_env!(get_context(voter.clone(), DEFAULT_COMMIT_DURATION + 3).build());
+            contract.reveal_vote(request_id, *price, salt);
+        }
+
+        assert_eq!(
+            contract.get_revealed_vote_distribution(request_id, 0, 10),
+            vec![(5, U128(700)), (7, U128(100))]
+        );
+        assert_eq!(
+            contract.get_revealed_vote_distribution(request_id, 1, 2),
+            vec![(5, U128(400)), (7, U128(100))]
+        );
+        assert!(contract
+            .get_revealed_vote_distribution([9u8; 32], 0, 10)
+            .is_empty());
+    }
+
+    #[test]
+    fn test_resolution_outcome_records_payout_split() {
+        testing_env!(get_context(accounts(0), 0).build());
+        let mut contract = setup_contract();
+        contract.set_min_participation_rate(0);
+        contract.set_slashing_treasury_bps(3_000);
+        contract.set_slashing_burn_bps(2_000);
+
+        let request_id = contract.request_price(
+            "YES_OR_NO_QUERY".to_string(),
+            1000,
+            b"test".to_vec(),
+            None,
+            None,
+            None,
+        );
+        let salt_1 = [1u8; 32];
+        let salt_2 = [2u8; 32];
+        commit(
+            &mut contract,
+            accounts(1),
+            request_id,
+            900,
+            Voting::compute_vote_hash_static(&request_id, 1, salt_1),
+        );
+        commit(
+            &mut contract,
+            accounts(2),
+            request_id,
+            1_000,
+            Voting::compute_vote_hash_static(&request_id, 0, salt_2),
+        );
+        commit(&mut contract, accounts(3), request_id, 1_100, [3u8; 32]);
+
+        testing_env!(get_context(accounts(0), DEFAULT_COMMIT_DURATION + 2).build());
+        contract.advance_to_reveal(request_id);
+        testing_env!(get_context(accounts(1), DEFAULT_COMMIT_DURATION + 3).build());
+        contract.reveal_vote(request_id, 1, salt_1);
+        testing_env!(get_context(accounts(2), DEFAULT_COMMIT_DURATION + 4).build());
+        contract.reveal_vote(request_id, 0, salt_2);
+        assert_eq!(contract.get_resolution_outcome(request_id), None);
+
+        testing_env!(get_context(
+            accounts(0),
+            DEFAULT_COMMIT_DURATION + DEFAULT_REVEAL_DURATION + 10
+        )
+        .build());
+        contract.resolve_price(request_id);
+
+        // 2000 slashed: 600 to treasury, 400 burned, 1000 to the sole winner
+        let outcome = contract.get_resolution_outcome(request_id).unwrap();
+        assert_eq!(
+            outcome,
+            ResolutionOutcome {
+                resolved_price: 0,
+                total_slashed: U128(2_000),
+                total_rewarded: U128(1_000),
+                treasury_cut: U128(600),
+            }
+        );
+        assert!(near_sdk::test_utils::get_logs()
+            .iter()
+            .any(|log| log.contains("stake_burned") && log.contains("\"amount\":\"400\"")));
+        assert_eq!(
+            outcome.total_rewarded.0 + outcome.treasury_cut.0 + 400,
+            outcome.total_slashed.0
+        );
+    }
+
+    #[test]
+    fn test_voting_token_rotation_pays_out_in_committed_token() {
+        testing_env!(get_context(accounts(0), 0).build());
+        let mut contract = setup_contract();
+        contract.set_min_participation_rate(0);
+
+        let request_id = contract.request_price(
+            "YES_OR_NO_QUERY".to_string(),
+            1000,
+            b"test".to_vec(),
+            None,
+            None,
+            None,
+        );
+        let salt = [1u8; 32];
+        commit(
+            &mut contract,
+            accounts(1),
+            request_id,
+            100,
+            Voting::compute_vote_hash_static(&request_id, 1, salt),
+        );
+        assert_eq!(
+            contract.get_request(request_id).unwrap().voting_token,
+            Some(account(TOKEN_ACCOUNT))
+        );
+
+        testing_env!(get_context(accounts(0), DEFAULT_COMMIT_DURATION + 2).build());
+        contract.advance_to_reveal(request_id);
+        contract.set_voting_token(account("new-token.testnet"));
+        testing_env!(get_context(accounts(1), DEFAULT_COMMIT_DURATION + 3).build());
+        contract.reveal_vote(request_id, 1, salt);
+
+        testing_env!(get_context(
+            accounts(0),
+            DEFAULT_COMMIT_DURATION + DEFAULT_REVEAL_DURATION + 10
+        )
+        .build());
+        assert_eq!(
+            contract.resolve_price(request_id),
+            ResolvePriceOutcome::Resolved { price: 1 }
+        );
+        testing_env!(get_context(
+            accounts(1),
+            DEFAULT_COMMIT_DURATION + DEFAULT_REVEAL_DURATION + 11
+        )
+        .build());
+        contract.claim_reward(request_id);
+        let receipts = near_sdk::test_utils::get_created_receipts();
+        assert!(receipts
+            .iter()
+            .any(|receipt| receipt.receiver_id == account(TOKEN_ACCOUNT)));
+        assert!(receipts
+            .iter()
+            .all(|receipt| receipt.receiver_id != account("new-token.testnet")));
+    }
+
+    #[test]
+    fn test_advance_without_commits_routes_to_emergency() {
+        testing_env!(get_context(accounts(0), 0).build());
+        let mut contract = setup_contract();
+        let request_id = contract.request_price(
+            "YES_OR_NO_QUERY".to_string(),
+            1000,
+            b"test".to_vec(),
+            None,
+            None,
+            None,
+        );
+
+        testing_env!(get_context(accounts(1), DEFAULT_COMMIT_DURATION).build());
+        contract.advance_to_reveal(request_id);
+        assert!(near_sdk::test_utils::get_logs()
+            .iter()
+            .any(|log| log.contains("request_expired_no_commits")));
+        assert!(contract.get_request(request_id).unwrap().emergency_required);
+
+        // Keepers leave it alone; the owner (or the oracle's fallback) decides it
+        testing_env!(get_context(
+            accounts(1),
+            DEFAULT_COMMIT_DURATION + DEFAULT_REVEAL_DURATION
+        )
+        .build());
+        assert_eq!(
+            contract.process_request(request_id),
+            ProcessRequestAction::NothingDue
+        );
+        testing_env!(get_context(
+            accounts(0),
+            DEFAULT_COMMIT_DURATION + DEFAULT_REVEAL_DURATION
+        )
+        .build());
+        contract.emergency_resolve_price(request_id, 0, "no commits".to_string());
+        assert_eq!(contract.get_price(request_id), Some(0));
+    }
+
+    #[test]
+    fn test_request_price_accepts_ancillary_data_at_limit() {
+        testing_env!(get_context(accounts(0), 0).build());
+        let mut contract = setup_contract();
+        assert_eq!(
+            contract.get_max_ancillary_data_len(),
+            DEFAULT_MAX_ANCILLARY_DATA_LEN
+        );
+        contract.set_max_ancillary_data_len(40);
+
+        let request_id = contract.request_price(
+            "YES_OR_NO_QUERY".to_string(),
+            1000,
+            vec![7u8; 40],
+            None,
+            None,
+            None,
+        );
+        assert_eq!(
+            contract
+                .get_request(request_id)
+                .unwrap()
+                .ancillary_data
+                .len(),
+            40
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Ancillary data too long")]
+    fn test_request_price_rejects_oversized_ancillary_data() {
+        testing_env!(get_context(accounts(0), 0).build());
+        let mut contract = setup_contract();
+        contract.set_max_ancillary_data_len(40);
+
+        contract.request_price(
+            "YES_OR_NO_QUERY".to_string(),
+            1000,
+            vec![7u8; 41],
+            None,
+            None,
+            None,
+        );
+    }
+
+    #[test]
+    fn test_authorized_requesters_restrict_request_price() {
+        testing_env!(get_context(accounts(0), 0).build());
+        let mut contract = setup_contract();
+        assert!(contract.is_authorized_requester(accounts(3)));
+
+        contract.add_authorized_requester(accounts(1));
+        assert!(contract.is_authorized_requester(accounts(1)));
+        assert!(!contract.is_authorized_requester(accounts(3)));
+
+        testing_env!(get_context(accounts(1), 0).build());
+        let request_id = contract.request_price(
+            "YES_OR_NO_QUERY".to_string(),
+            1000,
+            b"test".to_vec(),
+            None,
+            None,
+            None,
+        );
+        assert_eq!(
+            contract.get_request(request_id).unwrap().requester,
+            accounts(1)
+        );
+
+        // Removing the last requester makes the contract permissionless again
+        testing_env!(get_context(accounts(0), 0).build());
+        contract.remove_authorized_requester(accounts(1));
+        assert!(contract.is_authorized_requester(accounts(3)));
+    }
+
+    #[test]
+    #[should_panic(expected = "Requester not authorized")]
+    fn test_request_price_rejects_unauthorized_requester() {
+        testing_env!(get_context(accounts(0), 0).build());
+        let mut contract = setup_contract();
+        contract.add_authorized_requester(accounts(1));
+
+        testing_env!(get_context(accounts(3), 0).build());
+        contract.request_price(
+            "YES_OR_NO_QUERY".to_string(),
+            1000,
+            b"test".to_vec(),
+            None,
+            None,
+            None,
+        );
+    }
+
+    #[test]
+    fn test_slash_voter_burns_committed_stake() {
+        testing_env!(get_context(accounts(0), 0).build());
+        let mut contract = setup_contract();
+        let request_id = contract.request_price(
+            "YES_OR_NO_QUERY".to_string(),
+            1000,
+            b"test".to_vec(),
+            None,
+            None,
+            None,
+        );
+        commit(&mut contract, accounts(1), request_id, 500, [1u8; 32]);
+
+        testing_env!(get_context(accounts(0), 2).build());
+        contract.slash_voter(
+            request_id,
+            accounts(1),
+            U128(200),
+            "audit finding".to_string(),
+        );
+
+        assert_eq!(contract.get_total_committed_stake(request_id), U128(300));
+        assert_eq!(contract.get_locked_stake(), U128(300));
+        let receipts = near_sdk::test_utils::get_created_receipts();
+        assert!(receipts
+            .iter()
+            .any(|receipt| receipt.receiver_id == account(TOKEN_ACCOUNT)));
+        let logs = near_sdk::test_utils::get_logs();
+        assert!(logs
+            .iter()
+            .any(|log| log.contains("stake_burned") && log.contains("\"amount\":\"200\"")));
+        assert!(logs
+            .iter()
+            .any(|log| log.contains("voter_slashed") && log.contains("audit finding")));
+    }
+
+    #[test]
+    fn test_slash_voter_keeps_locked_stake_in_line_with_claims() {
+        testing_env!(get_context(accounts(0), 0).build());
+        let mut contract = setup_contract();
+        contract.set_min_participation_rate(0);
+        let request_id = contract.request_price(
+            "YES_OR_NO_QUERY".to_string(),
+            1000,
+            b"test".to_vec(),
+            None,
+            None,
+            None,
+        );
+        let votes = [
+            (accounts(1), NUMERICAL_TRUE, [1u8; 32], 200),
+            (accounts(2), 0, [2u8; 32], 100),
+        ];
+        for (voter, price, salt, stake) in votes.iter() {
+            commit(
+                &mut contract,
+                voter.clone(),
+                request_id,
+                *stake,
+                Voting::compute_vote_hash_static(&request_id, *price, *salt),
+            );
+        }
+        testing_env!(get_context(accounts(0), DEFAULT_COMMIT_DURATION + 2).build());
+        contract.advance_to_reveal(request_id);
+        for (voter, price, salt, _) in votes.iter() {
+            testing_env!(get_context(voter.clone(), DEFAULT_COMMIT_DURATION + 3).build());
+            contract.reveal_vote(request_id, *price, *salt);
+        }
+
+        testing_env!(get_context(accounts(0), DEFAULT_COMMIT_DURATION + 4).build());
+        contract.slash_voter(
+            request_id,
+            accounts(1),
+            U128(50),
+            "audit finding".to_string(),
+        );
+        assert_eq!(contract.get_total_committed_stake(request_id), U128(250));
+        assert_eq!(
+            contract.get_request(request_id).unwrap().revealed_stake,
+            250
+        );
+
+        let after_reveal = DEFAULT_COMMIT_DURATION + DEFAULT_REVEAL_DURATION + 10;
+        testing_env!(get_context(accounts(0), after_reveal).build());
+        contract.resolve_price(request_id);
+
+        let claimable = contract.get_claimable_reward(request_id, accounts(1)).0
+            + contract.get_claimable_reward(request_id, accounts(2)).0;
+        assert!(claimable > 0);
+        assert_eq!(contract.get_locked_stake(), U128(claimable));
+    }
+
+    #[test]
+    #[should_panic(expected = "Slash amount exceeds committed stake")]
+    fn test_slash_voter_capped_by_committed_stake() {
+        testing_env!(get_context(accounts(0), 0).build());
+        let mut contract = setup_contract();
+        let request_id = contract.request_price(
+            "YES_OR_NO_QUERY".to_string(),
+            1000,
+            b"test".to_vec(),
+            None,
+            None,
+            None,
+        );
+        commit(&mut contract, accounts(1), request_id, 500, [1u8; 32]);
+
+        testing_env!(get_context(accounts(0), 2).build());
+        contract.slash_voter(
+            request_id,
+            accounts(1),
+            U128(501),
+            "audit finding".to_string(),
+        );
+    }
+
+    #[test]
+    fn test_request_view_tracks_phase_deadlines() {
+        testing_env!(get_context(accounts(0), 0).build());
+        let mut contract = setup_contract();
+        contract.set_min_participation_rate(0);
+        let request_id = contract.request_price(
+            "YES_OR_NO_QUERY".to_string(),
+            1000,
+            b"test".to_vec(),
+            None,
+            None,
+            None,
+        );
+        let salt = [1u8; 32];
+        commit(
+            &mut contract,
+            accounts(1),
+            request_id,
+            100,
+            Voting::compute_vote_hash_static(&request_id, NUMERICAL_TRUE, salt),
+        );
+
+        let view = contract.get_request_view(request_id).unwrap();
+        assert_eq!(view.commit_deadline_ns, DEFAULT_COMMIT_DURATION);
+        assert_eq!(view.reveal_deadline_ns, None);
+        assert!(view.is_commit_open);
+        assert!(!view.is_reveal_open);
+        assert!(!view.is_resolvable);
+
+        let reveal_start = DEFAULT_COMMIT_DURATION + 2;
+        testing_env!(get_context(accounts(0), reveal_start).build());
+        assert!(
+            !contract
+                .get_request_view(request_id)
+                .unwrap()
+                .is_commit_open
+        );
+        contract.advance_to_reveal(request_id);
+
+        testing_env!(get_context(accounts(1), reveal_start + 1).build());
+        contract.reveal_vote(request_id, NUMERICAL_TRUE, salt);
+
+        let view = contract.get_request_view(request_id).unwrap();
+        assert_eq!(
+            view.reveal_deadline_ns,
+            Some(reveal_start + DEFAULT_REVEAL_DURATION)
+        );
+        assert!(!view.is_commit_open);
+        assert!(view.is_reveal_open);
+        assert!(!view.is_resolvable);
+
+        testing_env!(get_context(accounts(0), reveal_start + DEFAULT_REVEAL_DURATION).build());
+        let view = contract.get_request_view(request_id).unwrap();
+        assert!(!view.is_reveal_open);
+        assert!(view.is_resolvable);
+
+        contract.resolve_price(request_id);
+        let view = contract.get_request_view(request_id).unwrap();
+        assert_eq!(view.request.phase, VotingPhase::Resolved);
+        assert!(!view.is_commit_open && !view.is_reveal_open && !view.is_resolvable);
+        assert!(contract.get_request_view([0u8; 32]).is_none());
+    }
+
+    #[test]
+    fn test_second_reveal_does_not_double_count_stake() {
+        testing_env!(get_context(accounts(0), 0).build());
+        let mut contract = setup_contract();
+        let request_id = contract.request_price(
+            "YES_OR_NO_QUERY".to_string(),
+            1000,
+            b"test".to_vec(),
+            None,
+            None,
+            None,
+        );
+        let salt = [1u8; 32];
+        commit(
+            &mut contract,
+            accounts(1),
+            request_id,
+            100,
+            Voting::compute_vote_hash_static(&request_id, NUMERICAL_TRUE, salt),
+        );
+        testing_env!(get_context(accounts(0), DEFAULT_COMMIT_DURATION + 2).build());
+        contract.advance_to_reveal(request_id);
+
+        testing_env!(get_context(accounts(1), DEFAULT_COMMIT_DURATION + 3).build());
+        contract.reveal_vote(request_id, NUMERICAL_TRUE, salt);
+        let second_reveal = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            contract.reveal_votes(vec![(request_id, NUMERICAL_TRUE, salt)]);
+        }));
+        assert!(second_reveal.is_err());
+        assert_eq!(
+            contract.get_request(request_id).unwrap().revealed_stake,
+            100
+        );
+    }
 
-        let commitments = self
-            .commitments
-            .get(request_id)
-            .expect("Commitments not initialized");
-        let voters = self
-            .request_voters
-            .get(request_id)
-            .expect("Voter list not initialized")
-            .clone();
+    #[test]
+    fn test_reward_pool_rounding_remainder_goes_to_treasury() {
+        testing_env!(get_context(accounts(0), 0).build());
+        let mut contract = setup_contract();
+        contract.set_min_participation_rate(0);
+        contract.set_slashing_treasury_bps(0);
+
+        let request_id = contract.request_price(
+            "YES_OR_NO_QUERY".to_string(),
+            1000,
+            b"test".to_vec(),
+            None,
+            None,
+            None,
+        );
+        let votes = [
+            (accounts(1), 0, [1u8; 32]),
+            (accounts(2), 0, [2u8; 32]),
+            (accounts(3), 0, [3u8; 32]),
+            (accounts(4), NUMERICAL_TRUE, [4u8; 32]),
+        ];
+        for (voter, price, salt) in votes.iter() {
+            commit(
+                &mut contract,
+                voter.clone(),
+                request_id,
+                100,
+                Voting::compute_vote_hash_static(&request_id, *price, *salt),
+            );
+        }
 
-        let winner_stake: u128 = revealed_votes
-            .iter()
-            .filter(|(price, _, _)| *price == resolved_price)
-            .map(|(_, stake, _)| *stake)
-            .sum();
-        let mut total_slashed = 0u128;
-        for voter in &voters {
-            if let Some(commitment) = commitments.get(voter) {
-                let is_winner =
-                    commitment.revealed && commitment.revealed_price == Some(resolved_price);
-                if !is_winner {
-                    total_slashed = total_slashed.saturating_add(commitment.staked_amount);
-                }
-            }
+        testing_env!(get_context(accounts(0), DEFAULT_COMMIT_DURATION + 2).build());
+        contract.advance_to_reveal(request_id);
+        for (voter, price, salt) in votes.iter() {
+            testing_env!(get_context(voter.clone(), DEFAULT_COMMIT_DURATION + 3).build());
+            contract.reveal_vote(request_id, *price, *salt);
         }
-        if total_slashed > 0 {
-            let treasury_cut = total_slashed.saturating_mul(self.slashing_treasury_bps as u128)
-                / BASIS_POINTS_DENOMINATOR as u128;
-            let reward_pool = total_slashed.saturating_sub(treasury_cut);
-            self.transfer_ft(voting_token.clone(), treasury, treasury_cut);
 
-            for (price, stake, voter) in revealed_votes {
-                if *price == resolved_price {
-                    let reward = if winner_stake > 0 {
-                        reward_pool.saturating_mul(*stake) / winner_stake
-                    } else {
-                        0
-                    };
-                    self.transfer_ft(
-                        voting_token.clone(),
-                        voter.clone(),
-                        stake.saturating_add(reward),
-                    );
-                }
-            }
-        } else {
-            for (price, stake, voter) in revealed_votes {
-                if *price == resolved_price {
-                    self.transfer_ft(voting_token.clone(), voter.clone(), *stake);
+        testing_env!(get_context(
+            accounts(0),
+            DEFAULT_COMMIT_DURATION + DEFAULT_REVEAL_DURATION + 10
+        )
+        .build());
+        contract.resolve_price(request_id);
+
+        // 100 slashed across three equal winners: 33 each, the 1 left over goes to treasury
+        let outcome = contract.get_resolution_outcome(request_id).unwrap();
+        assert_eq!(outcome.total_slashed, U128(100));
+        assert_eq!(outcome.total_rewarded, U128(99));
+        assert_eq!(outcome.treasury_cut, U128(1));
+        assert_eq!(
+            outcome.total_rewarded.0 + outcome.treasury_cut.0,
+            outcome.total_slashed.0
+        );
+        // The leftover is an ft_transfer of voting tokens to the treasury
+        let treasury = account(TREASURY_ACCOUNT);
+        assert!(near_sdk::test_utils::get_created_receipts()
+            .iter()
+            .filter(|receipt| receipt.receiver_id == account(TOKEN_ACCOUNT))
+            .flat_map(|receipt| receipt.actions.iter())
+            .any(|action| match action {
+                near_sdk::mock::MockAction::FunctionCallWeight {
+                    method_name, args, ..
+                } => {
+                    let args: near_sdk::serde_json::Value =
+                        near_sdk::serde_json::from_slice(args).unwrap();
+                    method_name == b"ft_transfer"
+                        && args["receiver_id"] == treasury.as_str()
+                        && args["amount"] == "1"
                 }
-            }
-        }
+                _ => false,
+            }));
     }
 
-    fn transfer_ft(&self, token: AccountId, receiver_id: AccountId, amount: u128) {
-        if amount == 0 {
-            return;
-        }
-        let _ = Promise::new(token).function_call(
-            "ft_transfer".to_string(),
-            near_sdk::serde_json::json!({
-                "receiver_id": receiver_id,
-                "amount": U128(amount),
-            })
-            .to_string()
-            .into_bytes(),
-            NearToken::from_yoctonear(1),
-            GAS_FOR_FT_TRANSFER,
+    #[test]
+    fn test_open_request_keeps_snapshotted_participation_rate() {
+        testing_env!(get_context(accounts(0), 0).build());
+        let mut contract = setup_contract();
+        contract.set_min_participation_rate(0);
+        let request_id = contract.request_price(
+            "YES_OR_NO_QUERY".to_string(),
+            1000,
+            b"test".to_vec(),
+            None,
+            None,
+            None,
+        );
+        let salt = [1u8; 32];
+        commit(
+            &mut contract,
+            accounts(1),
+            request_id,
+            100,
+            Voting::compute_vote_hash_static(&request_id, 0, salt),
+        );
+        commit(&mut contract, accounts(2), request_id, 900, [2u8; 32]);
+
+        // Raised after the request opened; 10% revealed stake would now fall short
+        testing_env!(get_context(accounts(0), 2).build());
+        contract.set_min_participation_rate(5_000);
+        assert_eq!(
+            contract
+                .get_request(request_id)
+                .unwrap()
+                .min_participation_rate,
+            0
+        );
+
+        testing_env!(get_context(accounts(0), DEFAULT_COMMIT_DURATION + 2).build());
+        contract.advance_to_reveal(request_id);
+        testing_env!(get_context(accounts(1), DEFAULT_COMMIT_DURATION + 3).build());
+        contract.reveal_vote(request_id, 0, salt);
+        assert!(contract.is_participation_sufficient(request_id));
+
+        testing_env!(get_context(
+            accounts(0),
+            DEFAULT_COMMIT_DURATION + DEFAULT_REVEAL_DURATION + 10
+        )
+        .build());
+        assert_eq!(
+            contract.resolve_price(request_id),
+            ResolvePriceOutcome::Resolved { price: 0 }
         );
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use near_sdk::test_utils::{accounts, VMContextBuilder};
-    use near_sdk::testing_env;
-    const TOKEN_ACCOUNT: &str = "token.testnet";
-    const TREASURY_ACCOUNT: &str = "treasury.testnet";
+    #[test]
+    #[should_panic(expected = "Hash doesn't match commitment")]
+    fn test_commitment_cannot_be_replayed_on_another_request() {
+        testing_env!(get_context(accounts(0), 0).build());
+        let mut contract = setup_contract();
+        let request_a = contract.request_price(
+            "YES_OR_NO_QUERY".to_string(),
+            1000,
+            b"a".to_vec(),
+            None,
+            None,
+            None,
+        );
+        let request_b = contract.request_price(
+            "YES_OR_NO_QUERY".to_string(),
+            1000,
+            b"b".to_vec(),
+            None,
+            None,
+            None,
+        );
+        let salt = [8u8; 32];
+        let hash_a = Voting::compute_vote_hash_static(&request_a, 1, salt);
+        assert_ne!(
+            hash_a,
+            Voting::compute_vote_hash_static(&request_b, 1, salt)
+        );
 
-    fn account(id: &str) -> AccountId {
-        id.parse().unwrap()
+        // Voter 2 copies voter 1's commitment for request A onto request B
+        commit(&mut contract, accounts(1), request_a, 100, hash_a);
+        commit(&mut contract, accounts(2), request_b, 100, hash_a);
+
+        testing_env!(get_context(accounts(0), DEFAULT_COMMIT_DURATION + 2).build());
+        contract.advance_to_reveal(request_a);
+        contract.advance_to_reveal(request_b);
+
+        testing_env!(get_context(accounts(1), DEFAULT_COMMIT_DURATION + 3).build());
+        assert!(contract.would_reveal_succeed(request_a, accounts(1), 1, salt));
+        assert!(!contract.would_reveal_succeed(request_b, accounts(2), 1, salt));
+
+        testing_env!(get_context(accounts(2), DEFAULT_COMMIT_DURATION + 3).build());
+        contract.reveal_vote(request_b, 1, salt);
     }
 
-    fn get_context(predecessor: AccountId, block_timestamp: u64) -> VMContextBuilder {
-        let mut builder = VMContextBuilder::new();
-        builder
-            .predecessor_account_id(predecessor)
-            .block_timestamp(block_timestamp);
-        builder
+    /// Creates a request from `requester` with one revealed vote for 1, ready to resolve.
+    fn setup_revealed_request(contract: &mut Voting, requester: AccountId) -> CryptoHash {
+        testing_env!(get_context(requester, 0).build());
+        let request_id = contract.request_price(
+            "YES_OR_NO_QUERY".to_string(),
+            1000,
+            b"test".to_vec(),
+            None,
+            None,
+            None,
+        );
+        let salt = [5u8; 32];
+        commit(
+            contract,
+            accounts(1),
+            request_id,
+            100,
+            Voting::compute_vote_hash_static(&request_id, 1, salt),
+        );
+        testing_env!(get_context(accounts(0), DEFAULT_COMMIT_DURATION + 2).build());
+        contract.advance_to_reveal(request_id);
+        testing_env!(get_context(accounts(1), DEFAULT_COMMIT_DURATION + 3).build());
+        contract.reveal_vote(request_id, 1, salt);
+        request_id
     }
 
-    fn setup_contract() -> Voting {
-        let mut contract = Voting::new(accounts(0));
-        contract.set_voting_token(account(TOKEN_ACCOUNT));
-        contract.set_treasury(account(TREASURY_ACCOUNT));
-        contract
+    #[test]
+    fn test_resolve_price_notifies_resolution_callback() {
+        testing_env!(get_context(accounts(0), 0).build());
+        let mut contract = setup_contract();
+        let request_id = setup_revealed_request(&mut contract, accounts(3));
+        assert_eq!(
+            contract
+                .get_request(request_id)
+                .unwrap()
+                .resolution_callback,
+            Some(accounts(3))
+        );
+
+        testing_env!(get_context(
+            accounts(0),
+            DEFAULT_COMMIT_DURATION + DEFAULT_REVEAL_DURATION + 10
+        )
+        .build());
+        contract.resolve_price(request_id);
+        assert!(near_sdk::test_utils::get_created_receipts()
+            .iter()
+            .any(|receipt| receipt.receiver_id == accounts(3)));
     }
 
     #[test]
-    fn test_new() {
-        let context = get_context(accounts(0), 0);
-        testing_env!(context.build());
+    fn test_cleared_resolution_callback_is_not_notified() {
+        testing_env!(get_context(accounts(0), 0).build());
+        let mut contract = setup_contract();
+        let request_id = setup_revealed_request(&mut contract, accounts(3));
 
-        let contract = Voting::new(accounts(0));
-        assert_eq!(contract.get_owner(), accounts(0));
+        testing_env!(get_context(accounts(3), DEFAULT_COMMIT_DURATION + 3).build());
+        contract.set_resolution_callback(request_id, None);
 
-        let (commit_dur, reveal_dur, min_part) = contract.get_config();
-        assert_eq!(commit_dur, DEFAULT_COMMIT_DURATION);
-        assert_eq!(reveal_dur, DEFAULT_REVEAL_DURATION);
-        assert_eq!(min_part, 500);
+        testing_env!(get_context(
+            accounts(0),
+            DEFAULT_COMMIT_DURATION + DEFAULT_REVEAL_DURATION + 10
+        )
+        .build());
+        contract.resolve_price(request_id);
+        assert!(near_sdk::test_utils::get_created_receipts()
+            .iter()
+            .all(|receipt| receipt.receiver_id != accounts(3)));
     }
 
     #[test]
-    fn test_request_price() {
-        let context = get_context(accounts(0), 0);
-        testing_env!(context.build());
+    #[should_panic(expected = "Only the requester can set the resolution callback")]
+    fn test_set_resolution_callback_requires_requester() {
+        testing_env!(get_context(accounts(0), 0).build());
+        let mut contract = setup_contract();
+        let request_id = setup_revealed_request(&mut contract, accounts(3));
 
-        let mut contract = Voting::new(accounts(0));
+        testing_env!(get_context(accounts(2), DEFAULT_COMMIT_DURATION + 3).build());
+        contract.set_resolution_callback(request_id, Some(accounts(2)));
+    }
 
-        let request_id =
-            contract.request_price("YES_OR_NO_QUERY".to_string(), 1000, b"test claim".to_vec());
+    #[test]
+    fn test_commitments_for_request_id_starting_with_outer_prefix() {
+        testing_env!(get_context(accounts(0), 0).build());
+        let mut contract = setup_contract();
+
+        // Find a timestamp whose request id starts with b'c', the outer commitments prefix
+        let timestamp = (0..10_000u64)
+            .find(|timestamp| dvm_request_id("YES_OR_NO_QUERY", *timestamp, b"test", 0)[0] == b'c')
+            .unwrap();
+        let request_id = contract.request_price(
+            "YES_OR_NO_QUERY".to_string(),
+            timestamp,
+            b"test".to_vec(),
+            None,
+            None,
+            None,
+        );
+        assert_eq!(request_id[0], b'c');
+        assert_ne!(Voting::commitments_prefix(&request_id)[0], b'c');
+
+        let salt = [3u8; 32];
+        commit(
+            &mut contract,
+            accounts(1),
+            request_id,
+            100,
+            Voting::compute_vote_hash_static(&request_id, 1, salt),
+        );
+        commit(&mut contract, accounts(2), request_id, 50, [4u8; 32]);
+        assert_eq!(contract.get_total_committed_stake(request_id), U128(150));
+
+        testing_env!(get_context(accounts(0), DEFAULT_COMMIT_DURATION + 2).build());
+        contract.advance_to_reveal(request_id);
+        testing_env!(get_context(accounts(1), DEFAULT_COMMIT_DURATION + 3).build());
+        contract.reveal_vote(request_id, 1, salt);
 
+        testing_env!(get_context(
+            accounts(0),
+            DEFAULT_COMMIT_DURATION + DEFAULT_REVEAL_DURATION + 10
+        )
+        .build());
+        assert_eq!(
+            contract.resolve_price(request_id),
+            ResolvePriceOutcome::Resolved { price: 1 }
+        );
         let request = contract.get_request(request_id).unwrap();
-        assert_eq!(request.identifier, "YES_OR_NO_QUERY");
-        assert_eq!(request.timestamp, 1000);
-        assert_eq!(request.status, RequestStatus::Active);
-        assert_eq!(request.phase, VotingPhase::Commit);
-        assert_eq!(request.revealed_stake, 0);
+        assert_eq!(request.timestamp, timestamp);
+        assert_eq!(request.revealed_stake, 100);
     }
 
     #[test]
-    fn test_multiple_requests_same_params() {
-        // Each request gets a unique nonce, so same parameters can create
-        // multiple price requests (this is valid behavior)
-        let context = get_context(accounts(0), 0);
-        testing_env!(context.build());
+    fn test_cleanup_request_frees_storage() {
+        testing_env!(get_context(accounts(0), 0).build());
+        let mut contract = setup_contract();
+        let request_id = setup_revealed_request(&mut contract, accounts(0));
+
+        let after_reveal = DEFAULT_COMMIT_DURATION + DEFAULT_REVEAL_DURATION + 10;
+        testing_env!(get_context(accounts(0), after_reveal).build());
+        contract.resolve_price(request_id);
+        testing_env!(get_context(accounts(1), after_reveal).build());
+        contract.claim_reward(request_id);
+        contract.commitments.get_mut(&request_id).unwrap().flush();
+        contract.commitments.flush();
+        contract.requests.flush();
+        contract.total_committed_stake.flush();
+        contract.request_voters.flush();
+
+        testing_env!(get_context(accounts(4), after_reveal + 1).build());
+        let storage_before = env::storage_usage();
+        let refund = contract.cleanup_request(request_id);
+
+        assert!(env::storage_usage() < storage_before);
+        assert!(refund.0 > 0);
+        assert!(contract.get_request(request_id).is_none());
+        assert_eq!(contract.get_total_committed_stake(request_id), U128(0));
+        // The resolved price stays available to a requester that has not read it yet
+        assert_eq!(contract.get_price(request_id), Some(1));
+        assert!(contract.has_price(request_id));
+        assert!(contract.get_resolution_outcome(request_id).is_some());
+    }
 
-        let mut contract = Voting::new(accounts(0));
+    #[test]
+    fn test_failed_transfer_retried_after_cleanup() {
+        testing_env!(get_context(accounts(0), 0).build());
+        let mut contract = setup_contract();
+        let request_id = setup_revealed_request(&mut contract, accounts(0));
+
+        let after_reveal = DEFAULT_COMMIT_DURATION + DEFAULT_REVEAL_DURATION + 10;
+        testing_env!(get_context(accounts(0), after_reveal).build());
+        contract.resolve_price(request_id);
+        testing_env!(get_context(accounts(1), after_reveal).build());
+        let claimed = contract.claim_reward(request_id);
+
+        // The claim transfer fails after its reward entry is gone
+        testing_env!(
+            get_context(accounts(0), after_reveal).build(),
+            near_sdk::test_vm_config(),
+            near_sdk::RuntimeFeesConfig::test(),
+            Default::default(),
+            vec![PromiseResult::Failed]
+        );
+        contract.on_transfer_ft_complete(account(TOKEN_ACCOUNT), accounts(1), claimed, request_id);
 
-        let request_id_1 =
-            contract.request_price("YES_OR_NO_QUERY".to_string(), 1000, b"test".to_vec());
-        let request_id_2 =
-            contract.request_price("YES_OR_NO_QUERY".to_string(), 1000, b"test".to_vec());
+        testing_env!(get_context(accounts(4), after_reveal + 1).build());
+        contract.cleanup_request(request_id);
+        assert!(contract.get_request(request_id).is_none());
 
-        // They should have different IDs
-        assert_ne!(request_id_1, request_id_2);
+        assert_eq!(contract.retry_transfer(request_id, accounts(1)), claimed);
+        assert!(near_sdk::test_utils::get_created_receipts()
+            .iter()
+            .any(|receipt| receipt.receiver_id == account(TOKEN_ACCOUNT)));
+        assert_eq!(contract.get_locked_stake(), U128(0));
     }
 
     #[test]
-    fn test_commit_vote_via_ft_transfer_call() {
-        let context = get_context(accounts(0), 0);
-        testing_env!(context.build());
-
+    #[should_panic(expected = "Emergency refunds not fully claimed")]
+    fn test_cleanup_request_rejects_unclaimed_emergency_refunds() {
+        testing_env!(get_context(accounts(0), 0).build());
         let mut contract = setup_contract();
+        contract.set_max_low_participation_extensions(0);
 
-        let request_id =
-            contract.request_price("YES_OR_NO_QUERY".to_string(), 1000, b"test".to_vec());
+        let request_id = contract.request_price(
+            "YES_OR_NO_QUERY".to_string(),
+            1000,
+            b"test".to_vec(),
+            None,
+            None,
+            None,
+        );
+        commit(&mut contract, accounts(1), request_id, 100, [1u8; 32]);
 
-        let salt = [7u8; 32];
-        let commit_hash = Voting::compute_vote_hash_static(1_000, salt);
-        testing_env!(get_context(account(TOKEN_ACCOUNT), 1).build());
-        let msg = near_sdk::serde_json::to_string(&FtOnTransferMsg::CommitVote {
-            request_id,
-            commit_hash,
-        })
-        .unwrap();
-        contract.ft_on_transfer(accounts(1), U128(1_000), msg);
+        testing_env!(get_context(accounts(0), DEFAULT_COMMIT_DURATION + 2).build());
+        contract.advance_to_reveal(request_id);
+        let after_reveal = DEFAULT_COMMIT_DURATION + DEFAULT_REVEAL_DURATION + 10;
+        testing_env!(get_context(accounts(0), after_reveal).build());
+        contract.resolve_price(request_id);
+        contract.emergency_resolve_price(request_id, 0, "No reveals".to_string());
 
-        assert_eq!(contract.get_total_committed_stake(request_id).0, 1000);
+        contract.cleanup_request(request_id);
+    }
+
+    fn request_with_one_commit(contract: &mut Voting) -> CryptoHash {
+        testing_env!(get_context(accounts(0), 0).build());
+        contract.set_min_commits_to_advance(2);
+        let request_id = contract.request_price(
+            "YES_OR_NO_QUERY".to_string(),
+            1000,
+            b"test".to_vec(),
+            None,
+            None,
+            None,
+        );
+        commit(contract, accounts(1), request_id, 100, [1u8; 32]);
+        request_id
     }
 
     #[test]
-    #[should_panic(expected = "Only voting token can call ft_on_transfer")]
-    fn test_commit_wrong_token_rejected() {
-        let context = get_context(accounts(0), 0);
-        testing_env!(context.build());
+    #[should_panic(expected = "Not enough commits to advance")]
+    fn test_advance_to_reveal_blocked_by_too_few_commits() {
+        testing_env!(get_context(accounts(0), 0).build());
+        let mut contract = setup_contract();
+        let request_id = request_with_one_commit(&mut contract);
 
+        testing_env!(get_context(accounts(0), DEFAULT_COMMIT_DURATION + 2).build());
+        contract.advance_to_reveal(request_id);
+    }
+
+    #[test]
+    fn test_extend_commit_phase_then_advance_after_more_commits() {
+        testing_env!(get_context(accounts(0), 0).build());
         let mut contract = setup_contract();
+        let request_id = request_with_one_commit(&mut contract);
 
-        let request_id =
-            contract.request_price("YES_OR_NO_QUERY".to_string(), 1000, b"test".to_vec());
+        let after_commit = DEFAULT_COMMIT_DURATION + 2;
+        testing_env!(get_context(accounts(0), after_commit).build());
+        assert_eq!(
+            contract.process_request(request_id),
+            ProcessRequestAction::NothingDue
+        );
+        contract.extend_commit_phase(request_id, 1_000);
+        let view = contract.get_request_view(request_id).unwrap();
+        assert_eq!(view.commit_deadline_ns, after_commit + 1_000);
+        assert!(view.is_commit_open);
 
-        let salt = [9u8; 32];
-        let commit_hash = Voting::compute_vote_hash_static(1_000, salt);
-        testing_env!(get_context(accounts(1), 1).build());
-        let msg = near_sdk::serde_json::to_string(&FtOnTransferMsg::CommitVote {
-            request_id,
-            commit_hash,
-        })
-        .unwrap();
-        contract.ft_on_transfer(accounts(1), U128(1_000), msg);
+        testing_env!(get_context(account(TOKEN_ACCOUNT), after_commit + 10).build());
+        contract.ft_on_transfer(
+            accounts(2),
+            U128(100),
+            near_sdk::serde_json::to_string(&FtOnTransferMsg::CommitVote {
+                request_id,
+                commit_hash: [2u8; 32],
+            })
+            .unwrap(),
+        );
+
+        testing_env!(get_context(accounts(3), after_commit + 1_000).build());
+        assert_eq!(
+            contract.process_request(request_id),
+            ProcessRequestAction::AdvancedToReveal
+        );
+        assert_eq!(contract.get_phase(request_id), Some(VotingPhase::Reveal));
     }
 
     #[test]
-    fn test_advance_to_reveal() {
-        let mut context = get_context(accounts(0), 0);
-        testing_env!(context.build());
+    fn test_route_to_emergency_after_too_few_commits() {
+        testing_env!(get_context(accounts(0), 0).build());
+        let mut contract = setup_contract();
+        let request_id = request_with_one_commit(&mut contract);
 
-        let mut contract = Voting::new(accounts(0));
+        testing_env!(get_context(accounts(0), DEFAULT_COMMIT_DURATION + 2).build());
+        contract.route_to_emergency(request_id);
+        let request = contract.get_request(request_id).unwrap();
+        assert_eq!(request.phase, VotingPhase::Reveal);
+        assert!(request.emergency_required);
+        assert!(near_sdk::test_utils::get_logs()
+            .iter()
+            .any(|log| log.contains("\"event\":\"insufficient_commits\"")));
 
-        let request_id =
-            contract.request_price("YES_OR_NO_QUERY".to_string(), 1000, b"test".to_vec());
+        contract.emergency_resolve_price(request_id, 0, "Too few commits".to_string());
+        assert_eq!(contract.get_price(request_id), Some(0));
+    }
 
-        // Fast forward past commit phase
-        context.block_timestamp(DEFAULT_COMMIT_DURATION + 1);
-        testing_env!(context.build());
+    #[test]
+    #[should_panic(expected = "Request awaits emergency resolution")]
+    fn test_routed_request_cannot_be_revealed() {
+        testing_env!(get_context(accounts(0), 0).build());
+        let mut contract = setup_contract();
+        contract.set_min_commits_to_advance(2);
+        let request_id = contract.request_price(
+            "YES_OR_NO_QUERY".to_string(),
+            1000,
+            b"test".to_vec(),
+            None,
+            None,
+            None,
+        );
+        let salt = [1u8; 32];
+        commit(
+            &mut contract,
+            accounts(1),
+            request_id,
+            100,
+            Voting::compute_vote_hash_static(&request_id, NUMERICAL_TRUE, salt),
+        );
 
-        contract.advance_to_reveal(request_id);
+        testing_env!(get_context(accounts(0), DEFAULT_COMMIT_DURATION + 2).build());
+        contract.route_to_emergency(request_id);
 
-        let phase = contract.get_phase(request_id).unwrap();
-        assert_eq!(phase, VotingPhase::Reveal);
+        testing_env!(get_context(accounts(1), DEFAULT_COMMIT_DURATION + 3).build());
+        contract.reveal_vote(request_id, NUMERICAL_TRUE, salt);
     }
 
     #[test]
-    #[should_panic(expected = "Commit phase not yet ended")]
-    fn test_advance_too_early() {
-        let mut context = get_context(accounts(0), 0);
-        testing_env!(context.build());
-
-        let mut contract = Voting::new(accounts(0));
-
-        let request_id =
-            contract.request_price("YES_OR_NO_QUERY".to_string(), 1000, b"test".to_vec());
+    #[should_panic(expected = "Request awaits emergency resolution")]
+    fn test_routed_request_cannot_be_resolved() {
+        testing_env!(get_context(accounts(0), 0).build());
+        let mut contract = setup_contract();
+        let request_id = request_with_one_commit(&mut contract);
 
-        // Try to advance before commit phase ends
-        context.block_timestamp(1000);
-        testing_env!(context.build());
+        testing_env!(get_context(accounts(0), DEFAULT_COMMIT_DURATION + 2).build());
+        contract.route_to_emergency(request_id);
 
-        contract.advance_to_reveal(request_id);
+        testing_env!(get_context(
+            accounts(0),
+            DEFAULT_COMMIT_DURATION + DEFAULT_REVEAL_DURATION + 10
+        )
+        .build());
+        contract.resolve_price(request_id);
     }
 
     #[test]
-    fn test_set_config() {
-        let context = get_context(accounts(0), 0);
-        testing_env!(context.build());
-
-        let mut contract = Voting::new(accounts(0));
+    fn test_claim_reward_after_resolution() {
+        testing_env!(get_context(accounts(0), 0).build());
+        let mut contract = setup_contract();
+        contract.set_min_participation_rate(0);
+        contract.set_slashing_treasury_bps(0);
+
+        let request_id = contract.request_price(
+            "YES_OR_NO_QUERY".to_string(),
+            1000,
+            b"test".to_vec(),
+            None,
+            None,
+            None,
+        );
+        let votes = [
+            (accounts(1), NUMERICAL_TRUE, [1u8; 32], 200),
+            (accounts(2), 0, [2u8; 32], 100),
+        ];
+        for (voter, price, salt, stake) in votes.iter() {
+            commit(
+                &mut contract,
+                voter.clone(),
+                request_id,
+                *stake,
+                Voting::compute_vote_hash_static(&request_id, *price, *salt),
+            );
+        }
+        testing_env!(get_context(accounts(0), DEFAULT_COMMIT_DURATION + 2).build());
+        contract.advance_to_reveal(request_id);
+        for (voter, price, salt, _) in votes.iter() {
+            testing_env!(get_context(voter.clone(), DEFAULT_COMMIT_DURATION + 3).build());
+            contract.reveal_vote(request_id, *price, *salt);
+        }
 
-        contract.set_commit_phase_duration(100);
-        contract.set_reveal_phase_duration(200);
-        contract.set_min_participation_rate(1000);
+        let after_reveal = DEFAULT_COMMIT_DURATION + DEFAULT_REVEAL_DURATION + 10;
+        testing_env!(get_context(accounts(0), after_reveal).build());
+        contract.resolve_price(request_id);
+        // Resolution credits the winner instead of transferring to it
+        assert!(near_sdk::test_utils::get_created_receipts()
+            .iter()
+            .all(|receipt| receipt.receiver_id != account(TOKEN_ACCOUNT)));
+        assert_eq!(
+            contract.get_claimable_reward(request_id, accounts(1)),
+            U128(300)
+        );
+        assert_eq!(
+            contract.get_claimable_reward(request_id, accounts(2)),
+            U128(0)
+        );
+        assert_eq!(contract.get_locked_stake(), U128(300));
 
-        let (commit_dur, reveal_dur, min_part) = contract.get_config();
-        assert_eq!(commit_dur, 100);
-        assert_eq!(reveal_dur, 200);
-        assert_eq!(min_part, 1000);
+        testing_env!(get_context(accounts(1), after_reveal + 1).build());
+        assert_eq!(contract.claim_reward(request_id), U128(300));
+        assert_eq!(
+            contract.get_claimable_reward(request_id, accounts(1)),
+            U128(0)
+        );
+        assert_eq!(contract.get_locked_stake(), U128(0));
+        assert!(near_sdk::test_utils::get_created_receipts()
+            .iter()
+            .any(|receipt| receipt.receiver_id == account(TOKEN_ACCOUNT)));
+        assert!(near_sdk::test_utils::get_logs()
+            .iter()
+            .any(|log| log.contains("\"event\":\"reward_claimed\"")));
     }
 
     #[test]
-    #[should_panic(expected = "Only owner can call this method")]
-    fn test_set_config_unauthorized() {
-        let context = get_context(accounts(0), 0);
-        testing_env!(context.build());
+    #[should_panic(expected = "No reward to claim")]
+    fn test_claim_reward_rejects_double_claim() {
+        testing_env!(get_context(accounts(0), 0).build());
+        let mut contract = setup_contract();
+        let request_id = setup_revealed_request(&mut contract, accounts(0));
 
-        let mut contract = Voting::new(accounts(0));
+        let after_reveal = DEFAULT_COMMIT_DURATION + DEFAULT_REVEAL_DURATION + 10;
+        testing_env!(get_context(accounts(0), after_reveal).build());
+        contract.resolve_price(request_id);
 
-        testing_env!(get_context(accounts(1), 0).build());
-        contract.set_commit_phase_duration(100);
+        testing_env!(get_context(accounts(1), after_reveal + 1).build());
+        contract.claim_reward(request_id);
+        contract.claim_reward(request_id);
     }
 
     #[test]
-    fn test_transfer_ownership() {
-        let context = get_context(accounts(0), 0);
-        testing_env!(context.build());
-
-        let mut contract = Voting::new(accounts(0));
-
-        contract.set_owner(accounts(1));
-        assert_eq!(contract.get_owner(), accounts(1));
+    #[should_panic(expected = "Rewards not fully claimed")]
+    fn test_cleanup_request_rejects_unclaimed_rewards() {
+        testing_env!(get_context(accounts(0), 0).build());
+        let mut contract = setup_contract();
+        let request_id = setup_revealed_request(&mut contract, accounts(0));
 
-        // New owner can set config
-        testing_env!(get_context(accounts(1), 0).build());
-        contract.set_commit_phase_duration(100);
+        let after_reveal = DEFAULT_COMMIT_DURATION + DEFAULT_REVEAL_DURATION + 10;
+        testing_env!(get_context(accounts(0), after_reveal).build());
+        contract.resolve_price(request_id);
+        contract.cleanup_request(request_id);
     }
 
     #[test]
-    fn test_has_price() {
-        let context = get_context(accounts(0), 0);
-        testing_env!(context.build());
+    fn test_get_request_result_for_binary_request() {
+        testing_env!(get_context(accounts(0), 0).build());
+        let mut contract = setup_contract();
+        contract.set_min_participation_rate(0);
 
-        let mut contract = Voting::new(accounts(0));
+        let request_id = contract.request_price(
+            "ASSERT_TRUTH".to_string(),
+            1000,
+            b"test".to_vec(),
+            None,
+            None,
+            Some(true),
+        );
+        let salt = [1u8; 32];
+        commit(
+            &mut contract,
+            accounts(1),
+            request_id,
+            100,
+            Voting::compute_vote_hash_static(&request_id, NUMERICAL_TRUE, salt),
+        );
+        assert_eq!(
+            contract.get_request_result(request_id),
+            Some((0, true, false))
+        );
 
-        let request_id =
-            contract.request_price("YES_OR_NO_QUERY".to_string(), 1000, b"test".to_vec());
+        testing_env!(get_context(accounts(0), DEFAULT_COMMIT_DURATION + 2).build());
+        contract.advance_to_reveal(request_id);
+        testing_env!(get_context(accounts(1), DEFAULT_COMMIT_DURATION + 3).build());
+        contract.reveal_vote(request_id, NUMERICAL_TRUE, salt);
+        testing_env!(get_context(
+            accounts(0),
+            DEFAULT_COMMIT_DURATION + DEFAULT_REVEAL_DURATION + 10
+        )
+        .build());
+        contract.resolve_price(request_id);
 
-        // Not resolved yet
-        assert!(!contract.has_price(request_id));
+        assert_eq!(
+            contract.get_request_result(request_id),
+            Some((NUMERICAL_TRUE, true, true))
+        );
+        assert_eq!(contract.get_request_result([9u8; 32]), None);
     }
 
     #[test]
-    fn test_resolve_price_weighted_median() {
+    fn test_get_request_result_for_numerical_request() {
         testing_env!(get_context(accounts(0), 0).build());
         let mut contract = setup_contract();
         contract.set_min_participation_rate(0);
 
-        let request_id =
-            contract.request_price("YES_OR_NO_QUERY".to_string(), 1000, b"test".to_vec());
-
-        let v1_salt = [1u8; 32];
-        let v2_salt = [2u8; 32];
-        let v3_salt = [3u8; 32];
-        let v1_hash = Voting::compute_vote_hash_static(0, v1_salt);
-        let v2_hash = Voting::compute_vote_hash_static(1, v2_salt);
-        let v3_hash = Voting::compute_vote_hash_static(1, v3_salt);
-
-        testing_env!(get_context(account(TOKEN_ACCOUNT), 1).build());
-        contract.ft_on_transfer(
-            accounts(1),
-            U128(100),
-            near_sdk::serde_json::to_string(&FtOnTransferMsg::CommitVote {
-                request_id,
-                commit_hash: v1_hash,
-            })
-            .unwrap(),
-        );
-        testing_env!(get_context(account(TOKEN_ACCOUNT), 1).build());
-        contract.ft_on_transfer(
-            accounts(2),
-            U128(400),
-            near_sdk::serde_json::to_string(&FtOnTransferMsg::CommitVote {
-                request_id,
-                commit_hash: v2_hash,
-            })
-            .unwrap(),
+        let request_id = contract.request_price(
+            "NUMERICAL".to_string(),
+            1000,
+            b"test".to_vec(),
+            None,
+            None,
+            None,
         );
-        testing_env!(get_context(account(TOKEN_ACCOUNT), 1).build());
-        contract.ft_on_transfer(
-            accounts(3),
-            U128(500),
-            near_sdk::serde_json::to_string(&FtOnTransferMsg::CommitVote {
-                request_id,
-                commit_hash: v3_hash,
-            })
-            .unwrap(),
+        let salt = [2u8; 32];
+        commit(
+            &mut contract,
+            accounts(1),
+            request_id,
+            100,
+            Voting::compute_vote_hash_static(&request_id, 4_200, salt),
         );
-
         testing_env!(get_context(accounts(0), DEFAULT_COMMIT_DURATION + 2).build());
         contract.advance_to_reveal(request_id);
-
         testing_env!(get_context(accounts(1), DEFAULT_COMMIT_DURATION + 3).build());
-        contract.reveal_vote(request_id, 0, v1_salt);
-        testing_env!(get_context(accounts(2), DEFAULT_COMMIT_DURATION + 4).build());
-        contract.reveal_vote(request_id, 1, v2_salt);
-        testing_env!(get_context(accounts(3), DEFAULT_COMMIT_DURATION + 5).build());
-        contract.reveal_vote(request_id, 1, v3_salt);
-
+        contract.reveal_vote(request_id, 4_200, salt);
         testing_env!(get_context(
             accounts(0),
             DEFAULT_COMMIT_DURATION + DEFAULT_REVEAL_DURATION + 10
         )
         .build());
-        let outcome = contract.resolve_price(request_id);
-        assert_eq!(outcome, ResolvePriceOutcome::Resolved { price: 1 });
-        assert!(contract.has_price(request_id));
+        contract.resolve_price(request_id);
+
+        assert_eq!(
+            contract.get_request_result(request_id),
+            Some((4_200, false, true))
+        );
     }
 
     #[test]
-    fn test_low_participation_requires_emergency() {
+    fn test_low_participation_request_listed_until_emergency_resolved() {
         testing_env!(get_context(accounts(0), 0).build());
         let mut contract = setup_contract();
         contract.set_min_participation_rate(9_000);
         contract.set_max_low_participation_extensions(0);
 
-        let request_id =
-            contract.request_price("YES_OR_NO_QUERY".to_string(), 1000, b"test".to_vec());
+        let request_id = contract.request_price(
+            "YES_OR_NO_QUERY".to_string(),
+            1000,
+            b"test".to_vec(),
+            None,
+            None,
+            None,
+        );
         let salt = [1u8; 32];
-        let hash = Voting::compute_vote_hash_static(1, salt);
-
-        testing_env!(get_context(account(TOKEN_ACCOUNT), 1).build());
-        contract.ft_on_transfer(
+        commit(
+            &mut contract,
             accounts(1),
-            U128(100),
-            near_sdk::serde_json::to_string(&FtOnTransferMsg::CommitVote {
-                request_id,
-                commit_hash: hash,
-            })
-            .unwrap(),
+            request_id,
+            100,
+            Voting::compute_vote_hash_static(&request_id, 1, salt),
         );
-        testing_env!(get_context(account(TOKEN_ACCOUNT), 1).build());
-        contract.ft_on_transfer(
+        commit(
+            &mut contract,
             accounts(2),
-            U128(900),
-            near_sdk::serde_json::to_string(&FtOnTransferMsg::CommitVote {
-                request_id,
-                commit_hash: Voting::compute_vote_hash_static(0, [2u8; 32]),
-            })
-            .unwrap(),
+            request_id,
+            900,
+            Voting::compute_vote_hash_static(&request_id, 0, [2u8; 32]),
         );
 
         testing_env!(get_context(accounts(0), DEFAULT_COMMIT_DURATION + 2).build());
         contract.advance_to_reveal(request_id);
         testing_env!(get_context(accounts(1), DEFAULT_COMMIT_DURATION + 3).build());
         contract.reveal_vote(request_id, 1, salt);
+        assert!(contract.get_emergency_pending(0, 10).is_empty());
 
-        testing_env!(get_context(
-            accounts(0),
-            DEFAULT_COMMIT_DURATION + DEFAULT_REVEAL_DURATION + 10
-        )
-        .build());
-        let outcome = contract.resolve_price(request_id);
-        assert_eq!(outcome, ResolvePriceOutcome::EmergencyRequired);
-        let req = contract.get_request(request_id).unwrap();
-        assert!(req.emergency_required);
+        let after_reveal = DEFAULT_COMMIT_DURATION + DEFAULT_REVEAL_DURATION + 10;
+        testing_env!(get_context(accounts(0), after_reveal).build());
+        assert_eq!(
+            contract.resolve_price(request_id),
+            ResolvePriceOutcome::EmergencyRequired
+        );
+        assert_eq!(contract.get_emergency_pending(0, 10), vec![request_id]);
+        assert!(contract.get_emergency_pending(1, 10).is_empty());
 
-        testing_env!(get_context(
-            accounts(0),
-            DEFAULT_COMMIT_DURATION + DEFAULT_REVEAL_DURATION + 11
-        )
-        .build());
-        let emergency =
-            contract.emergency_resolve_price(request_id, 0, "Low participation".to_string());
-        assert_eq!(emergency, 0);
-        assert!(contract.has_price(request_id));
+        contract.emergency_resolve_price(request_id, 1, "Low participation".to_string());
+        assert!(contract.get_emergency_pending(0, 10).is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "Treasury not configured")]
+    fn test_sweep_to_treasury_requires_treasury() {
+        testing_env!(get_context(accounts(0), 0).build());
+        let mut contract = Voting::new(accounts(0));
+        contract.set_voting_token(account(TOKEN_ACCOUNT));
+
+        let _ = contract.sweep_to_treasury(account(TOKEN_ACCOUNT), U128(10));
+    }
+
+    #[test]
+    fn test_failed_transfer_recorded_and_retried() {
+        testing_env!(get_context(accounts(0), 0).build());
+        let mut contract = setup_contract();
+        let request_id = contract.request_price(
+            "YES_OR_NO_QUERY".to_string(),
+            1000,
+            b"test".to_vec(),
+            None,
+            None,
+            None,
+        );
+        let treasury = account(TREASURY_ACCOUNT);
+
+        testing_env!(
+            get_context(accounts(0), 1).build(),
+            near_sdk::test_vm_config(),
+            near_sdk::RuntimeFeesConfig::test(),
+            Default::default(),
+            vec![PromiseResult::Failed]
+        );
+        contract.on_transfer_ft_complete(
+            account(TOKEN_ACCOUNT),
+            treasury.clone(),
+            U128(40),
+            request_id,
+        );
+        assert_eq!(
+            contract.get_failed_transfer(request_id, treasury.clone()),
+            U128(40)
+        );
+        // The unpaid amount is owed again, so it cannot be rescued
+        assert_eq!(contract.get_locked_stake(), U128(40));
+        assert!(near_sdk::test_utils::get_logs()
+            .iter()
+            .any(|log| log.contains("transfer_failed")));
+
+        testing_env!(get_context(accounts(3), 2).build());
+        assert_eq!(
+            contract.retry_transfer(request_id, treasury.clone()),
+            U128(40)
+        );
+        assert_eq!(contract.get_failed_transfer(request_id, treasury), U128(0));
+        assert_eq!(contract.get_locked_stake(), U128(0));
+        assert!(near_sdk::test_utils::get_created_receipts()
+            .iter()
+            .any(|receipt| receipt.receiver_id == account(TOKEN_ACCOUNT)));
+    }
+
+    #[test]
+    #[should_panic(expected = "No failed transfer to retry")]
+    fn test_retry_transfer_requires_failed_transfer() {
+        testing_env!(get_context(accounts(0), 0).build());
+        let mut contract = setup_contract();
+        let request_id = contract.request_price(
+            "YES_OR_NO_QUERY".to_string(),
+            1000,
+            b"test".to_vec(),
+            None,
+            None,
+            None,
+        );
+
+        contract.retry_transfer(request_id, accounts(1));
+    }
+
+    /// Two committers on a new request, advanced to reveal with early resolution enabled.
+    fn setup_early_resolve_request(contract: &mut Voting) -> CryptoHash {
+        contract.set_min_participation_rate(0);
+        contract.set_allow_early_resolve_on_full_reveal(true);
+        assert!(contract.get_allow_early_resolve_on_full_reveal());
+
+        let request_id = contract.request_price(
+            "YES_OR_NO_QUERY".to_string(),
+            1000,
+            b"test".to_vec(),
+            None,
+            None,
+            None,
+        );
+        for (voter, salt) in [(accounts(1), [1u8; 32]), (accounts(2), [2u8; 32])] {
+            commit(
+                contract,
+                voter,
+                request_id,
+                100,
+                Voting::compute_vote_hash_static(&request_id, 1, salt),
+            );
+        }
+
+        testing_env!(get_context(accounts(0), DEFAULT_COMMIT_DURATION).build());
+        contract.advance_to_reveal(request_id);
+        request_id
+    }
+
+    #[test]
+    fn test_full_reveal_allows_early_resolution() {
+        testing_env!(get_context(accounts(0), 0).build());
+        let mut contract = setup_contract();
+        let request_id = setup_early_resolve_request(&mut contract);
+
+        testing_env!(get_context(accounts(1), DEFAULT_COMMIT_DURATION + 1).build());
+        contract.reveal_vote(request_id, 1, [1u8; 32]);
+        testing_env!(get_context(accounts(2), DEFAULT_COMMIT_DURATION + 2).build());
+        contract.reveal_vote(request_id, 1, [2u8; 32]);
+        assert!(contract.get_request_view(request_id).unwrap().is_resolvable);
+
+        testing_env!(get_context(accounts(3), DEFAULT_COMMIT_DURATION + 3).build());
+        assert_eq!(
+            contract.resolve_price(request_id),
+            ResolvePriceOutcome::Resolved { price: 1 }
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Reveal phase not yet ended")]
+    fn test_partial_reveal_keeps_reveal_deadline() {
+        testing_env!(get_context(accounts(0), 0).build());
+        let mut contract = setup_contract();
+        let request_id = setup_early_resolve_request(&mut contract);
+
+        testing_env!(get_context(accounts(1), DEFAULT_COMMIT_DURATION + 1).build());
+        contract.reveal_vote(request_id, 1, [1u8; 32]);
+        assert!(!contract.get_request_view(request_id).unwrap().is_resolvable);
+
+        testing_env!(get_context(accounts(3), DEFAULT_COMMIT_DURATION + 3).build());
+        contract.resolve_price(request_id);
     }
 }
@@ -0,0 +1,498 @@
+//! Aggregator Escalation Manager
+//!
+//! An escalation manager that arbitrates disputes through a quorum of other
+//! escalation managers instead of a single arbiter:
+//! - Forwards `request_price` to every member
+//! - Fans out `get_price` to every member and returns the price backed by a
+//!   strict majority of member weight
+//! - Panics when no price reaches a majority, leaving the dispute to the
+//!   oracle owner's override once it is stale
+//!
+//! Each member must be initialized with this contract as its `oracle`, since
+//! members only accept `request_price` (and mark resolutions consumed on
+//! `get_price`) when called by their oracle.
+
+use near_sdk::{
+    env, near, require, AccountId, Gas, GasWeight, NearToken, PanicOnDefault, Promise,
+    PromiseResult,
+};
+use oracle_types::interfaces::AssertionPolicy;
+use oracle_types::ownership::PendingOwner;
+use oracle_types::types::Bytes32;
+use std::collections::HashSet;
+
+/// Maximum number of member escalation managers.
+pub const MAX_MEMBERS: usize = 5;
+
+/// Minimum gas for each member's `request_price`; members also share any gas left unused.
+const GAS_FOR_MEMBER_REQUEST_PRICE: Gas = Gas::from_tgas(1);
+/// Gas attached to each member's `get_price`.
+const GAS_FOR_MEMBER_GET_PRICE: Gas = Gas::from_tgas(10);
+/// Gas attached to the callback tallying member prices.
+const GAS_FOR_TALLY_CALLBACK: Gas = Gas::from_tgas(10);
+
+/// A member escalation manager and the weight of its price in the tally.
+#[near(serializers = [json, borsh])]
+#[derive(Clone)]
+pub struct Member {
+    /// The member escalation manager contract.
+    pub account_id: AccountId,
+    /// Weight of the member's price; must be positive.
+    pub weight: u32,
+}
+
+/// Events emitted while aggregating member prices.
+#[near(serializers = [json])]
+#[serde(tag = "event", content = "data")]
+#[serde(rename_all = "snake_case")]
+pub enum AggregatorEvent {
+    /// A price was backed by a majority of member weight.
+    MajorityReached {
+        price: String,
+        weight: u64,
+        total_weight: u64,
+    },
+    /// No price was backed by a majority of member weight.
+    NoMajority {
+        responded_weight: u64,
+        total_weight: u64,
+    },
+}
+
+impl AggregatorEvent {
+    fn emit(&self) {
+        env::log_str(&format!(
+            "EVENT_JSON:{}",
+            near_sdk::serde_json::to_string(self).unwrap()
+        ));
+    }
+}
+
+/// Aggregator escalation manager contract.
+///
+/// Arbitrates every dispute itself (`arbitrate_via_escalation_manager`) by
+/// weighted majority of its member escalation managers' `get_price`.
+#[near(contract_state)]
+#[derive(PanicOnDefault)]
+pub struct AggregatorEscalationManager {
+    /// The oracle that this escalation manager works with.
+    oracle: AccountId,
+    /// The owner who can configure members.
+    owner: AccountId,
+    /// Account proposed as the next owner, pending its `accept_ownership`.
+    pending_owner: PendingOwner,
+    /// Member escalation managers, in the order their prices are requested.
+    members: Vec<Member>,
+}
+
+#[near]
+impl AggregatorEscalationManager {
+    /// Initialize the escalation manager.
+    ///
+    /// # Arguments
+    ///
+    /// * `oracle` - The optimistic oracle contract address
+    /// * `members` - The member escalation managers and their weights
+    #[init]
+    pub fn new(oracle: AccountId, members: Vec<Member>) -> Self {
+        Self::assert_valid_members(&members);
+        Self {
+            oracle,
+            owner: env::predecessor_account_id(),
+            pending_owner: PendingOwner::default(),
+            members,
+        }
+    }
+
+    // ========== Owner Methods ==========
+
+    /// Replace the member escalation managers.
+    ///
+    /// Disputes already requested are priced by the new members once settled, so
+    /// change members only while no dispute is awaiting resolution.
+    pub fn set_members(&mut self, members: Vec<Member>) {
+        self.assert_only_owner();
+        Self::assert_valid_members(&members);
+        self.members = members;
+    }
+
+    /// Propose a new owner, who takes over once it calls `accept_ownership`.
+    pub fn propose_owner(&mut self, new_owner: AccountId) {
+        self.assert_only_owner();
+        self.pending_owner.propose(new_owner);
+    }
+
+    /// Accept a pending ownership proposal. Only the proposed owner can call this.
+    pub fn accept_ownership(&mut self) {
+        self.pending_owner
+            .accept(&mut self.owner, "aggregator_escalation_manager");
+    }
+
+    // ========== Escalation Manager Interface ==========
+
+    /// Returns the assertion policy for a given assertion.
+    ///
+    /// Disputes are always arbitrated by the members rather than the DVM.
+    pub fn get_assertion_policy(&self, _assertion_id: Bytes32) -> AssertionPolicy {
+        AssertionPolicy {
+            arbitrate_via_escalation_manager: true,
+            ..AssertionPolicy::default()
+        }
+    }
+
+    /// Validates whether a dispute should be allowed.
+    ///
+    /// All disputes are allowed; members decide the outcome, not who may dispute.
+    pub fn is_dispute_allowed(&self, _assertion_id: Bytes32, _dispute_caller: AccountId) -> bool {
+        true
+    }
+
+    /// Called when a price is requested for dispute resolution.
+    ///
+    /// Forwards the request to every member. Only callable by the oracle.
+    pub fn request_price(&mut self, identifier: Bytes32, time: u64, ancillary_data: Vec<u8>) {
+        self.assert_only_oracle();
+
+        let args = near_sdk::serde_json::json!({
+            "identifier": identifier,
+            "time": time,
+            "ancillary_data": ancillary_data,
+        })
+        .to_string()
+        .into_bytes();
+        for member in &self.members {
+            let _ = Promise::new(member.account_id.clone()).function_call_weight(
+                "request_price".to_string(),
+                args.clone(),
+                NearToken::from_yoctonear(0),
+                GAS_FOR_MEMBER_REQUEST_PRICE,
+                GasWeight(1),
+            );
+        }
+    }
+
+    /// Returns the resolution for a disputed assertion.
+    ///
+    /// Requests `get_price` from every member and resolves to the price backed by a
+    /// strict majority of member weight (see `on_member_prices`). The members queried are
+    /// passed along, so the tally matches the results even if members change meanwhile.
+    pub fn get_price(&self, identifier: Bytes32, time: u64, ancillary_data: Vec<u8>) -> Promise {
+        let args = near_sdk::serde_json::json!({
+            "identifier": identifier,
+            "time": time,
+            "ancillary_data": ancillary_data,
+        })
+        .to_string()
+        .into_bytes();
+        let member_price = |member: &Member| {
+            Promise::new(member.account_id.clone()).function_call(
+                "get_price".to_string(),
+                args.clone(),
+                NearToken::from_yoctonear(0),
+                GAS_FOR_MEMBER_GET_PRICE,
+            )
+        };
+
+        let (first, rest) = self.members.split_first().expect("No members configured");
+        rest.iter()
+            .fold(member_price(first), |prices, member| {
+                prices.and(member_price(member))
+            })
+            .then(
+                Promise::new(env::current_account_id()).function_call(
+                    "on_member_prices".to_string(),
+                    near_sdk::serde_json::json!({ "members": self.members })
+                        .to_string()
+                        .into_bytes(),
+                    NearToken::from_yoctonear(0),
+                    GAS_FOR_TALLY_CALLBACK,
+                ),
+            )
+    }
+
+    /// Callback tallying the `get_price` results of `members`, the members queried in
+    /// that order.
+    ///
+    /// Members whose call failed (e.g. no resolution set yet) abstain but still count
+    /// towards the total weight. Panics when no price has a strict majority, leaving the
+    /// dispute unsettled until the oracle owner overrides it.
+    #[allow(deprecated)]
+    #[private]
+    pub fn on_member_prices(&mut self, members: Vec<Member>) -> i128 {
+        require!(
+            env::promise_results_count() == members.len() as u64,
+            "Expected one promise result per member"
+        );
+
+        let prices: Vec<Option<i128>> = (0..members.len() as u64)
+            .map(|index| match env::promise_result(index) {
+                PromiseResult::Successful(value) => near_sdk::serde_json::from_slice(&value).ok(),
+                _ => None,
+            })
+            .collect();
+
+        let total_weight = Self::total_weight(&members);
+        match Self::majority_price(&members, &prices, total_weight) {
+            Some((price, weight)) => {
+                AggregatorEvent::MajorityReached {
+                    price: price.to_string(),
+                    weight,
+                    total_weight,
+                }
+                .emit();
+                price
+            }
+            None => {
+                // Logged before the panic so indexers still see the deadlock
+                AggregatorEvent::NoMajority {
+                    responded_weight: members
+                        .iter()
+                        .zip(&prices)
+                        .filter(|(_, price)| price.is_some())
+                        .map(|(member, _)| member.weight as u64)
+                        .sum(),
+                    total_weight,
+                }
+                .emit();
+                env::panic_str("No majority among escalation managers")
+            }
+        }
+    }
+
+    /// Callback when an assertion is resolved.
+    ///
+    /// Does nothing. Only callable by the oracle.
+    pub fn assertion_resolved_callback(
+        &mut self,
+        _assertion_id: String,
+        _asserted_truthfully: bool,
+    ) {
+        self.assert_only_oracle();
+    }
+
+    /// Callback when an assertion is disputed.
+    ///
+    /// Does nothing. Only callable by the oracle.
+    pub fn assertion_disputed_callback(&mut self, _assertion_id: String) {
+        self.assert_only_oracle();
+    }
+
+    // ========== View Methods ==========
+
+    pub fn get_oracle(&self) -> &AccountId {
+        &self.oracle
+    }
+
+    pub fn get_owner(&self) -> &AccountId {
+        &self.owner
+    }
+
+    /// Get the account proposed as the next owner, if any.
+    pub fn get_pending_owner(&self) -> Option<AccountId> {
+        self.pending_owner.get()
+    }
+
+    /// Get the member escalation managers and their weights.
+    pub fn get_members(&self) -> &Vec<Member> {
+        &self.members
+    }
+
+    // ========== Internal ==========
+
+    /// The price backed by more than half of the total member weight, with that weight.
+    fn majority_price(
+        members: &[Member],
+        prices: &[Option<i128>],
+        total_weight: u64,
+    ) -> Option<(i128, u64)> {
+        let mut tally: Vec<(i128, u64)> = Vec::new();
+        for (member, price) in members.iter().zip(prices) {
+            let Some(price) = price else {
+                continue;
+            };
+            match tally.iter_mut().find(|(tallied, _)| tallied == price) {
+                Some(entry) => entry.1 += member.weight as u64,
+                None => tally.push((*price, member.weight as u64)),
+            }
+        }
+        tally
+            .into_iter()
+            .find(|(_, weight)| weight * 2 > total_weight)
+    }
+
+    fn total_weight(members: &[Member]) -> u64 {
+        members.iter().map(|member| member.weight as u64).sum()
+    }
+
+    fn assert_valid_members(members: &[Member]) {
+        require!(!members.is_empty(), "At least one member required");
+        require!(members.len() <= MAX_MEMBERS, "Too many members");
+        let mut seen = HashSet::new();
+        for member in members {
+            require!(member.weight > 0, "Member weight must be positive");
+            require!(seen.insert(member.account_id.clone()), "Duplicate member");
+        }
+    }
+
+    fn assert_only_oracle(&self) {
+        require!(
+            env::predecessor_account_id() == self.oracle,
+            "Only the oracle can call this method"
+        );
+    }
+
+    fn assert_only_owner(&self) {
+        require!(
+            env::predecessor_account_id() == self.owner,
+            "Only the owner can call this method"
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use near_sdk::test_utils::VMContextBuilder;
+    use near_sdk::testing_env;
+
+    const AGGREGATOR: &str = "aggregator.near";
+    const NUMERICAL_TRUE: i128 = 1_000_000_000_000_000_000;
+
+    fn account(id: &str) -> AccountId {
+        id.parse().unwrap()
+    }
+
+    fn context(predecessor: &str) -> VMContextBuilder {
+        let mut builder = VMContextBuilder::new();
+        builder
+            .predecessor_account_id(account(predecessor))
+            .current_account_id(account(AGGREGATOR));
+        builder
+    }
+
+    fn set_context_with_results(predecessor: &str, promise_results: Vec<PromiseResult>) {
+        testing_env!(
+            context(predecessor).build(),
+            near_sdk::test_vm_config(),
+            near_sdk::RuntimeFeesConfig::test(),
+            Default::default(),
+            promise_results
+        );
+    }
+
+    fn price_result(price: i128) -> PromiseResult {
+        PromiseResult::Successful(near_sdk::serde_json::to_vec(&price).unwrap())
+    }
+
+    fn setup() -> AggregatorEscalationManager {
+        testing_env!(context("owner.near").build());
+        let members = ["em-a.near", "em-b.near", "em-c.near"]
+            .into_iter()
+            .map(|id| Member {
+                account_id: account(id),
+                weight: 1,
+            })
+            .collect();
+        AggregatorEscalationManager::new(account("oracle.near"), members)
+    }
+
+    #[test]
+    fn test_two_of_three_agreement_resolves() {
+        let mut manager = setup();
+        set_context_with_results(
+            AGGREGATOR,
+            vec![
+                price_result(NUMERICAL_TRUE),
+                price_result(0),
+                price_result(NUMERICAL_TRUE),
+            ],
+        );
+
+        let members = manager.get_members().clone();
+        assert_eq!(manager.on_member_prices(members), NUMERICAL_TRUE);
+        assert!(near_sdk::test_utils::get_logs()
+            .iter()
+            .any(|log| log.contains("majority_reached")));
+    }
+
+    #[test]
+    #[should_panic(expected = "No majority among escalation managers")]
+    fn test_no_majority_panics() {
+        let mut manager = setup();
+        // One member has no resolution yet; the other two disagree
+        set_context_with_results(
+            AGGREGATOR,
+            vec![
+                price_result(NUMERICAL_TRUE),
+                price_result(0),
+                PromiseResult::Failed,
+            ],
+        );
+
+        let members = manager.get_members().clone();
+        manager.on_member_prices(members);
+    }
+
+    #[test]
+    fn test_weighted_member_outvotes_others() {
+        let mut manager = setup();
+        manager.set_members(vec![
+            Member {
+                account_id: account("em-a.near"),
+                weight: 3,
+            },
+            Member {
+                account_id: account("em-b.near"),
+                weight: 1,
+            },
+            Member {
+                account_id: account("em-c.near"),
+                weight: 1,
+            },
+        ]);
+        set_context_with_results(
+            AGGREGATOR,
+            vec![
+                price_result(0),
+                price_result(NUMERICAL_TRUE),
+                price_result(NUMERICAL_TRUE),
+            ],
+        );
+
+        let members = manager.get_members().clone();
+        assert_eq!(manager.on_member_prices(members), 0);
+    }
+
+    #[test]
+    fn test_tally_uses_members_queried() {
+        let mut manager = setup();
+        let queried = manager.get_members().clone();
+        // Members change while the `get_price` results are in flight
+        testing_env!(context("owner.near").build());
+        manager.set_members(vec![Member {
+            account_id: account("em-d.near"),
+            weight: 1,
+        }]);
+        set_context_with_results(
+            AGGREGATOR,
+            vec![
+                price_result(NUMERICAL_TRUE),
+                price_result(NUMERICAL_TRUE),
+                price_result(0),
+            ],
+        );
+
+        assert_eq!(manager.on_member_prices(queried), NUMERICAL_TRUE);
+    }
+
+    #[test]
+    #[should_panic(expected = "Duplicate member")]
+    fn test_set_members_rejects_duplicates() {
+        let mut manager = setup();
+        let member = Member {
+            account_id: account("em-a.near"),
+            weight: 1,
+        };
+        manager.set_members(vec![member.clone(), member]);
+    }
+}
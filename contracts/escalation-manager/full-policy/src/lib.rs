@@ -9,6 +9,7 @@
 
 use near_sdk::{env, near, require, AccountId, PanicOnDefault};
 use oracle_types::interfaces::AssertionPolicy;
+use oracle_types::ownership::PendingOwner;
 use oracle_types::types::Bytes32;
 use std::collections::{HashMap, HashSet};
 
@@ -20,6 +21,36 @@ pub struct ArbitrationResolution {
     pub value_set: bool,
     /// The resolution (true = assertion correct, false = assertion incorrect).
     pub resolution: bool,
+    /// Whether the oracle has read the resolution via `get_price`; it can no longer be cleared.
+    pub consumed: bool,
+}
+
+/// Events emitted over an arbitration resolution's lifecycle.
+#[near(serializers = [json])]
+#[serde(tag = "event", content = "data")]
+#[serde(rename_all = "snake_case")]
+pub enum ArbitrationEvent {
+    /// The owner set a resolution.
+    ArbitrationResolutionSet {
+        request_id: String,
+        resolution: bool,
+    },
+    /// The owner cleared a resolution before the oracle read it.
+    ArbitrationResolutionCleared { request_id: String },
+    /// The oracle read the resolution.
+    ArbitrationResolutionConsumed {
+        request_id: String,
+        resolution: bool,
+    },
+}
+
+impl ArbitrationEvent {
+    fn emit(&self) {
+        env::log_str(&format!(
+            "EVENT_JSON:{}",
+            near_sdk::serde_json::to_string(self).unwrap()
+        ));
+    }
 }
 
 /// Numerical representation of "true" (1e18).
@@ -39,6 +70,8 @@ pub struct FullPolicyEscalationManager {
     oracle: AccountId,
     /// The owner who can configure policies.
     owner: AccountId,
+    /// Account proposed as the next owner, pending its `accept_ownership`.
+    pending_owner: PendingOwner,
 
     // Policy flags
     /// If true, only whitelisted asserting callers can create assertions.
@@ -72,6 +105,7 @@ impl FullPolicyEscalationManager {
         Self {
             oracle,
             owner: env::predecessor_account_id(),
+            pending_owner: PendingOwner::default(),
             block_by_asserting_caller: false,
             block_by_asserter: false,
             validate_disputers: false,
@@ -142,12 +176,45 @@ impl FullPolicyEscalationManager {
         );
 
         self.arbitration_resolutions.insert(
-            request_id,
+            request_id.clone(),
             ArbitrationResolution {
                 value_set: true,
                 resolution,
+                consumed: false,
             },
         );
+
+        ArbitrationEvent::ArbitrationResolutionSet {
+            request_id,
+            resolution,
+        }
+        .emit();
+    }
+
+    /// Clear an arbitration resolution so it can be set again.
+    ///
+    /// Only allowed before the oracle has consumed the resolution via `get_price`.
+    pub fn clear_arbitration_resolution(
+        &mut self,
+        identifier: Bytes32,
+        time: u64,
+        ancillary_data: Vec<u8>,
+    ) {
+        self.assert_only_owner();
+
+        let request_id = Self::get_request_id(&identifier, time, &ancillary_data);
+        let resolution = self
+            .arbitration_resolutions
+            .get(&request_id)
+            .expect("Arbitration resolution not set");
+        require!(
+            !resolution.consumed,
+            "Arbitration resolution already consumed"
+        );
+
+        self.arbitration_resolutions.remove(&request_id);
+
+        ArbitrationEvent::ArbitrationResolutionCleared { request_id }.emit();
     }
 
     /// Add/remove an asserting caller from the whitelist.
@@ -180,10 +247,16 @@ impl FullPolicyEscalationManager {
         }
     }
 
-    /// Transfer ownership.
-    pub fn set_owner(&mut self, new_owner: AccountId) {
+    /// Propose a new owner, who takes over once it calls `accept_ownership`.
+    pub fn propose_owner(&mut self, new_owner: AccountId) {
         self.assert_only_owner();
-        self.owner = new_owner;
+        self.pending_owner.propose(new_owner);
+    }
+
+    /// Accept a pending ownership proposal. Only the proposed owner can call this.
+    pub fn accept_ownership(&mut self) {
+        self.pending_owner
+            .accept(&mut self.owner, "full_policy_escalation_manager");
     }
 
     // ========== Escalation Manager Interface ==========
@@ -237,16 +310,28 @@ impl FullPolicyEscalationManager {
     }
 
     /// Get the arbitration resolution.
-    pub fn get_price(&self, identifier: Bytes32, time: u64, ancillary_data: Vec<u8>) -> i128 {
+    ///
+    /// When called by the oracle, the resolution is marked consumed and can no longer be cleared.
+    pub fn get_price(&mut self, identifier: Bytes32, time: u64, ancillary_data: Vec<u8>) -> i128 {
         let request_id = Self::get_request_id(&identifier, time, &ancillary_data);
+        let from_oracle = env::predecessor_account_id() == self.oracle;
 
         let resolution = self
             .arbitration_resolutions
-            .get(&request_id)
+            .get_mut(&request_id)
             .expect("Arbitration resolution not set");
 
         require!(resolution.value_set, "Arbitration resolution not set");
 
+        if from_oracle && !resolution.consumed {
+            resolution.consumed = true;
+            ArbitrationEvent::ArbitrationResolutionConsumed {
+                request_id,
+                resolution: resolution.resolution,
+            }
+            .emit();
+        }
+
         if resolution.resolution {
             NUMERICAL_TRUE
         } else {
@@ -278,6 +363,11 @@ impl FullPolicyEscalationManager {
         &self.owner
     }
 
+    /// Get the account proposed as the next owner, if any.
+    pub fn get_pending_owner(&self) -> Option<AccountId> {
+        self.pending_owner.get()
+    }
+
     pub fn get_config(
         &self,
     ) -> (
@@ -308,6 +398,17 @@ impl FullPolicyEscalationManager {
         self.whitelisted_dispute_callers.contains(&caller)
     }
 
+    /// Get the stored arbitration resolution without consuming it.
+    pub fn get_arbitration_resolution(
+        &self,
+        identifier: Bytes32,
+        time: u64,
+        ancillary_data: Vec<u8>,
+    ) -> Option<ArbitrationResolution> {
+        let request_id = Self::get_request_id(&identifier, time, &ancillary_data);
+        self.arbitration_resolutions.get(&request_id).cloned()
+    }
+
     /// Get the request ID for a price request.
     pub fn get_request_id(identifier: &Bytes32, time: u64, ancillary_data: &[u8]) -> String {
         use near_sdk::env::keccak256;
@@ -336,3 +437,90 @@ impl FullPolicyEscalationManager {
         );
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use near_sdk::test_utils::VMContextBuilder;
+    use near_sdk::testing_env;
+
+    const IDENTIFIER: Bytes32 = [1u8; 32];
+    const TIME: u64 = 100;
+
+    fn account(id: &str) -> AccountId {
+        id.parse().unwrap()
+    }
+
+    fn set_predecessor(predecessor: &str) {
+        testing_env!(VMContextBuilder::new()
+            .predecessor_account_id(account(predecessor))
+            .build());
+    }
+
+    fn setup() -> FullPolicyEscalationManager {
+        set_predecessor("owner.near");
+        FullPolicyEscalationManager::new(account("oracle.near"))
+    }
+
+    #[test]
+    fn test_set_clear_and_reset_resolution() {
+        let mut manager = setup();
+
+        manager.set_arbitration_resolution(IDENTIFIER, TIME, vec![7], true);
+        manager.clear_arbitration_resolution(IDENTIFIER, TIME, vec![7]);
+        assert!(manager
+            .get_arbitration_resolution(IDENTIFIER, TIME, vec![7])
+            .is_none());
+
+        manager.set_arbitration_resolution(IDENTIFIER, TIME, vec![7], false);
+        let logs = near_sdk::test_utils::get_logs();
+        assert!(logs
+            .iter()
+            .any(|log| log.contains("arbitration_resolution_cleared")));
+
+        set_predecessor("oracle.near");
+        assert_eq!(manager.get_price(IDENTIFIER, TIME, vec![7]), 0);
+        assert!(
+            manager
+                .get_arbitration_resolution(IDENTIFIER, TIME, vec![7])
+                .unwrap()
+                .consumed
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Arbitration resolution already consumed")]
+    fn test_clear_rejected_after_consumption() {
+        let mut manager = setup();
+        manager.set_arbitration_resolution(IDENTIFIER, TIME, vec![7], true);
+
+        set_predecessor("oracle.near");
+        assert_eq!(manager.get_price(IDENTIFIER, TIME, vec![7]), NUMERICAL_TRUE);
+
+        set_predecessor("owner.near");
+        manager.clear_arbitration_resolution(IDENTIFIER, TIME, vec![7]);
+    }
+
+    #[test]
+    fn test_two_step_ownership_transfer() {
+        let mut manager = setup();
+        manager.propose_owner(account("new-owner.near"));
+        assert_eq!(manager.get_owner(), &account("owner.near"));
+
+        set_predecessor("new-owner.near");
+        manager.accept_ownership();
+        assert_eq!(manager.get_owner(), &account("new-owner.near"));
+        assert!(manager.get_pending_owner().is_none());
+        manager.set_whitelisted_dispute_caller(account("alice.near"), true);
+    }
+
+    #[test]
+    #[should_panic(expected = "Only the pending owner can accept ownership")]
+    fn test_accept_ownership_rejects_other_accounts() {
+        let mut manager = setup();
+        manager.propose_owner(account("new-owner.near"));
+
+        set_predecessor("mallory.near");
+        manager.accept_ownership();
+    }
+}
@@ -5,6 +5,7 @@
 
 use near_sdk::{env, near, require, AccountId, PanicOnDefault};
 use oracle_types::interfaces::AssertionPolicy;
+use oracle_types::ownership::PendingOwner;
 use oracle_types::types::Bytes32;
 use std::collections::HashSet;
 
@@ -19,6 +20,8 @@ pub struct WhitelistDisputerEscalationManager {
     oracle: AccountId,
     /// The owner who can manage the whitelist.
     owner: AccountId,
+    /// Account proposed as the next owner, pending its `accept_ownership`.
+    pending_owner: PendingOwner,
     /// Accounts that are allowed to dispute.
     whitelisted_dispute_callers: HashSet<AccountId>,
 }
@@ -35,6 +38,7 @@ impl WhitelistDisputerEscalationManager {
         Self {
             oracle,
             owner: env::predecessor_account_id(),
+            pending_owner: PendingOwner::default(),
             whitelisted_dispute_callers: HashSet::new(),
         }
     }
@@ -61,10 +65,16 @@ impl WhitelistDisputerEscalationManager {
         }
     }
 
-    /// Transfer ownership to a new account.
-    pub fn set_owner(&mut self, new_owner: AccountId) {
+    /// Propose a new owner, who takes over once it calls `accept_ownership`.
+    pub fn propose_owner(&mut self, new_owner: AccountId) {
         self.assert_only_owner();
-        self.owner = new_owner;
+        self.pending_owner.propose(new_owner);
+    }
+
+    /// Accept a pending ownership proposal. Only the proposed owner can call this.
+    pub fn accept_ownership(&mut self) {
+        self.pending_owner
+            .accept(&mut self.owner, "whitelist_disputer_escalation_manager");
     }
 
     // ========== Escalation Manager Interface ==========
@@ -124,6 +134,11 @@ impl WhitelistDisputerEscalationManager {
         &self.owner
     }
 
+    /// Get the account proposed as the next owner, if any.
+    pub fn get_pending_owner(&self) -> Option<AccountId> {
+        self.pending_owner.get()
+    }
+
     /// Check if an account is whitelisted.
     pub fn is_whitelisted(&self, account: AccountId) -> bool {
         self.whitelisted_dispute_callers.contains(&account)
@@ -145,3 +160,49 @@ impl WhitelistDisputerEscalationManager {
         );
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use near_sdk::test_utils::VMContextBuilder;
+    use near_sdk::testing_env;
+
+    fn account(id: &str) -> AccountId {
+        id.parse().unwrap()
+    }
+
+    fn set_predecessor(predecessor: &str) {
+        testing_env!(VMContextBuilder::new()
+            .predecessor_account_id(account(predecessor))
+            .build());
+    }
+
+    fn setup() -> WhitelistDisputerEscalationManager {
+        set_predecessor("owner.near");
+        WhitelistDisputerEscalationManager::new(account("oracle.near"))
+    }
+
+    #[test]
+    fn test_two_step_ownership_transfer() {
+        let mut manager = setup();
+        manager.propose_owner(account("new-owner.near"));
+        assert_eq!(manager.get_owner(), &account("owner.near"));
+        assert_eq!(manager.get_pending_owner(), Some(account("new-owner.near")));
+
+        set_predecessor("new-owner.near");
+        manager.accept_ownership();
+        assert_eq!(manager.get_owner(), &account("new-owner.near"));
+        manager.set_dispute_caller_in_whitelist(account("alice.near"), true);
+        assert!(manager.is_whitelisted(account("alice.near")));
+    }
+
+    #[test]
+    #[should_panic(expected = "Only the pending owner can accept ownership")]
+    fn test_accept_ownership_rejects_other_accounts() {
+        let mut manager = setup();
+        manager.propose_owner(account("new-owner.near"));
+
+        set_predecessor("mallory.near");
+        manager.accept_ownership();
+    }
+}
@@ -162,6 +162,11 @@ impl AssertionExample {
     // View Methods
     // ========================================================================
 
+    /// Answers the registry's verification probe when it registers this contract
+    pub fn is_oracle_integrator(&self) -> bool {
+        true
+    }
+
     pub fn get_oracle(&self) -> AccountId {
         self.oracle.clone()
     }
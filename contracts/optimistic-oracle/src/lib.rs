@@ -3,13 +3,16 @@ use near_sdk::{
     json_types::{U128, U64},
     near, require,
     serde::{Deserialize, Serialize},
-    store::LookupMap,
-    AccountId, CryptoHash, Gas, NearToken, PanicOnDefault, Promise, PromiseError,
+    store::{IterableSet, LookupMap, LookupSet},
+    AccountId, CryptoHash, Gas, NearToken, PanicOnDefault, Promise, PromiseError, PromiseOrValue,
+    PromiseResult,
 };
 
 /// Gas for cross-contract calls
 const GAS_FOR_FT_TRANSFER: Gas = Gas::from_tgas(10);
 const GAS_FOR_CALLBACK: Gas = Gas::from_tgas(5);
+/// Gas for `on_resolved_callback_complete`, which records callback delivery.
+const GAS_FOR_RESOLVED_CALLBACK_COMPLETE: Gas = Gas::from_tgas(5);
 const GAS_FOR_DVM_REQUEST: Gas = Gas::from_tgas(30);
 const GAS_FOR_DVM_CALLBACK: Gas = Gas::from_tgas(50);
 const GAS_FOR_DVM_GET_PRICE: Gas = Gas::from_tgas(10);
@@ -17,11 +20,31 @@ const GAS_FOR_DVM_GET_PRICE: Gas = Gas::from_tgas(10);
 const GAS_FOR_DVM_PRICE_CALLBACK: Gas = Gas::from_tgas(180);
 /// Gas for `on_settlement_payout_complete`, invoked after payout ft_transfer call.
 const GAS_FOR_SETTLEMENT_PAYOUT_CALLBACK: Gas = Gas::from_tgas(80);
+/// Gas for the NEP-145 `storage_deposit` registering a payout recipient.
+const GAS_FOR_STORAGE_DEPOSIT: Gas = Gas::from_tgas(5);
+/// Upper bound for the per-recipient storage deposit attached to payouts: 0.1 NEAR
+const MAX_PAYOUT_STORAGE_DEPOSIT: NearToken = NearToken::from_millinear(100);
+/// Upper bound for owner-configured DVM gas overrides.
+const MAX_DVM_GAS: Gas = Gas::from_tgas(250);
+/// Gas for the registry `is_contract_registered` check on assertion callers.
+const GAS_FOR_REGISTRY_CHECK: Gas = Gas::from_tgas(5);
+/// Gas for `on_registry_checked`, which creates the assertion.
+const GAS_FOR_REGISTRY_CALLBACK: Gas = Gas::from_tgas(30);
+/// Gas for the escalation manager `request_price` call on disputes it arbitrates.
+const GAS_FOR_ESCALATION_MANAGER_REQUEST: Gas = Gas::from_tgas(10);
+/// Gas for the escalation manager `get_price` call settling a dispute it arbitrates;
+/// covers an aggregator manager fanning out to its members.
+const GAS_FOR_ESCALATION_MANAGER_GET_PRICE: Gas = Gas::from_tgas(75);
 
 use oracle_types::{
-    events::Event,
-    interfaces::{Assertion, EscalationManagerSettings, WhitelistedCurrency},
-    types::Bytes32,
+    events::{Event, EVENT_STANDARD},
+    interfaces::{
+        AdminAction, AssertEligibility, Assertion, AssertionTimeline, DomainConfig,
+        EscalationManagerSettings, OracleConfig, PrunedAssertion, ResolutionSource,
+        WhitelistedCurrency,
+    },
+    ownership::PendingOwner,
+    types::{dvm_request_id, Bytes32},
 };
 
 // ============================================================================
@@ -38,12 +61,137 @@ const DEFAULT_LIVENESS_NS: u64 = 2 * 60 * 60 * 1_000_000_000;
 /// Burned bond percentage: 50% represented as 0.5e18 (same as UMA)
 const BURNED_BOND_PERCENTAGE: u128 = 500_000_000_000_000_000; // 0.5e18
 
+/// Default time a DVM request must stay unresolved before the owner may override it: 7 days
+const DEFAULT_ADMIN_OVERRIDE_STALE_NS: u64 = 7 * 24 * 60 * 60 * 1_000_000_000;
+
+/// Shortest configurable wait before an admin override: 1 day (in nanoseconds)
+const MIN_ADMIN_OVERRIDE_STALE_NS: u64 = 24 * 60 * 60 * 1_000_000_000;
+
+/// Maximum number of disputers that may pool bonds against a single assertion.
+/// Bounded by `GAS_FOR_DVM_PRICE_CALLBACK`, which must fit one registered payout per
+/// disputer, the two fee transfers and the payout callback.
+const MAX_DISPUTERS: usize = 4;
+
+/// Maximum length in bytes of a dispute evidence URI
+const MAX_EVIDENCE_URI_LEN: usize = 256;
+
+/// Maximum length in bytes of an assertion metadata URI
+const MAX_METADATA_URI_LEN: usize = 256;
+
+/// Default cap on expiration extensions per assertion; the dispute grace window extends
+/// at most once
+const DEFAULT_MAX_ASSERTION_EXTENSIONS: u32 = 1;
+
+/// Maximum settlement retries dispatched by one `retry_all_settlements` call, bounded by gas
+const MAX_SETTLEMENT_RETRY_BATCH: u64 = 3;
+
+/// Maximum number of currencies whitelisted by one `whitelist_currencies` call
+const MAX_CURRENCY_WHITELIST_BATCH: usize = 20;
+
+/// Number of most recent owner actions kept in the admin action log
+const MAX_ADMIN_ACTIONS: u64 = 256;
+
 /// Numerical representation of "true" for oracle responses
 const NUMERICAL_TRUE: i128 = 1_000_000_000_000_000_000; // 1e18
 
-/// 1e18 for percentage calculations
+/// 1e18 for percentage calculations. `burned_bond_percentage` is a fraction scaled by this,
+/// independent of any token's decimals.
 const SCALE: u128 = 1_000_000_000_000_000_000;
 
+/// Basis points denominator (100% = 10000 basis points)
+const BASIS_POINTS_DENOMINATOR: u128 = 10_000;
+
+/// Upper bound for the dispute bond multiplier: 10x the assertion bond
+const MAX_DISPUTE_BOND_MULTIPLIER_BPS: u32 = 100_000;
+
+// ============================================================================
+// Errors
+// ============================================================================
+
+/// Panic messages for assertion and dispute validation.
+/// Each message starts with a fixed category text that off-chain callers can match on,
+/// followed by the values that caused the failure.
+#[derive(Debug)]
+pub enum OracleError {
+    AssertionAlreadyExists {
+        assertion_id: Bytes32,
+    },
+    UnsupportedIdentifier {
+        identifier: Bytes32,
+    },
+    UnsupportedCurrency {
+        currency: AccountId,
+    },
+    BondTooLow {
+        required: u128,
+        provided: u128,
+    },
+    AssertionExpired {
+        assertion_id: Bytes32,
+        expiration_time_ns: u64,
+    },
+    WrongDisputeCurrency {
+        expected: AccountId,
+        provided: AccountId,
+    },
+    DisputeBondMismatch {
+        required: u128,
+        provided: u128,
+    },
+    InsufficientStorageBalance {
+        account_id: AccountId,
+        required: u128,
+        available: u128,
+    },
+}
+
+impl std::fmt::Display for OracleError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::AssertionAlreadyExists { assertion_id } => {
+                write!(f, "Assertion already exists: {}", hex::encode(assertion_id))
+            }
+            Self::UnsupportedIdentifier { identifier } => {
+                write!(f, "Unsupported identifier: {}", hex::encode(identifier))
+            }
+            Self::UnsupportedCurrency { currency } => {
+                write!(f, "Unsupported currency: {}", currency)
+            }
+            Self::BondTooLow { required, provided } => {
+                write!(f, "Bond amount too low: need {} got {}", required, provided)
+            }
+            Self::AssertionExpired {
+                assertion_id,
+                expiration_time_ns,
+            } => write!(
+                f,
+                "Assertion is expired: {} expired at {}",
+                hex::encode(assertion_id),
+                expiration_time_ns
+            ),
+            Self::WrongDisputeCurrency { expected, provided } => write!(
+                f,
+                "Wrong currency for dispute: need {} got {}",
+                expected, provided
+            ),
+            Self::DisputeBondMismatch { required, provided } => write!(
+                f,
+                "Dispute bond must match required dispute bond: need {} got {}",
+                required, provided
+            ),
+            Self::InsufficientStorageBalance {
+                account_id,
+                required,
+                available,
+            } => write!(
+                f,
+                "Insufficient assertion storage balance for {}: need {} got {}",
+                account_id, required, available
+            ),
+        }
+    }
+}
+
 // ============================================================================
 // NEP-141 ft_on_transfer Message Types
 // ============================================================================
@@ -61,6 +209,15 @@ pub struct AssertTruthArgs {
     pub callback_recipient: Option<AccountId>,
     /// Optional escalation manager address
     pub escalation_manager: Option<AccountId>,
+    /// Resolve disputes through the escalation manager instead of the DVM
+    #[serde(default)]
+    pub arbitrate_via_escalation_manager: bool,
+    /// Ignore the oracle resolution when notifying the callback recipient
+    #[serde(default)]
+    pub discard_oracle: bool,
+    /// Require the escalation manager to approve disputers
+    #[serde(default)]
+    pub validate_disputers: bool,
     /// Liveness period in nanoseconds (if None, uses default)
     pub liveness_ns: Option<U64>,
     /// Optional assertion timestamp in nanoseconds used for deterministic assertion IDs.
@@ -73,6 +230,9 @@ pub struct AssertTruthArgs {
     /// Optional deterministic assertion id supplied by an upstream integrator.
     /// If provided, the oracle uses it directly instead of recomputing from inputs.
     pub assertion_id_override: Option<Bytes32>,
+    /// Optional pointer (e.g. IPFS CID) to structured context for the claim
+    #[serde(default)]
+    pub metadata_uri: Option<String>,
 }
 
 /// Message types for ft_on_transfer
@@ -86,6 +246,9 @@ pub enum FtOnTransferMsg {
     DisputeAssertion {
         assertion_id: Bytes32,
         disputer: AccountId,
+        /// Optional pointer (e.g. IPFS CID) to the disputer's rationale for DVM voters
+        #[serde(default)]
+        evidence_uri: Option<String>,
     },
 }
 
@@ -99,6 +262,9 @@ pub struct NestOptimisticOracle {
     /// Contract owner (equivalent to Ownable in Solidity)
     owner: AccountId,
 
+    /// Account proposed as the next owner, pending its `accept_ownership`
+    pending_owner: PendingOwner,
+
     /// Default currency for assertions (NEP-141 token account ID)
     default_currency: AccountId,
 
@@ -126,6 +292,128 @@ pub struct NestOptimisticOracle {
 
     /// Reverse mapping from DVM request_id to assertion_id
     request_to_assertion: LookupMap<CryptoHash, Bytes32>,
+
+    /// Timestamp at which each disputed assertion's DVM request was created
+    dispute_escalation_times: LookupMap<Bytes32, u64>,
+
+    /// Time a DVM request must remain unresolved before the owner may override it
+    admin_override_stale_ns: u64,
+
+    /// Window before expiration in which a dispute extends the effective expiration
+    /// by the same amount; 0 disables the extension
+    dispute_grace_ns: u64,
+
+    /// Extended expiration for assertions disputed within the grace window
+    extended_expirations: LookupMap<Bytes32, u64>,
+
+    /// Storage bytes written when each assertion was created
+    assertion_storage_bytes: LookupMap<Bytes32, u64>,
+
+    /// Portion of the disputed-settlement oracle fee that is burned (bps)
+    fee_burn_bps: u16,
+
+    /// Sink account receiving the burned share of the oracle fee
+    /// NEP-141 tokens have no standard burn, so the burn share is sent here instead
+    burn_sink: Option<AccountId>,
+
+    /// Recipient payouts still outstanding for assertions with pending settlement
+    pending_payouts: LookupMap<Bytes32, Vec<(AccountId, U128)>>,
+
+    /// Assertions whose dispute was resolved manually by the owner
+    admin_resolved_assertions: LookupSet<Bytes32>,
+
+    /// Assertions with a failed settlement payout awaiting retry (pending and not in-flight)
+    retryable_settlements: IterableSet<Bytes32>,
+
+    /// Outstanding bond liability per currency (posted bonds not yet paid out)
+    total_bonded: LookupMap<AccountId, u128>,
+
+    /// Storage deposit used to register payout recipients with the bond token before
+    /// transferring to them; `None` disables registration
+    payout_storage_deposit: Option<NearToken>,
+
+    /// Gas attached to the DVM `request_price` call
+    gas_for_dvm_request: Gas,
+
+    /// Gas attached to the DVM `get_price` call
+    gas_for_dvm_get_price: Gas,
+
+    /// Gas attached to `on_dvm_price_received`, which settles the assertion
+    gas_for_dvm_price_callback: Gas,
+
+    /// Dispute bond required per dispute, as a multiple of the assertion bond (bps)
+    dispute_bond_multiplier_bps: u32,
+
+    /// Registry that assertion callers must be registered with; `None` keeps asserting
+    /// permissionless
+    registry: Option<AccountId>,
+
+    /// Per-domain defaults overriding the global currency and liveness
+    domain_configs: LookupMap<Bytes32, DomainConfig>,
+
+    /// Time after DVM escalation past which an unresolved dispute settles with
+    /// `dvm_fallback_resolution`
+    dvm_resolution_deadline_ns: u64,
+
+    /// Resolution applied once the DVM deadline passes without a price; `None` always
+    /// waits for the DVM
+    dvm_fallback_resolution: Option<bool>,
+
+    /// Per-identifier minimum DVM price at which the asserter wins, overriding NUMERICAL_TRUE
+    resolution_thresholds: LookupMap<Bytes32, i128>,
+
+    /// Whether assertions must use a whitelisted identifier; disable only on test deployments
+    require_identifier_whitelist: bool,
+
+    /// Per-currency bond floor enforced on top of the fee-derived minimum
+    absolute_min_bonds: LookupMap<AccountId, u128>,
+
+    /// Per-identifier bond floor for riskier query types, enforced on top of the
+    /// currency's minimum bond
+    identifier_min_bonds: LookupMap<Bytes32, u128>,
+
+    /// Minimum time between two disputes by the same disputer; 0 disables the cooldown
+    dispute_cooldown_ns: u64,
+
+    /// Time of each disputer's latest dispute, recorded while a cooldown is set
+    last_dispute_times: LookupMap<AccountId, u64>,
+
+    /// Pointer to structured off-chain context for assertions created with one
+    metadata_uris: LookupMap<Bytes32, String>,
+
+    /// Maximum number of times an assertion's expiration may be extended
+    max_assertion_extensions: u32,
+
+    /// Share of every posted bond taken as a protocol fee when the assertion is created (bps)
+    assertion_creation_fee_bps: u16,
+
+    /// Bumped whenever a change may move a currency's minimum bond (final fee, burned bond
+    /// percentage or bond floor), so integrators can detect stale quotes
+    currency_params_version: u64,
+
+    /// Time a first dispute must wait before `escalate_dispute` sends it to the DVM,
+    /// leaving room to negotiate; 0 escalates immediately
+    escalation_delay_ns: u64,
+
+    /// Dispute time of first disputes awaiting `escalate_dispute`
+    pending_escalations: LookupMap<Bytes32, u64>,
+
+    /// Ring buffer of the last `MAX_ADMIN_ACTIONS` owner-only calls, keyed by
+    /// `index % MAX_ADMIN_ACTIONS`
+    admin_action_log: LookupMap<u64, AdminAction>,
+
+    /// Total number of owner-only calls ever recorded
+    admin_action_count: u64,
+
+    /// Result of each pruned assertion, kept so the id keeps answering and cannot be
+    /// asserted again
+    pruned_assertions: LookupMap<Bytes32, PrunedAssertion>,
+
+    /// Prepaid NEAR per account, drawn on to cover the storage of the assertions it creates
+    assertion_storage_balances: LookupMap<AccountId, NearToken>,
+
+    /// Storage deposit charged for each assertion, refunded to its asserting caller on pruning
+    assertion_storage_deposits: LookupMap<Bytes32, NearToken>,
 }
 
 // ============================================================================
@@ -156,6 +444,7 @@ impl NestOptimisticOracle {
 
         let mut contract = Self {
             owner,
+            pending_owner: PendingOwner::default(),
             default_currency: default_currency.clone(),
             default_liveness_ns: liveness,
             burned_bond_percentage: burn_pct,
@@ -165,6 +454,43 @@ impl NestOptimisticOracle {
             voting_contract,
             dispute_requests: LookupMap::new(b"d"),
             request_to_assertion: LookupMap::new(b"r"),
+            dispute_escalation_times: LookupMap::new(b"e"),
+            admin_override_stale_ns: DEFAULT_ADMIN_OVERRIDE_STALE_NS,
+            dispute_grace_ns: 0,
+            extended_expirations: LookupMap::new(b"g"),
+            assertion_storage_bytes: LookupMap::new(b"b"),
+            fee_burn_bps: 0,
+            burn_sink: None,
+            pending_payouts: LookupMap::new(b"p"),
+            admin_resolved_assertions: LookupSet::new(b"o"),
+            retryable_settlements: IterableSet::new(b"q"),
+            total_bonded: LookupMap::new(b"t"),
+            payout_storage_deposit: None,
+            gas_for_dvm_request: GAS_FOR_DVM_REQUEST,
+            gas_for_dvm_get_price: GAS_FOR_DVM_GET_PRICE,
+            gas_for_dvm_price_callback: GAS_FOR_DVM_PRICE_CALLBACK,
+            dispute_bond_multiplier_bps: BASIS_POINTS_DENOMINATOR as u32,
+            registry: None,
+            domain_configs: LookupMap::new(b"m"),
+            dvm_resolution_deadline_ns: 0,
+            dvm_fallback_resolution: None,
+            resolution_thresholds: LookupMap::new(b"h"),
+            require_identifier_whitelist: true,
+            absolute_min_bonds: LookupMap::new(b"n"),
+            identifier_min_bonds: LookupMap::new(b"j"),
+            dispute_cooldown_ns: 0,
+            last_dispute_times: LookupMap::new(b"l"),
+            metadata_uris: LookupMap::new(b"u"),
+            max_assertion_extensions: DEFAULT_MAX_ASSERTION_EXTENSIONS,
+            assertion_creation_fee_bps: 0,
+            currency_params_version: 0,
+            escalation_delay_ns: 0,
+            pending_escalations: LookupMap::new(b"s"),
+            admin_action_log: LookupMap::new(b"k"),
+            admin_action_count: 0,
+            pruned_assertions: LookupMap::new(b"f"),
+            assertion_storage_balances: LookupMap::new(b"x"),
+            assertion_storage_deposits: LookupMap::new(b"y"),
         };
 
         // Cache the default identifier as approved
@@ -175,6 +501,7 @@ impl NestOptimisticOracle {
             default_currency: &default_currency,
             default_liveness_ns: liveness,
             burned_bond_percentage: burn_pct,
+            currency_params_version: 0,
         }
         .emit();
 
@@ -200,28 +527,109 @@ impl NestOptimisticOracle {
         U64(self.default_liveness_ns)
     }
 
+    /// Returns the default currency for a domain, falling back to the global default
+    pub fn domain_default_currency(&self, domain_id: Bytes32) -> AccountId {
+        self.domain_configs
+            .get(&domain_id)
+            .map(|config| config.default_currency.clone())
+            .unwrap_or_else(|| self.default_currency.clone())
+    }
+
+    /// Returns the default liveness for a domain in nanoseconds, falling back to the global default
+    pub fn domain_default_liveness(&self, domain_id: Bytes32) -> U64 {
+        U64(self.resolve_default_liveness(&domain_id))
+    }
+
+    /// Returns the defaults configured for a domain, if any
+    pub fn get_domain_config(&self, domain_id: Bytes32) -> Option<DomainConfig> {
+        self.domain_configs.get(&domain_id).cloned()
+    }
+
     /// Fetches information about a specific assertion
     pub fn get_assertion(&self, assertion_id: Bytes32) -> Option<Assertion> {
         self.assertions.get(&assertion_id).cloned()
     }
 
+    /// Returns the metadata URI an assertion was created with, if any
+    pub fn get_assertion_metadata_uri(&self, assertion_id: Bytes32) -> Option<String> {
+        self.metadata_uris.get(&assertion_id).cloned()
+    }
+
     /// Returns the minimum bond amount required to make an assertion
-    /// min_bond = final_fee * 1e18 / burned_bond_percentage
+    /// min_bond = max(ceil(final_fee * 1e18 / burned_bond_percentage), absolute_min_bond),
+    /// using the currency's own burned bond percentage if it has one
+    ///
+    /// `final_fee` and the result are both in the currency's own base units; the 1e18 factors
+    /// cancel, so the bond is correct for any token decimals (1 USDC fee at 50% -> 2_000_000).
+    /// Rounding up keeps the burned share of a minimum bond at or above `final_fee`.
     pub fn get_minimum_bond(&self, currency: AccountId) -> U128 {
-        match self.cached_currencies.get(&currency) {
-            Some(cached) if cached.is_whitelisted => {
-                let final_fee = cached.final_fee.0;
-                let min_bond = final_fee
-                    .saturating_mul(SCALE)
-                    .saturating_div(self.burned_bond_percentage);
-                U128(min_bond)
-            }
-            _ => U128(0),
-        }
+        let fee_min_bond = match self.cached_currencies.get(&currency) {
+            Some(cached) if cached.is_whitelisted => cached
+                .final_fee
+                .0
+                .saturating_mul(SCALE)
+                .div_ceil(self.get_burned_bond_percentage(currency.clone()).0),
+            _ => 0,
+        };
+        U128(fee_min_bond.max(self.get_absolute_min_bond(currency).0))
+    }
+
+    /// Returns the burned bond percentage applied to bonds in a currency: its override if
+    /// set, else the oracle-wide value
+    pub fn get_burned_bond_percentage(&self, currency: AccountId) -> U128 {
+        U128(
+            self.cached_currencies
+                .get(&currency)
+                .and_then(|c| c.burned_bond_percentage)
+                .map_or(self.burned_bond_percentage, |pct| pct.0),
+        )
+    }
+
+    /// Returns a currency's (is_whitelisted, final_fee, params version). The version changes
+    /// whenever any currency's minimum bond may have moved; re-quote `get_minimum_bond` if it
+    /// differs from the one seen when quoting.
+    pub fn get_currency_params(&self, currency: AccountId) -> (bool, U128, U64) {
+        let (is_whitelisted, final_fee) = self
+            .cached_currencies
+            .get(&currency)
+            .map_or((false, U128(0)), |c| (c.is_whitelisted, c.final_fee));
+        (is_whitelisted, final_fee, U64(self.currency_params_version))
+    }
+
+    /// Returns the owner-set bond floor for a currency, enforced regardless of its final fee
+    pub fn get_absolute_min_bond(&self, currency: AccountId) -> U128 {
+        U128(self.absolute_min_bonds.get(&currency).copied().unwrap_or(0))
+    }
+
+    /// Returns the owner-set bond floor for an identifier (0 if none)
+    pub fn get_identifier_min_bond(&self, identifier: Bytes32) -> U128 {
+        U128(
+            self.identifier_min_bonds
+                .get(&identifier)
+                .copied()
+                .unwrap_or(0),
+        )
+    }
+
+    /// Returns the minimum bond for an assertion on `identifier` in `currency`: the larger of
+    /// `get_minimum_bond(currency)` and `get_identifier_min_bond(identifier)`
+    pub fn get_minimum_bond_for_identifier(
+        &self,
+        currency: AccountId,
+        identifier: Bytes32,
+    ) -> U128 {
+        U128(
+            self.get_minimum_bond(currency)
+                .0
+                .max(self.get_identifier_min_bond(identifier).0),
+        )
     }
 
     /// Fetches the resolution of a specific assertion
     pub fn get_assertion_result(&self, assertion_id: Bytes32) -> bool {
+        if let Some(pruned) = self.pruned_assertions.get(&assertion_id) {
+            return pruned.result;
+        }
         let assertion = self
             .assertions
             .get(&assertion_id)
@@ -236,6 +644,68 @@ impl NestOptimisticOracle {
         assertion.settlement_resolution
     }
 
+    /// Fetches the raw resolved price of a settled assertion on a numerical identifier.
+    /// Returns None for binary identifiers and for assertions not settled by a DVM or
+    /// escalation manager price;
+    /// use `get_assertion_result` for those.
+    pub fn get_assertion_numeric_result(&self, assertion_id: Bytes32) -> Option<i128> {
+        if let Some(pruned) = self.pruned_assertions.get(&assertion_id) {
+            return pruned.resolved_price;
+        }
+        let assertion = self
+            .assertions
+            .get(&assertion_id)
+            .expect("Assertion does not exist");
+        if !assertion.settled {
+            return None;
+        }
+        assertion.resolved_price
+    }
+
+    /// Lifecycle timestamps of an assertion: creation, (effective) expiration, first dispute,
+    /// DVM request and settlement.
+    pub fn get_assertion_timeline(&self, assertion_id: Bytes32) -> AssertionTimeline {
+        let assertion = self
+            .assertions
+            .get(&assertion_id)
+            .expect("Assertion does not exist");
+        AssertionTimeline {
+            assertion_time_ns: U64(assertion.assertion_time_ns),
+            expiration_time_ns: self.get_effective_expiration(assertion_id),
+            dispute_time_ns: assertion.dispute_time_ns.map(U64),
+            dvm_request_time_ns: self
+                .dispute_escalation_times
+                .get(&assertion_id)
+                .copied()
+                .map(U64),
+            settlement_time_ns: assertion.settlement_time_ns.map(U64),
+        }
+    }
+
+    /// Returns the bond a disputer must transfer, in the assertion's currency, to dispute it.
+    pub fn get_dispute_cost(&self, assertion_id: Bytes32) -> U128 {
+        let assertion = self
+            .assertions
+            .get(&assertion_id)
+            .expect("Assertion does not exist");
+        U128(Self::required_dispute_bond(
+            assertion.bond.0,
+            self.dispute_bond_multiplier_bps,
+        ))
+    }
+
+    /// Returns whether settlement will notify the assertion's callback recipient.
+    /// False when no recipient is set or `discard_oracle` suppresses the callback, in which
+    /// case integrators should poll `get_assertion_result` instead.
+    pub fn will_notify_callback(&self, assertion_id: Bytes32) -> bool {
+        let assertion = self
+            .assertions
+            .get(&assertion_id)
+            .expect("Assertion does not exist");
+        assertion.callback_recipient.is_some()
+            && !assertion.escalation_manager_settings.discard_oracle
+    }
+
     /// Check if an identifier is cached/approved
     pub fn is_identifier_supported(&self, identifier: Bytes32) -> bool {
         self.cached_identifiers
@@ -252,6 +722,47 @@ impl NestOptimisticOracle {
             .unwrap_or(false)
     }
 
+    /// Pre-flight check for an assertion by `caller` with the given bond currency and
+    /// identifier (default identifier if omitted), mirroring the checks made on transfer.
+    /// The bond amount is not checked; compare it against `get_minimum_bond`.
+    /// When a registry is set, the caller is only confirmed asynchronously at assertion
+    /// time, reported as `PendingRegistryCheck`.
+    pub fn assert_eligibility(
+        &self,
+        caller: AccountId,
+        currency: AccountId,
+        identifier: Option<Bytes32>,
+    ) -> AssertEligibility {
+        let identifier = identifier.unwrap_or(DEFAULT_IDENTIFIER);
+        if self.require_identifier_whitelist && !self.is_identifier_supported(identifier) {
+            return AssertEligibility::UnsupportedIdentifier;
+        }
+        if !self.is_currency_whitelisted(currency) {
+            return AssertEligibility::UnsupportedCurrency;
+        }
+        match self.registry {
+            Some(ref registry) => AssertEligibility::PendingRegistryCheck {
+                registry: registry.clone(),
+                caller,
+            },
+            None => AssertEligibility::Eligible,
+        }
+    }
+
+    /// Returns whether `caller` passes the local assertion checks of `assert_eligibility`.
+    /// A registry check, if configured, still applies when the assertion is made.
+    pub fn can_assert(
+        &self,
+        caller: AccountId,
+        currency: AccountId,
+        identifier: Option<Bytes32>,
+    ) -> bool {
+        matches!(
+            self.assert_eligibility(caller, currency, identifier),
+            AssertEligibility::Eligible | AssertEligibility::PendingRegistryCheck { .. }
+        )
+    }
+
     /// Get the voting contract address
     pub fn get_voting_contract(&self) -> Option<AccountId> {
         self.voting_contract.clone()
@@ -262,6 +773,79 @@ impl NestOptimisticOracle {
         self.dispute_requests.get(&assertion_id).copied()
     }
 
+    /// Preview the DVM request ID a dispute of this assertion escalates to.
+    ///
+    /// The voting contract hashes the dispute time and its own request nonce into the ID,
+    /// so both must be supplied: `dispute_time_ns` is the block time the dispute executes
+    /// at, and `request_nonce` is the voting contract's `get_request_nonce()` when the
+    /// request arrives. Any other DVM request created in between shifts the nonce, so a
+    /// preview is a prediction; after escalation, `get_dispute_request` is authoritative.
+    /// Returns None for unknown assertions and those arbitrated by their escalation manager.
+    pub fn preview_dispute_request_id(
+        &self,
+        assertion_id: Bytes32,
+        dispute_time_ns: U64,
+        request_nonce: U64,
+        evidence_uri: Option<String>,
+    ) -> Option<CryptoHash> {
+        let assertion = self.assertions.get(&assertion_id)?;
+        if assertion
+            .escalation_manager_settings
+            .arbitrate_via_escalation_manager
+        {
+            return None;
+        }
+        Some(dvm_request_id(
+            &Self::dvm_identifier(&assertion.identifier),
+            dispute_time_ns.0,
+            &Self::dispute_ancillary_data(&assertion_id, evidence_uri.as_deref()),
+            request_nonce.0,
+        ))
+    }
+
+    /// Get the assertion that escalated to a given DVM request
+    pub fn get_assertion_for_request(&self, request_id: CryptoHash) -> Option<Bytes32> {
+        self.request_to_assertion.get(&request_id).copied()
+    }
+
+    /// Get the escalation manager settings an assertion was created with, so disputers can
+    /// check e.g. `validate_disputers` before attempting a dispute
+    pub fn get_escalation_settings(&self, assertion_id: Bytes32) -> EscalationManagerSettings {
+        self.assertions
+            .get(&assertion_id)
+            .expect("Assertion does not exist")
+            .escalation_manager_settings
+            .clone()
+    }
+
+    /// Returns which mechanism resolves (or resolved) an assertion
+    pub fn get_resolution_source(&self, assertion_id: Bytes32) -> ResolutionSource {
+        let assertion = self
+            .assertions
+            .get(&assertion_id)
+            .expect("Assertion does not exist");
+
+        if assertion.disputer.is_none() {
+            ResolutionSource::Undisputed
+        } else if self.admin_resolved_assertions.contains(&assertion_id) {
+            ResolutionSource::AdminOverride
+        } else if assertion.escalation_manager_settings.discard_oracle {
+            ResolutionSource::DiscardedOracle
+        } else if assertion
+            .escalation_manager_settings
+            .arbitrate_via_escalation_manager
+        {
+            ResolutionSource::EscalationManager
+        } else if self.dispute_requests.contains_key(&assertion_id)
+            || self.pending_escalations.contains_key(&assertion_id)
+        {
+            ResolutionSource::Dvm
+        } else {
+            // Not escalated to the DVM, so only the owner fallback can resolve it
+            ResolutionSource::AdminOverride
+        }
+    }
+
     /// Check if a disputed assertion has been resolved by DVM
     pub fn is_dispute_resolved(&self, assertion_id: Bytes32) -> bool {
         self.dispute_requests.get(&assertion_id).is_some()
@@ -279,7 +863,7 @@ impl NestOptimisticOracle {
         default_liveness_ns: U64,
         burned_bond_percentage: U128,
     ) {
-        self.assert_owner();
+        self.assert_admin("set_admin_properties");
 
         require!(
             burned_bond_percentage.0 <= SCALE,
@@ -289,12 +873,17 @@ impl NestOptimisticOracle {
 
         self.default_currency = default_currency.clone();
         self.default_liveness_ns = default_liveness_ns.0;
+        if self.burned_bond_percentage != burned_bond_percentage.0 {
+            // Moves the minimum bond of every currency without an override
+            self.currency_params_version += 1;
+        }
         self.burned_bond_percentage = burned_bond_percentage.0;
 
         Event::AdminPropertiesSet {
             default_currency: &default_currency,
             default_liveness_ns: default_liveness_ns.0,
             burned_bond_percentage: burned_bond_percentage.0,
+            currency_params_version: self.currency_params_version,
         }
         .emit();
     }
@@ -302,67 +891,465 @@ impl NestOptimisticOracle {
     /// Whitelist a currency with its final fee (Phase 1 simplified)
     /// In UMA this is done via syncUmaParams, but we simplify for Phase 1
     pub fn whitelist_currency(&mut self, currency: AccountId, final_fee: U128) {
-        self.assert_owner();
+        self.assert_admin("whitelist_currency");
+        self.internal_whitelist_currency(currency, final_fee);
+    }
+
+    /// Whitelist several currencies, each with its final fee, in one call
+    pub fn whitelist_currencies(&mut self, entries: Vec<(AccountId, U128)>) {
+        self.assert_admin("whitelist_currencies");
+        require!(
+            entries.len() <= MAX_CURRENCY_WHITELIST_BATCH,
+            "Too many currencies in batch"
+        );
+        for (currency, final_fee) in entries {
+            self.internal_whitelist_currency(currency, final_fee);
+        }
+    }
+
+    /// Whitelists (or updates the final fee of) a currency, keeping its burned bond
+    /// percentage override if it has one
+    fn internal_whitelist_currency(&mut self, currency: AccountId, final_fee: U128) {
+        let burned_bond_percentage = self
+            .cached_currencies
+            .get(&currency)
+            .and_then(|c| c.burned_bond_percentage);
         self.cached_currencies.insert(
-            currency,
+            currency.clone(),
             WhitelistedCurrency {
                 is_whitelisted: true,
                 final_fee,
+                burned_bond_percentage,
             },
         );
+        Event::CurrencyWhitelisted {
+            currency: &currency,
+            final_fee: final_fee.0,
+        }
+        .emit();
+        self.bump_currency_params_version(&currency);
+    }
+
+    /// Override the burned bond percentage for a whitelisted currency, e.g. a lower
+    /// burn for stablecoins. None reverts to the oracle-wide value.
+    pub fn set_currency_burned_bond_percentage(
+        &mut self,
+        currency: AccountId,
+        burned_bond_percentage: Option<U128>,
+    ) {
+        self.assert_admin("set_currency_burned_bond_percentage");
+        if let Some(pct) = burned_bond_percentage {
+            require!(pct.0 <= SCALE, "Burned bond percentage > 100%");
+            require!(pct.0 > 0, "Burned bond percentage is 0");
+        }
+        let cached = self
+            .cached_currencies
+            .get_mut(&currency)
+            .unwrap_or_else(|| env::panic_str("Currency not whitelisted"));
+        cached.burned_bond_percentage = burned_bond_percentage;
+        self.bump_currency_params_version(&currency);
+    }
+
+    /// Set a bond floor for a currency that applies even if its final fee is zero or
+    /// misconfigured. Zero removes the floor.
+    pub fn set_absolute_min_bond(&mut self, currency: AccountId, amount: U128) {
+        self.assert_admin("set_absolute_min_bond");
+        if amount.0 == 0 {
+            self.absolute_min_bonds.remove(&currency);
+        } else {
+            self.absolute_min_bonds.insert(currency.clone(), amount.0);
+        }
+        self.bump_currency_params_version(&currency);
+    }
+
+    /// Set a bond floor for assertions on an identifier, in the bond currency's base units,
+    /// applied on top of the currency's minimum bond. Zero removes the floor.
+    pub fn set_identifier_min_bond(&mut self, identifier: Bytes32, amount: U128) {
+        self.assert_admin("set_identifier_min_bond");
+        if amount.0 == 0 {
+            self.identifier_min_bonds.remove(&identifier);
+        } else {
+            self.identifier_min_bonds.insert(identifier, amount.0);
+        }
+        Event::IdentifierMinBondSet {
+            identifier: &identifier,
+            minimum_bond: &amount,
+        }
+        .emit();
+    }
+
+    /// Records a change to a currency's bond parameters and announces its new minimum bond
+    fn bump_currency_params_version(&mut self, currency: &AccountId) {
+        self.currency_params_version += 1;
+        Event::CurrencyParamsUpdated {
+            currency,
+            minimum_bond: &self.get_minimum_bond(currency.clone()),
+            version: self.currency_params_version,
+        }
+        .emit();
     }
 
     /// Approve an identifier for use
     pub fn whitelist_identifier(&mut self, identifier: Bytes32) {
-        self.assert_owner();
+        self.assert_admin("whitelist_identifier");
         self.cached_identifiers.insert(identifier, true);
     }
 
+    /// Set whether assertions must use a whitelisted identifier. Disabling accepts any
+    /// identifier, which is meant for testnet experimentation only.
+    pub fn set_require_identifier_whitelist(&mut self, required: bool) {
+        self.assert_admin("set_require_identifier_whitelist");
+        self.require_identifier_whitelist = required;
+        Event::IdentifierWhitelistRequirementSet { required }.emit();
+    }
+
+    /// Whether assertions must use a whitelisted identifier
+    pub fn get_require_identifier_whitelist(&self) -> bool {
+        self.require_identifier_whitelist
+    }
+
+    /// Set the default currency and liveness for assertions in a domain
+    pub fn set_domain_config(&mut self, domain_id: Bytes32, config: DomainConfig) {
+        self.assert_admin("set_domain_config");
+        self.domain_configs.insert(domain_id, config);
+    }
+
+    /// Remove a domain's defaults so its assertions use the global defaults again
+    pub fn remove_domain_config(&mut self, domain_id: Bytes32) {
+        self.assert_admin("remove_domain_config");
+        self.domain_configs.remove(&domain_id);
+    }
+
     /// Set the DVM voting contract address
     pub fn set_voting_contract(&mut self, voting_contract: AccountId) {
-        self.assert_owner();
+        self.assert_admin("set_voting_contract");
         self.voting_contract = Some(voting_contract);
     }
 
-    /// Transfer oracle ownership to a new account.
-    pub fn set_owner(&mut self, new_owner: AccountId) {
-        self.assert_owner();
-        self.owner = new_owner;
+    /// Set the registry that assertion callers must be registered with.
+    /// `None` disables the check so anyone may assert.
+    pub fn set_registry(&mut self, registry: Option<AccountId>) {
+        self.assert_admin("set_registry");
+        self.registry = registry;
     }
 
-    /// Get current oracle owner.
-    pub fn get_owner(&self) -> AccountId {
-        self.owner.clone()
+    /// Get the registry gating assertion callers, if any
+    pub fn get_registry(&self) -> Option<AccountId> {
+        self.registry.clone()
     }
 
-    /// Emergency token withdrawal for stuck funds recovery.
-    /// Owner-only: can move bonded funds, so use only for controlled recovery.
-    pub fn emergency_withdraw_token(
-        &mut self,
-        token: AccountId,
-        receiver_id: AccountId,
-        amount: U128,
-    ) -> Promise {
-        self.assert_owner();
-        require!(amount.0 > 0, "Amount must be positive");
+    /// Set how long a DVM request must remain unresolved before the owner may override it
+    /// (at least 1 day)
+    pub fn set_admin_override_stale_period(&mut self, stale_ns: U64) {
+        self.assert_admin("set_admin_override_stale_period");
+        require!(
+            stale_ns.0 >= MIN_ADMIN_OVERRIDE_STALE_NS,
+            "Stale period below minimum"
+        );
+        self.admin_override_stale_ns = stale_ns.0;
+    }
 
-        Promise::new(token).function_call(
-            "ft_transfer".to_string(),
-            near_sdk::serde_json::json!({
-                "receiver_id": receiver_id,
-                "amount": amount,
-            })
-            .to_string()
-            .into_bytes(),
-            NearToken::from_yoctonear(1),
-            GAS_FOR_FT_TRANSFER,
-        )
+    /// Get how long a DVM request must remain unresolved before the owner may override it
+    pub fn get_admin_override_stale_period(&self) -> U64 {
+        U64(self.admin_override_stale_ns)
+    }
+
+    /// Configure the fallback for disputes the DVM never resolves. Once `deadline_ns` has
+    /// passed since escalation without a DVM price, settlement applies `resolution`
+    /// (true = asserter wins). `None` disables the fallback.
+    pub fn set_dvm_fallback(&mut self, deadline_ns: U64, resolution: Option<bool>) {
+        self.assert_admin("set_dvm_fallback");
+        self.dvm_resolution_deadline_ns = deadline_ns.0;
+        self.dvm_fallback_resolution = resolution;
+    }
+
+    /// Set the minimum DVM price at which the asserter wins for an identifier
+    pub fn set_resolution_threshold(&mut self, identifier: Bytes32, threshold: i128) {
+        self.assert_admin("set_resolution_threshold");
+        require!(threshold > 0, "Threshold must be positive");
+        self.resolution_thresholds.insert(identifier, threshold);
+        Event::ResolutionThresholdSet {
+            identifier: &identifier,
+            threshold: &threshold.to_string(),
+        }
+        .emit();
+    }
+
+    /// Get the minimum DVM price at which the asserter wins for an identifier
+    /// (NUMERICAL_TRUE unless configured)
+    pub fn get_resolution_threshold(&self, identifier: Bytes32) -> i128 {
+        self.resolution_thresholds
+            .get(&identifier)
+            .copied()
+            .unwrap_or(NUMERICAL_TRUE)
+    }
+
+    /// Get the DVM fallback config: (deadline_ns, resolution)
+    pub fn get_dvm_fallback(&self) -> (U64, Option<bool>) {
+        (
+            U64(self.dvm_resolution_deadline_ns),
+            self.dvm_fallback_resolution,
+        )
+    }
+
+    /// Set the dispute grace window. A dispute landing within `grace_ns` of expiration
+    /// pushes the effective expiration out by `grace_ns` before the dispute may be resolved.
+    pub fn set_dispute_grace_period(&mut self, grace_ns: U64) {
+        self.assert_admin("set_dispute_grace_period");
+        self.dispute_grace_ns = grace_ns.0;
+    }
+
+    /// Get the dispute grace window
+    pub fn get_dispute_grace_period(&self) -> U64 {
+        U64(self.dispute_grace_ns)
+    }
+
+    /// Set the minimum time a disputer must wait between disputes; 0 disables it.
+    /// A dispute within the cooldown is rejected and its bond refunded.
+    pub fn set_dispute_cooldown(&mut self, cooldown_ns: U64) {
+        self.assert_admin("set_dispute_cooldown");
+        self.dispute_cooldown_ns = cooldown_ns.0;
+    }
+
+    /// Get the minimum time between two disputes by the same disputer
+    pub fn get_dispute_cooldown(&self) -> U64 {
+        U64(self.dispute_cooldown_ns)
+    }
+
+    /// Set how long a first dispute waits before it can be escalated to the DVM with
+    /// `escalate_dispute`; 0 escalates disputes immediately.
+    pub fn set_escalation_delay(&mut self, delay_ns: U64) {
+        self.assert_admin("set_escalation_delay");
+        self.escalation_delay_ns = delay_ns.0;
+    }
+
+    /// Get the delay between a first dispute and its DVM escalation
+    pub fn get_escalation_delay(&self) -> U64 {
+        U64(self.escalation_delay_ns)
+    }
+
+    /// Get the earliest time a deferred dispute can be escalated, or None if the assertion
+    /// has no dispute awaiting escalation
+    pub fn get_pending_escalation(&self, assertion_id: Bytes32) -> Option<U64> {
+        self.pending_escalations
+            .get(&assertion_id)
+            .map(|disputed_at| U64(disputed_at.saturating_add(self.escalation_delay_ns)))
+    }
+
+    /// Set how many times an assertion's expiration may be extended; 0 disables extensions.
+    /// Once an assertion reaches the cap, it settles at its current expiration.
+    pub fn set_max_assertion_extensions(&mut self, max_extensions: u32) {
+        self.assert_admin("set_max_assertion_extensions");
+        self.max_assertion_extensions = max_extensions;
+    }
+
+    /// Get how many times an assertion's expiration may be extended
+    pub fn get_max_assertion_extensions(&self) -> u32 {
+        self.max_assertion_extensions
+    }
+
+    /// Set the share of each posted bond taken as a fee at assertion creation; the
+    /// remaining bond must still meet the currency's minimum. The fee is split like the
+    /// oracle fee between the burn sink and the owner treasury.
+    pub fn set_assertion_creation_fee_bps(&mut self, fee_bps: u16) {
+        self.assert_admin("set_assertion_creation_fee_bps");
+        require!(
+            fee_bps as u128 <= BASIS_POINTS_DENOMINATOR,
+            "BPS cannot exceed 100%"
+        );
+        self.assertion_creation_fee_bps = fee_bps;
+    }
+
+    /// Get the share of each posted bond taken as a fee at assertion creation (bps)
+    pub fn get_assertion_creation_fee_bps(&self) -> u16 {
+        self.assertion_creation_fee_bps
+    }
+
+    /// Get the expiration used when resolving a dispute. Equals the original expiration
+    /// unless the assertion was disputed within the grace window.
+    pub fn get_effective_expiration(&self, assertion_id: Bytes32) -> U64 {
+        let assertion = self
+            .assertions
+            .get(&assertion_id)
+            .expect("Assertion does not exist");
+        U64(self
+            .extended_expirations
+            .get(&assertion_id)
+            .copied()
+            .unwrap_or(assertion.expiration_time_ns))
+    }
+
+    /// Configure the share of the oracle fee that is burned and the sink receiving it.
+    /// The remainder of the fee goes to the owner treasury.
+    pub fn set_fee_burn_config(&mut self, fee_burn_bps: u16, burn_sink: Option<AccountId>) {
+        self.assert_admin("set_fee_burn_config");
+        require!(
+            fee_burn_bps as u128 <= BASIS_POINTS_DENOMINATOR,
+            "BPS cannot exceed 100%"
+        );
+        require!(
+            fee_burn_bps == 0 || burn_sink.is_some(),
+            "Burn sink required when burning fees"
+        );
+        self.fee_burn_bps = fee_burn_bps;
+        self.burn_sink = burn_sink;
+    }
+
+    /// Get the oracle fee burn share (bps) and the burn sink account
+    pub fn get_fee_burn_config(&self) -> (u16, Option<AccountId>) {
+        (self.fee_burn_bps, self.burn_sink.clone())
+    }
+
+    /// Get the outstanding bond liability for a currency: bonds posted by asserters and
+    /// disputers that have not yet been paid out or taken as oracle fees.
+    pub fn get_total_bonded(&self, currency: AccountId) -> U128 {
+        U128(self.total_bonded.get(&currency).copied().unwrap_or(0))
+    }
+
+    /// Configure the NEAR deposit used to register settlement payout recipients with the
+    /// bond token before transferring to them. `None` disables registration.
+    /// Deposits are paid from the oracle's balance and refunded for registered recipients.
+    pub fn set_payout_storage_deposit(&mut self, deposit: Option<NearToken>) {
+        self.assert_admin("set_payout_storage_deposit");
+        if let Some(deposit) = deposit {
+            require!(
+                deposit <= MAX_PAYOUT_STORAGE_DEPOSIT,
+                "Storage deposit exceeds 0.1 NEAR"
+            );
+        }
+        self.payout_storage_deposit = deposit;
+    }
+
+    /// Get the storage deposit used to register payout recipients, if enabled
+    pub fn get_payout_storage_deposit(&self) -> Option<NearToken> {
+        self.payout_storage_deposit
+    }
+
+    /// Override gas attached to DVM cross-contract calls. `None` leaves a value unchanged.
+    pub fn set_dvm_gas(
+        &mut self,
+        request_gas: Option<Gas>,
+        get_price_gas: Option<Gas>,
+        price_callback_gas: Option<Gas>,
+    ) {
+        self.assert_admin("set_dvm_gas");
+        for gas in [request_gas, get_price_gas, price_callback_gas]
+            .into_iter()
+            .flatten()
+        {
+            require!(
+                gas > Gas::from_gas(0) && gas <= MAX_DVM_GAS,
+                "DVM gas must be positive and at most 250 TGas"
+            );
+        }
+        if let Some(gas) = request_gas {
+            self.gas_for_dvm_request = gas;
+        }
+        if let Some(gas) = get_price_gas {
+            self.gas_for_dvm_get_price = gas;
+        }
+        if let Some(gas) = price_callback_gas {
+            self.gas_for_dvm_price_callback = gas;
+        }
+    }
+
+    /// Get gas attached to DVM calls: (request_price, get_price, price callback)
+    pub fn get_dvm_gas(&self) -> (Gas, Gas, Gas) {
+        (
+            self.gas_for_dvm_request,
+            self.gas_for_dvm_get_price,
+            self.gas_for_dvm_price_callback,
+        )
+    }
+
+    /// Set the dispute bond as a multiple of the assertion bond, in basis points
+    /// (10000 = 1x). Applies to disputes posted after the change.
+    pub fn set_dispute_bond_multiplier(&mut self, multiplier_bps: u32) {
+        self.assert_admin("set_dispute_bond_multiplier");
+        require!(
+            multiplier_bps > 0 && multiplier_bps <= MAX_DISPUTE_BOND_MULTIPLIER_BPS,
+            "Dispute bond multiplier must be positive and at most 10x"
+        );
+        self.dispute_bond_multiplier_bps = multiplier_bps;
+    }
+
+    /// Get the dispute bond multiplier in basis points
+    pub fn get_dispute_bond_multiplier(&self) -> u32 {
+        self.dispute_bond_multiplier_bps
+    }
+
+    /// Propose a new oracle owner. Ownership only moves once `new_owner` calls
+    /// `accept_ownership`, so a mistyped account cannot take over the oracle.
+    /// Proposing again replaces the pending owner.
+    pub fn propose_owner(&mut self, new_owner: AccountId) {
+        self.assert_admin("propose_owner");
+        self.pending_owner.propose(new_owner);
+    }
+
+    /// Accept a pending ownership proposal. Only callable by the proposed owner.
+    pub fn accept_ownership(&mut self) {
+        self.pending_owner.accept(&mut self.owner, EVENT_STANDARD);
+    }
+
+    /// Get the account proposed as the next owner, if any.
+    pub fn get_pending_owner(&self) -> Option<AccountId> {
+        self.pending_owner.get()
+    }
+
+    /// Get current oracle owner.
+    pub fn get_owner(&self) -> AccountId {
+        self.owner.clone()
+    }
+
+    /// Get all contract-wide settings in one snapshot
+    pub fn get_config(&self) -> OracleConfig {
+        OracleConfig {
+            owner: self.owner.clone(),
+            default_currency: self.default_currency.clone(),
+            default_liveness_ns: U64(self.default_liveness_ns),
+            burned_bond_percentage: U128(self.burned_bond_percentage),
+            voting_contract: self.voting_contract.clone(),
+            registry: self.registry.clone(),
+            fee_burn_bps: self.fee_burn_bps,
+            burn_sink: self.burn_sink.clone(),
+            require_identifier_whitelist: self.require_identifier_whitelist,
+            dispute_bond_multiplier_bps: self.dispute_bond_multiplier_bps,
+            dispute_grace_ns: U64(self.dispute_grace_ns),
+            dispute_cooldown_ns: U64(self.dispute_cooldown_ns),
+            max_assertion_extensions: self.max_assertion_extensions,
+            assertion_creation_fee_bps: self.assertion_creation_fee_bps,
+            escalation_delay_ns: U64(self.escalation_delay_ns),
+        }
+    }
+
+    /// Emergency token withdrawal for stuck funds recovery.
+    /// Owner-only: can move bonded funds, so use only for controlled recovery.
+    pub fn emergency_withdraw_token(
+        &mut self,
+        token: AccountId,
+        receiver_id: AccountId,
+        amount: U128,
+    ) -> Promise {
+        self.assert_admin("emergency_withdraw_token");
+        require!(amount.0 > 0, "Amount must be positive");
+
+        Promise::new(token).function_call(
+            "ft_transfer".to_string(),
+            near_sdk::serde_json::json!({
+                "receiver_id": receiver_id,
+                "amount": amount,
+            })
+            .to_string()
+            .into_bytes(),
+            NearToken::from_yoctonear(1),
+            GAS_FOR_FT_TRANSFER,
+        )
     }
 
     /// Emergency native NEAR withdrawal for stuck balance recovery.
     /// Owner-only.
     pub fn emergency_withdraw_near(&mut self, receiver_id: AccountId, amount: U128) -> Promise {
-        self.assert_owner();
+        self.assert_admin("emergency_withdraw_near");
         require!(amount.0 > 0, "Amount must be positive");
         require!(
             env::account_balance() >= NearToken::from_yoctonear(amount.0),
@@ -378,7 +1365,12 @@ impl NestOptimisticOracle {
 
     /// Called by NEP-141 token contract when tokens are transferred via ft_transfer_call
     /// Returns the amount of tokens to refund (0 if all tokens are used)
-    pub fn ft_on_transfer(&mut self, sender_id: AccountId, amount: U128, msg: String) -> U128 {
+    pub fn ft_on_transfer(
+        &mut self,
+        sender_id: AccountId,
+        amount: U128,
+        msg: String,
+    ) -> PromiseOrValue<U128> {
         let currency = env::predecessor_account_id();
 
         // Parse the message to determine the action
@@ -387,26 +1379,45 @@ impl NestOptimisticOracle {
 
         match parsed_msg {
             FtOnTransferMsg::AssertTruth(args) => {
-                let _assertion_id = self.internal_assert_truth(
-                    args.claim,
-                    args.asserter,
-                    args.callback_recipient,
-                    args.escalation_manager,
-                    args.liveness_ns.map(|l| l.0),
-                    args.assertion_time_ns.map(|t| t.0),
-                    currency,
-                    amount.0,
-                    args.identifier,
-                    args.domain_id,
-                    args.assertion_id_override,
-                    sender_id,
-                );
+                // Registry-gated deployments create the assertion once the caller is confirmed
+                if let Some(ref registry) = self.registry {
+                    return PromiseOrValue::Promise(
+                        Promise::new(registry.clone())
+                            .function_call(
+                                "is_contract_registered".to_string(),
+                                near_sdk::serde_json::json!({
+                                    "contract_address": sender_id,
+                                })
+                                .to_string()
+                                .into_bytes(),
+                                NearToken::from_yoctonear(0),
+                                GAS_FOR_REGISTRY_CHECK,
+                            )
+                            .then(
+                                Promise::new(env::current_account_id()).function_call(
+                                    "on_registry_checked".to_string(),
+                                    near_sdk::serde_json::json!({
+                                        "sender_id": sender_id,
+                                        "currency": currency,
+                                        "amount": amount,
+                                        "msg": msg,
+                                    })
+                                    .to_string()
+                                    .into_bytes(),
+                                    NearToken::from_yoctonear(0),
+                                    GAS_FOR_REGISTRY_CALLBACK,
+                                ),
+                            ),
+                    );
+                }
+                self.internal_assert_truth_from_args(args, currency, amount.0, sender_id);
                 // All tokens used for bond, no refund
-                U128(0)
+                PromiseOrValue::Value(U128(0))
             }
             FtOnTransferMsg::DisputeAssertion {
                 assertion_id,
                 disputer,
+                evidence_uri,
             } => {
                 self.internal_dispute_assertion(
                     assertion_id,
@@ -414,17 +1425,101 @@ impl NestOptimisticOracle {
                     currency,
                     amount.0,
                     sender_id,
+                    evidence_uri,
                 );
                 // All tokens used for dispute bond, no refund
-                U128(0)
+                PromiseOrValue::Value(U128(0))
             }
         }
     }
 
+    /// Callback after the registry check on an assertion caller.
+    /// Creates the assertion for registered callers; otherwise refunds the full bond.
+    #[private]
+    pub fn on_registry_checked(
+        &mut self,
+        sender_id: AccountId,
+        currency: AccountId,
+        amount: U128,
+        msg: String,
+        #[callback_result] registered_result: Result<bool, PromiseError>,
+    ) -> U128 {
+        if !matches!(registered_result, Ok(true)) {
+            env::log_str(&format!(
+                "Assertion caller {} is not registered; refunding bond",
+                sender_id
+            ));
+            return amount;
+        }
+
+        let Ok(FtOnTransferMsg::AssertTruth(args)) = near_sdk::serde_json::from_str(&msg) else {
+            env::panic_str("Invalid ft_on_transfer message format");
+        };
+        self.internal_assert_truth_from_args(args, currency, amount.0, sender_id);
+        // All tokens used for bond, no refund
+        U128(0)
+    }
+
     // ========================================================================
     // Core Assertion Methods
     // ========================================================================
 
+    /// Create an assertion from an `AssertTruth` message, charging its storage to the
+    /// caller's assertion storage balance
+    fn internal_assert_truth_from_args(
+        &mut self,
+        args: AssertTruthArgs,
+        currency: AccountId,
+        bond: u128,
+        caller: AccountId,
+    ) -> Bytes32 {
+        let assertion_id = self.internal_assert_truth(
+            args.claim,
+            args.asserter,
+            args.callback_recipient,
+            args.escalation_manager,
+            args.arbitrate_via_escalation_manager,
+            args.discard_oracle,
+            args.validate_disputers,
+            args.liveness_ns.map(|l| l.0),
+            args.assertion_time_ns.map(|t| t.0),
+            currency,
+            bond,
+            args.identifier,
+            args.domain_id,
+            args.assertion_id_override,
+            args.metadata_uri,
+            caller.clone(),
+        );
+        self.charge_assertion_storage(assertion_id, &caller);
+        assertion_id
+    }
+
+    /// Draw an assertion's recorded storage cost from `payer`'s assertion storage balance
+    fn charge_assertion_storage(&mut self, assertion_id: Bytes32, payer: &AccountId) {
+        let bytes = self
+            .assertion_storage_bytes
+            .get(&assertion_id)
+            .copied()
+            .unwrap_or(0);
+        let cost = env::storage_byte_cost().saturating_mul(bytes as u128);
+        let balance = self.get_assertion_storage_balance(payer.clone()).0;
+        require!(
+            balance >= cost.as_yoctonear(),
+            OracleError::InsufficientStorageBalance {
+                account_id: payer.clone(),
+                required: cost.as_yoctonear(),
+                available: balance,
+            }
+            .to_string()
+        );
+        self.assertion_storage_balances.insert(
+            payer.clone(),
+            NearToken::from_yoctonear(balance - cost.as_yoctonear()),
+        );
+        self.assertion_storage_deposits.insert(assertion_id, cost);
+    }
+
     /// Internal implementation of assert_truth
     /// Called by ft_on_transfer when receiving bond tokens
     fn internal_assert_truth(
@@ -433,19 +1528,30 @@ impl NestOptimisticOracle {
         asserter: AccountId,
         callback_recipient: Option<AccountId>,
         escalation_manager: Option<AccountId>,
+        arbitrate_via_escalation_manager: bool,
+        discard_oracle: bool,
+        validate_disputers: bool,
         liveness_ns: Option<u64>,
         assertion_time_ns: Option<u64>,
         currency: AccountId,
-        bond: u128,
+        amount: u128,
         identifier: Option<Bytes32>,
         domain_id: Option<Bytes32>,
         assertion_id_override: Option<Bytes32>,
+        metadata_uri: Option<String>,
         caller: AccountId,
     ) -> Bytes32 {
+        if let Some(ref uri) = metadata_uri {
+            require!(uri.len() <= MAX_METADATA_URI_LEN, "Metadata URI too long");
+        }
+        // The creation fee comes out of the posted amount; the rest is bonded
+        let creation_fee = amount.saturating_mul(self.assertion_creation_fee_bps as u128)
+            / BASIS_POINTS_DENOMINATOR;
+        let bond = amount - creation_fee;
         let time = assertion_time_ns.unwrap_or_else(|| self.get_current_time());
-        let liveness = liveness_ns.unwrap_or(self.default_liveness_ns);
         let identifier = identifier.unwrap_or(DEFAULT_IDENTIFIER);
         let domain_id = domain_id.unwrap_or([0u8; 32]);
+        let liveness = liveness_ns.unwrap_or_else(|| self.resolve_default_liveness(&domain_id));
 
         // Generate unique assertion ID (or accept integrator-provided deterministic override)
         let assertion_id = assertion_id_override.unwrap_or_else(|| {
@@ -464,32 +1570,52 @@ impl NestOptimisticOracle {
 
         // Validations (equivalent to Solidity requires)
         require!(
-            self.assertions.get(&assertion_id).is_none(),
-            "Assertion already exists"
+            self.assertions.get(&assertion_id).is_none()
+                && !self.pruned_assertions.contains_key(&assertion_id),
+            OracleError::AssertionAlreadyExists { assertion_id }.to_string()
         );
         require!(
-            self.cached_identifiers
-                .get(&identifier)
-                .copied()
-                .unwrap_or(false),
-            "Unsupported identifier"
+            !self.require_identifier_whitelist
+                || self
+                    .cached_identifiers
+                    .get(&identifier)
+                    .copied()
+                    .unwrap_or(false),
+            OracleError::UnsupportedIdentifier { identifier }.to_string()
         );
         require!(
             self.cached_currencies
                 .get(&currency)
                 .map(|c| c.is_whitelisted)
                 .unwrap_or(false),
-            "Unsupported currency"
+            OracleError::UnsupportedCurrency {
+                currency: currency.clone()
+            }
+            .to_string()
+        );
+        let min_bond = self
+            .get_minimum_bond_for_identifier(currency.clone(), identifier)
+            .0;
+        require!(
+            bond >= min_bond,
+            OracleError::BondTooLow {
+                required: min_bond,
+                provided: bond
+            }
+            .to_string()
+        );
+        require!(
+            escalation_manager.is_some()
+                || !(arbitrate_via_escalation_manager || discard_oracle || validate_disputers),
+            "Escalation manager settings require an escalation manager"
         );
-        let min_bond = self.get_minimum_bond(currency.clone()).0;
-        require!(bond >= min_bond, "Bond amount too low");
 
         // Create the assertion
         let assertion = Assertion {
             escalation_manager_settings: EscalationManagerSettings {
-                arbitrate_via_escalation_manager: false,
-                discard_oracle: false,
-                validate_disputers: false,
+                arbitrate_via_escalation_manager,
+                discard_oracle,
+                validate_disputers,
                 asserting_caller: caller.clone(),
                 escalation_manager: escalation_manager.clone(),
             },
@@ -507,9 +1633,30 @@ impl NestOptimisticOracle {
             bond: U128(bond),
             callback_recipient: callback_recipient.clone(),
             disputer: None,
+            disputers: Vec::new(),
+            dispute_evidence_uri: None,
+            callback_delivered: false,
+            extension_count: 0,
+            resolved_price: None,
+            dispute_time_ns: None,
+            settlement_time_ns: None,
         };
 
+        let initial_storage_usage = env::storage_usage();
         self.assertions.insert(assertion_id, assertion);
+        self.assertions.flush();
+        if let Some(ref uri) = metadata_uri {
+            self.metadata_uris.insert(assertion_id, uri.clone());
+            self.metadata_uris.flush();
+        }
+        self.assertion_storage_bytes.insert(
+            assertion_id,
+            env::storage_usage().saturating_sub(initial_storage_usage),
+        );
+        self.increase_total_bonded(&currency, bond);
+        if creation_fee > 0 {
+            self.route_oracle_fee(&currency, creation_fee);
+        }
 
         // Emit event
         Event::AssertionMade {
@@ -524,6 +1671,8 @@ impl NestOptimisticOracle {
             currency: &currency,
             bond: &U128(bond),
             identifier: &identifier,
+            metadata_uri: &metadata_uri,
+            creation_fee: &U128(creation_fee),
         }
         .emit();
 
@@ -539,48 +1688,204 @@ impl NestOptimisticOracle {
         currency: AccountId,
         bond_amount: u128,
         _caller: AccountId,
+        evidence_uri: Option<String>,
     ) {
         let current_time = self.get_current_time();
+        let dispute_grace_ns = self.dispute_grace_ns;
+        let max_extensions = self.max_assertion_extensions;
+        let multiplier_bps = self.dispute_bond_multiplier_bps;
+        if let Some(ref uri) = evidence_uri {
+            require!(uri.len() <= MAX_EVIDENCE_URI_LEN, "Evidence URI too long");
+        }
+        if self.dispute_cooldown_ns > 0 {
+            if let Some(last_dispute_time) = self.last_dispute_times.get(&disputer) {
+                require!(
+                    current_time >= last_dispute_time.saturating_add(self.dispute_cooldown_ns),
+                    "Dispute cooldown active"
+                );
+            }
+        }
 
         let assertion = self
             .assertions
             .get_mut(&assertion_id)
             .expect("Assertion does not exist");
 
-        require!(assertion.disputer.is_none(), "Assertion already disputed");
+        require!(
+            !assertion.settled && !assertion.settlement_pending,
+            "Assertion already settling"
+        );
         require!(
             assertion.expiration_time_ns > current_time,
-            "Assertion is expired"
+            OracleError::AssertionExpired {
+                assertion_id,
+                expiration_time_ns: assertion.expiration_time_ns
+            }
+            .to_string()
+        );
+        require!(
+            assertion.currency == currency,
+            OracleError::WrongDisputeCurrency {
+                expected: assertion.currency.clone(),
+                provided: currency.clone()
+            }
+            .to_string()
         );
-        require!(assertion.currency == currency, "Wrong currency for dispute");
+        let required_bond = Self::required_dispute_bond(assertion.bond.0, multiplier_bps);
         require!(
-            bond_amount == assertion.bond.0,
-            "Dispute bond must match assertion bond"
+            bond_amount == required_bond,
+            OracleError::DisputeBondMismatch {
+                required: required_bond,
+                provided: bond_amount
+            }
+            .to_string()
         );
 
         // Store the identifier before we release the borrow
         let identifier = assertion.identifier;
+        let assertion_time_ns = assertion.assertion_time_ns;
+        let escalation_manager = assertion
+            .escalation_manager_settings
+            .escalation_manager
+            .clone();
+        let arbitrate_via_escalation_manager = assertion
+            .escalation_manager_settings
+            .arbitrate_via_escalation_manager;
+        let first_dispute = assertion.disputer.is_none();
+        let expiration_time_ns = assertion.expiration_time_ns;
+
+        // A last-second dispute leaves the asserter time to respond before resolution,
+        // unless the assertion has already used up its extensions
+        let extend_expiration = first_dispute
+            && dispute_grace_ns > 0
+            && expiration_time_ns - current_time <= dispute_grace_ns
+            && assertion.extension_count < max_extensions;
+        if extend_expiration {
+            assertion.extension_count += 1;
+        }
+
+        // Record the disputer, aggregating bonds posted by the same account
+        if let Some(entry) = assertion
+            .disputers
+            .iter_mut()
+            .find(|(account, _)| *account == disputer)
+        {
+            entry.1 = U128(entry.1 .0 + bond_amount);
+        } else {
+            require!(
+                assertion.disputers.len() < MAX_DISPUTERS,
+                "Too many disputers"
+            );
+            assertion
+                .disputers
+                .push((disputer.clone(), U128(bond_amount)));
+        }
+        if first_dispute {
+            assertion.disputer = Some(disputer.clone());
+            assertion.dispute_evidence_uri = evidence_uri.clone();
+            assertion.dispute_time_ns = Some(current_time);
+        }
+        self.increase_total_bonded(&currency, bond_amount);
+        if self.dispute_cooldown_ns > 0 {
+            self.last_dispute_times
+                .insert(disputer.clone(), current_time);
+        }
 
-        // Set the disputer
-        assertion.disputer = Some(disputer.clone());
+        if extend_expiration {
+            self.extended_expirations.insert(
+                assertion_id,
+                expiration_time_ns.saturating_add(dispute_grace_ns),
+            );
+        }
 
         // Emit event
         Event::AssertionDisputed {
             assertion_id: &assertion_id,
             caller: &env::predecessor_account_id(),
             disputer: &disputer,
+            evidence_uri: &evidence_uri,
         }
         .emit();
 
-        // Escalate to DVM if voting contract is configured
-        if let Some(ref voting_contract) = self.voting_contract {
-            // Convert identifier to string for DVM
-            let identifier_str = String::from_utf8_lossy(&identifier)
-                .trim_end_matches('\0')
-                .to_string();
+        // Additional disputers join the existing escalation
+        if !first_dispute {
+            return;
+        }
+
+        let ancillary_data = Self::dispute_ancillary_data(&assertion_id, evidence_uri.as_deref());
+
+        // Assertions arbitrated by their escalation manager never reach the DVM
+        if arbitrate_via_escalation_manager {
+            if let Some(manager) = escalation_manager {
+                let _ = Promise::new(manager).function_call(
+                    "request_price".to_string(),
+                    near_sdk::serde_json::json!({
+                        "identifier": identifier,
+                        "time": assertion_time_ns,
+                        "ancillary_data": ancillary_data,
+                    })
+                    .to_string()
+                    .into_bytes(),
+                    NearToken::from_yoctonear(0),
+                    GAS_FOR_ESCALATION_MANAGER_REQUEST,
+                );
+            }
+            return;
+        }
+
+        if self.voting_contract.is_none() {
+            return;
+        }
+        // With an escalation delay the dispute waits for `escalate_dispute`
+        if self.escalation_delay_ns > 0 {
+            self.pending_escalations.insert(assertion_id, current_time);
+            Event::DisputeEscalationScheduled {
+                assertion_id: &assertion_id,
+                escalate_after_ns: current_time.saturating_add(self.escalation_delay_ns),
+            }
+            .emit();
+            return;
+        }
+        self.request_dvm_vote(assertion_id, identifier, current_time, ancillary_data);
+    }
+
+    /// Escalate a dispute deferred by the escalation delay to the DVM once the delay has
+    /// passed. Callable by anyone, e.g. a keeper.
+    pub fn escalate_dispute(&mut self, assertion_id: Bytes32) {
+        let disputed_at = self
+            .pending_escalations
+            .get(&assertion_id)
+            .copied()
+            .expect("No dispute awaiting escalation");
+        require!(
+            self.get_current_time() >= disputed_at.saturating_add(self.escalation_delay_ns),
+            "Escalation delay not elapsed"
+        );
+        self.pending_escalations.remove(&assertion_id);
+
+        let assertion = self
+            .assertions
+            .get(&assertion_id)
+            .expect("Assertion does not exist");
+        let identifier = assertion.identifier;
+        let ancillary_data =
+            Self::dispute_ancillary_data(&assertion_id, assertion.dispute_evidence_uri.as_deref());
+        // The vote prices the assertion at dispute time, as with immediate escalation
+        self.request_dvm_vote(assertion_id, identifier, disputed_at, ancillary_data);
+    }
 
-            // Use assertion_id as ancillary data so DVM can identify the dispute
-            let ancillary_data = assertion_id.to_vec();
+    /// Creates the DVM vote for a disputed assertion and records its request id in
+    /// `on_dvm_request_complete`.
+    fn request_dvm_vote(
+        &self,
+        assertion_id: Bytes32,
+        identifier: Bytes32,
+        dispute_time_ns: u64,
+        ancillary_data: Vec<u8>,
+    ) {
+        if let Some(ref voting_contract) = self.voting_contract {
+            let identifier_str = Self::dvm_identifier(&identifier);
+            let numerical = self.is_numerical_identifier(&identifier);
 
             // Call voting.request_price() to create a DVM vote
             let _ = Promise::new(voting_contract.clone())
@@ -588,13 +1893,16 @@ impl NestOptimisticOracle {
                     "request_price".to_string(),
                     near_sdk::serde_json::json!({
                         "identifier": identifier_str,
-                        "timestamp": current_time,
+                        "timestamp": dispute_time_ns,
                         "ancillary_data": ancillary_data,
+                        // Assertions resolve true/false, so votes must be 0 or 1e18, unless
+                        // the identifier's threshold is compared against a numerical price
+                        "binary": !numerical,
                     })
                     .to_string()
                     .into_bytes(),
                     NearToken::from_yoctonear(0),
-                    GAS_FOR_DVM_REQUEST,
+                    self.gas_for_dvm_request,
                 )
                 .then(
                     Promise::new(env::current_account_id()).function_call(
@@ -624,6 +1932,8 @@ impl NestOptimisticOracle {
                 // Store the mapping between assertion and DVM request
                 self.dispute_requests.insert(assertion_id, request_id);
                 self.request_to_assertion.insert(request_id, assertion_id);
+                self.dispute_escalation_times
+                    .insert(assertion_id, self.get_current_time());
 
                 env::log_str(&format!(
                     "DVM request created for assertion. request_id: {:?}",
@@ -636,12 +1946,50 @@ impl NestOptimisticOracle {
         }
     }
 
+    /// Repoint an undisputed assertion to a different escalation manager.
+    /// Callable by the owner or the asserter, e.g. when the current manager is found faulty.
+    pub fn reassign_escalation_manager(&mut self, assertion_id: Bytes32, new_manager: AccountId) {
+        let caller = env::predecessor_account_id();
+        let owner = self.owner.clone();
+        let assertion = self
+            .assertions
+            .get_mut(&assertion_id)
+            .expect("Assertion does not exist");
+
+        require!(
+            caller == owner || caller == assertion.asserter,
+            "Only owner or asserter can reassign the escalation manager"
+        );
+        require!(
+            !assertion.settled && !assertion.settlement_pending,
+            "Assertion already settling"
+        );
+        require!(
+            assertion.disputer.is_none(),
+            "Cannot reassign escalation manager after dispute"
+        );
+
+        let previous_manager = assertion
+            .escalation_manager_settings
+            .escalation_manager
+            .replace(new_manager.clone());
+
+        Event::EscalationManagerReassigned {
+            assertion_id: &assertion_id,
+            previous_manager: &previous_manager,
+            new_manager: &new_manager,
+            caller: &caller,
+        }
+        .emit();
+    }
+
     // ========================================================================
     // Settlement Methods
     // ========================================================================
 
     /// Resolves an assertion. If the assertion has not been disputed, the assertion is resolved
-    /// as true and the asserter receives the bond. If disputed, resolution is fetched from DVM.
+    /// as true and the asserter receives the bond. If disputed, resolution is fetched from DVM,
+    /// or from the escalation manager when it arbitrates the dispute.
     pub fn settle_assertion(&mut self, assertion_id: Bytes32) {
         let current_time = self.get_current_time();
 
@@ -667,8 +2015,53 @@ impl NestOptimisticOracle {
 
             let _ = self.start_settlement_payout(assertion_id, true);
         } else {
-            // Disputed - check if DVM has resolved this
-            let request_id = self.dispute_requests.get(&assertion_id)
+            // Disputed - resolution waits for any grace extension, never the original expiry
+            self.assert_dispute_grace_elapsed(&assertion_id);
+            require!(
+                !self.pending_escalations.contains_key(&assertion_id),
+                "Dispute awaiting escalation to the DVM"
+            );
+
+            // Disputes arbitrated by the escalation manager settle from its price
+            if assertion
+                .escalation_manager_settings
+                .arbitrate_via_escalation_manager
+            {
+                if let Some(manager) = assertion.escalation_manager_settings.escalation_manager {
+                    let _ = Promise::new(manager)
+                        .function_call(
+                            "get_price".to_string(),
+                            near_sdk::serde_json::json!({
+                                "identifier": assertion.identifier,
+                                "time": assertion.assertion_time_ns,
+                                "ancillary_data": Self::dispute_ancillary_data(
+                                    &assertion_id,
+                                    assertion.dispute_evidence_uri.as_deref(),
+                                ),
+                            })
+                            .to_string()
+                            .into_bytes(),
+                            NearToken::from_yoctonear(0),
+                            GAS_FOR_ESCALATION_MANAGER_GET_PRICE,
+                        )
+                        .then(
+                            Promise::new(env::current_account_id()).function_call(
+                                "on_escalation_manager_price_received".to_string(),
+                                near_sdk::serde_json::json!({
+                                    "assertion_id": assertion_id,
+                                })
+                                .to_string()
+                                .into_bytes(),
+                                NearToken::from_yoctonear(0),
+                                self.gas_for_dvm_price_callback,
+                            ),
+                        );
+                    return;
+                }
+            }
+
+            // Check if DVM has resolved this
+            let request_id = self.dispute_requests.get(&assertion_id)
                 .expect("Dispute not escalated to DVM - use resolve_disputed_assertion for manual resolution");
 
             let voting_contract = self
@@ -686,7 +2079,7 @@ impl NestOptimisticOracle {
                     .to_string()
                     .into_bytes(),
                     NearToken::from_yoctonear(0),
-                    GAS_FOR_DVM_GET_PRICE,
+                    self.gas_for_dvm_get_price,
                 )
                 .then(
                     Promise::new(env::current_account_id()).function_call(
@@ -697,7 +2090,7 @@ impl NestOptimisticOracle {
                         .to_string()
                         .into_bytes(),
                         NearToken::from_yoctonear(0),
-                        GAS_FOR_DVM_PRICE_CALLBACK,
+                        self.gas_for_dvm_price_callback,
                     ),
                 );
         }
@@ -713,12 +2106,23 @@ impl NestOptimisticOracle {
     ) {
         match price_result {
             Ok(Some(price)) => {
-                // DVM has resolved - price >= NUMERICAL_TRUE means asserter wins
-                let resolution = price >= NUMERICAL_TRUE;
+                // DVM has resolved - price at or above the identifier's threshold means
+                // asserter wins
+                let resolution = self.record_resolved_price(assertion_id, price);
                 let _ = self.start_settlement_payout(assertion_id, resolution);
             }
             Ok(None) => {
-                env::panic_str("DVM has not resolved this dispute yet");
+                let Some((resolution, escalated_at_ns)) = self.due_dvm_fallback(&assertion_id)
+                else {
+                    env::panic_str("DVM has not resolved this dispute yet");
+                };
+                Event::DvmResolutionFallback {
+                    assertion_id: &assertion_id,
+                    resolution,
+                    escalated_at_ns,
+                }
+                .emit();
+                let _ = self.start_settlement_payout(assertion_id, resolution);
             }
             Err(_) => {
                 env::panic_str("Failed to get DVM resolution");
@@ -726,9 +2130,64 @@ impl NestOptimisticOracle {
         }
     }
 
+    /// Callback after the escalation manager's get_price completes
+    /// Settles a dispute the manager arbitrates based on its resolution
+    #[private]
+    pub fn on_escalation_manager_price_received(
+        &mut self,
+        assertion_id: Bytes32,
+        #[callback_result] price_result: Result<i128, PromiseError>,
+    ) {
+        match price_result {
+            Ok(price) => {
+                // Same threshold as a DVM price: at or above it the asserter wins
+                let resolution = self.record_resolved_price(assertion_id, price);
+                let _ = self.start_settlement_payout(assertion_id, resolution);
+            }
+            Err(_) => {
+                env::panic_str("Escalation manager has not resolved this dispute");
+            }
+        }
+    }
+
+    /// Called by the voting contract when a DVM request resolves, settling the linked
+    /// assertion without a separate `settle_assertion` call.
+    ///
+    /// Best-effort: does nothing if the request has no assertion, the assertion is already
+    /// settled or pending payout, or its dispute grace period has not elapsed, in which case
+    /// `settle_assertion` settles it as usual.
+    pub fn price_resolved_callback(&mut self, request_id: CryptoHash, resolved_price: i128) {
+        require!(
+            self.voting_contract.as_ref() == Some(&env::predecessor_account_id()),
+            "Only the voting contract can call this method"
+        );
+        let Some(assertion_id) = self.request_to_assertion.get(&request_id).copied() else {
+            return;
+        };
+        let Some(assertion) = self.assertions.get(&assertion_id) else {
+            return;
+        };
+        if assertion.settled || assertion.settlement_pending {
+            return;
+        }
+        if self
+            .extended_expirations
+            .get(&assertion_id)
+            .is_some_and(|expiration| self.get_current_time() < *expiration)
+        {
+            return;
+        }
+
+        let resolution = self.record_resolved_price(assertion_id, resolved_price);
+        let _ = self.start_settlement_payout(assertion_id, resolution);
+    }
+
     /// Settles an assertion and returns the resolution
     /// Equivalent to: function settleAndGetAssertionResult(bytes32 assertionId) external returns (bool)
     pub fn settle_and_get_assertion_result(&mut self, assertion_id: Bytes32) -> bool {
+        if let Some(pruned) = self.pruned_assertions.get(&assertion_id) {
+            return pruned.result;
+        }
         let assertion = self
             .assertions
             .get(&assertion_id)
@@ -744,12 +2203,20 @@ impl NestOptimisticOracle {
     /// Admin method to manually resolve a disputed assertion
     /// This is a fallback for when DVM escalation fails or is not configured
     /// In normal operation, use settle_assertion which queries DVM automatically
+    ///
+    /// If the dispute is headed for the DVM (awaiting escalation, requested, or escalated)
+    /// or arbitrated by its escalation manager, the override is only allowed once it has
+    /// been outstanding for `admin_override_stale_ns`, so the owner cannot front-run a
+    /// legitimate outcome. For escalated disputes the DVM is then queried, and the override
+    /// only applies if it has still not resolved.
     pub fn resolve_disputed_assertion(
         &mut self,
         assertion_id: Bytes32,
         resolution: bool, // true = asserter wins, false = disputer wins
+        reason: String,
     ) {
-        self.assert_owner();
+        self.assert_admin("resolve_disputed_assertion");
+        require!(!reason.trim().is_empty(), "Override reason required");
 
         let assertion = self
             .assertions
@@ -762,13 +2229,87 @@ impl NestOptimisticOracle {
             "Settlement already pending payout callback"
         );
         require!(assertion.disputer.is_some(), "Assertion not disputed");
-
-        // Check if DVM has been used - if so, should use settle_assertion instead
-        if self.dispute_requests.get(&assertion_id).is_some() {
-            env::log_str("Warning: This dispute was escalated to DVM. Consider using settle_assertion instead.");
+        self.assert_dispute_grace_elapsed(&assertion_id);
+
+        let arbitrated = assertion
+            .escalation_manager_settings
+            .arbitrate_via_escalation_manager;
+        if arbitrated || self.voting_contract.is_some() {
+            // Before the DVM request lands (awaiting escalation or in flight) the wait runs
+            // from the dispute
+            let outstanding_since = self
+                .dispute_escalation_times
+                .get(&assertion_id)
+                .copied()
+                .or(assertion.dispute_time_ns)
+                .unwrap_or(assertion.assertion_time_ns);
+            require!(
+                self.get_current_time()
+                    >= outstanding_since.saturating_add(self.admin_override_stale_ns),
+                if arbitrated {
+                    "Escalation manager dispute is not yet stale"
+                } else {
+                    "DVM request is not yet stale"
+                }
+            );
         }
 
-        let _ = self.start_settlement_payout(assertion_id, resolution);
+        let Some(request_id) = self.dispute_requests.get(&assertion_id).copied() else {
+            self.apply_admin_override(assertion_id, resolution, &reason);
+            return;
+        };
+        let voting_contract = self
+            .voting_contract
+            .clone()
+            .expect("Voting contract not configured");
+
+        // Only override a DVM request that has still not resolved
+        let _ = Promise::new(voting_contract)
+            .function_call(
+                "get_price".to_string(),
+                near_sdk::serde_json::json!({
+                    "request_id": request_id,
+                })
+                .to_string()
+                .into_bytes(),
+                NearToken::from_yoctonear(0),
+                self.gas_for_dvm_get_price,
+            )
+            .then(
+                Promise::new(env::current_account_id()).function_call(
+                    "on_admin_override_dvm_checked".to_string(),
+                    near_sdk::serde_json::json!({
+                        "assertion_id": assertion_id,
+                        "resolution": resolution,
+                        "reason": reason,
+                    })
+                    .to_string()
+                    .into_bytes(),
+                    NearToken::from_yoctonear(0),
+                    self.gas_for_dvm_price_callback,
+                ),
+            );
+    }
+
+    /// Callback after the DVM is queried for `resolve_disputed_assertion`
+    /// Applies the override only if the DVM has not resolved the dispute
+    #[private]
+    pub fn on_admin_override_dvm_checked(
+        &mut self,
+        assertion_id: Bytes32,
+        resolution: bool,
+        reason: String,
+        #[callback_result] price_result: Result<Option<i128>, PromiseError>,
+    ) {
+        match price_result {
+            Ok(None) => self.apply_admin_override(assertion_id, resolution, &reason),
+            Ok(Some(_)) => {
+                env::panic_str("DVM has resolved this dispute - use settle_assertion");
+            }
+            Err(_) => {
+                env::panic_str("Failed to check DVM resolution");
+            }
+        }
     }
 
     /// Retry a failed settlement payout callback.
@@ -789,6 +2330,7 @@ impl NestOptimisticOracle {
 
         let assertion_mut = self.assertions.get_mut(&assertion_id).unwrap();
         assertion_mut.settlement_in_flight = true;
+        self.retryable_settlements.remove(&assertion_id);
 
         Event::AssertionSettlementRetryRequested {
             assertion_id: &assertion_id,
@@ -797,7 +2339,63 @@ impl NestOptimisticOracle {
         }
         .emit();
 
-        let _ = self.dispatch_settlement_payout(assertion_id, assertion.pending_settlement_resolution);
+        let _ = self.dispatch_settlement_payout(assertion_id);
+    }
+
+    /// Retry up to `limit` failed settlement payouts in one call, for keepers recovering
+    /// from a transient token outage. Returns the number of retries dispatched.
+    pub fn retry_all_settlements(&mut self, limit: u64) -> u64 {
+        require!(limit > 0, "Limit must be positive");
+        let assertion_ids: Vec<Bytes32> = self
+            .retryable_settlements
+            .iter()
+            .take(limit.min(MAX_SETTLEMENT_RETRY_BATCH) as usize)
+            .copied()
+            .collect();
+        for assertion_id in assertion_ids.iter() {
+            self.retry_settlement_payout(*assertion_id);
+        }
+        assertion_ids.len() as u64
+    }
+
+    /// Get recorded owner-only calls, oldest first, starting at log index `from_index`.
+    /// Only the last `MAX_ADMIN_ACTIONS` (256) are kept; older indexes are skipped.
+    pub fn get_admin_actions(&self, from_index: u64, limit: u64) -> Vec<AdminAction> {
+        let oldest = self.admin_action_count.saturating_sub(MAX_ADMIN_ACTIONS);
+        let start = from_index.max(oldest);
+        let end = start.saturating_add(limit).min(self.admin_action_count);
+        (start..end)
+            .filter_map(|index| {
+                self.admin_action_log
+                    .get(&(index % MAX_ADMIN_ACTIONS))
+                    .cloned()
+            })
+            .collect()
+    }
+
+    /// Get assertions whose settlement payout failed and can be retried.
+    pub fn get_retryable_settlements(&self, from_index: u64, limit: u64) -> Vec<Bytes32> {
+        self.retryable_settlements
+            .iter()
+            .skip(from_index as usize)
+            .take(limit as usize)
+            .copied()
+            .collect()
+    }
+
+    /// Settles a dispute with the owner's resolution, recording the override.
+    fn apply_admin_override(&mut self, assertion_id: Bytes32, resolution: bool, reason: &str) {
+        Event::AdminResolutionOverride {
+            assertion_id: &assertion_id,
+            resolution,
+            reason,
+        }
+        .emit();
+        self.admin_resolved_assertions.insert(assertion_id);
+        // An overridden dispute is no longer escalated
+        self.pending_escalations.remove(&assertion_id);
+
+        let _ = self.start_settlement_payout(assertion_id, resolution);
     }
 
     /// Internal helper to begin async settlement payout flow.
@@ -823,81 +2421,219 @@ impl NestOptimisticOracle {
         assertion_mut.settlement_in_flight = true;
         assertion_mut.pending_settlement_resolution = resolution;
 
-        let (payout_recipient, payout_amount, disputed, _) =
+        let (payouts, disputed, oracle_fee) =
             self.compute_settlement_payout(&assertion, resolution);
+        let payout_amount: u128 = payouts.iter().map(|(_, amount)| amount).sum();
         Event::AssertionSettlementPending {
             assertion_id: &assertion_id,
             disputed,
             settlement_resolution: resolution,
-            payout_recipient: &payout_recipient,
+            payout_recipient: &payouts[0].0,
             payout_amount: &U128(payout_amount),
             settle_caller: &env::predecessor_account_id(),
         }
         .emit();
 
-        self.dispatch_settlement_payout(assertion_id, resolution)
+        // Best-effort fee transfers, sent once; final settlement is gated on recipient payouts.
+        if disputed && oracle_fee > 0 {
+            self.decrease_total_bonded(&assertion.currency, oracle_fee);
+            self.route_oracle_fee(&assertion.currency, oracle_fee);
+        }
+
+        self.pending_payouts.insert(
+            assertion_id,
+            payouts
+                .into_iter()
+                .map(|(recipient, amount)| (recipient, U128(amount)))
+                .collect(),
+        );
+
+        self.dispatch_settlement_payout(assertion_id)
     }
 
-    fn dispatch_settlement_payout(&self, assertion_id: Bytes32, resolution: bool) -> Promise {
+    /// Sends every outstanding recipient payout as one joint promise gated on a single callback.
+    fn dispatch_settlement_payout(&self, assertion_id: Bytes32) -> Promise {
         let assertion = self
             .assertions
             .get(&assertion_id)
-            .expect("Assertion does not exist")
-            .clone();
+            .expect("Assertion does not exist");
+        let payouts = self
+            .pending_payouts
+            .get(&assertion_id)
+            .expect("No pending payouts");
 
-        let (bond_recipient, bond_recipient_amount, disputed, oracle_fee) =
-            self.compute_settlement_payout(&assertion, resolution);
+        let mut transfers = payouts.iter().map(|(recipient, amount)| {
+            self.transfer_tokens_registered(assertion.currency.clone(), recipient.clone(), amount.0)
+        });
+        let first = transfers.next().expect("No pending payouts");
+        transfers
+            .fold(first, |joint, transfer| joint.and(transfer))
+            .then(
+                Promise::new(env::current_account_id()).function_call(
+                    "on_settlement_payout_complete".to_string(),
+                    near_sdk::serde_json::json!({
+                        "assertion_id": assertion_id,
+                    })
+                    .to_string()
+                    .into_bytes(),
+                    NearToken::from_yoctonear(0),
+                    GAS_FOR_SETTLEMENT_PAYOUT_CALLBACK,
+                ),
+            )
+    }
 
-        // Best-effort owner fee transfer; final settlement is gated on recipient payout callback.
-        if disputed && oracle_fee > 0 {
-            let _ = self.transfer_tokens(assertion.currency.clone(), self.owner.clone(), oracle_fee);
+    /// Ancillary data sent with a dispute's price request: the assertion_id so the arbiter
+    /// can identify the dispute, followed by the disputer's evidence pointer when provided.
+    fn dispute_ancillary_data(assertion_id: &Bytes32, evidence_uri: Option<&str>) -> Vec<u8> {
+        let mut ancillary_data = assertion_id.to_vec();
+        if let Some(uri) = evidence_uri {
+            ancillary_data.extend_from_slice(b",evidence_uri:");
+            ancillary_data.extend_from_slice(uri.as_bytes());
         }
+        ancillary_data
+    }
 
-        self.transfer_tokens(
-            assertion.currency.clone(),
-            bond_recipient,
-            bond_recipient_amount,
-        )
-        .then(
-            Promise::new(env::current_account_id()).function_call(
-                "on_settlement_payout_complete".to_string(),
-                near_sdk::serde_json::json!({
-                    "assertion_id": assertion_id,
-                })
-                .to_string()
-                .into_bytes(),
-                NearToken::from_yoctonear(0),
-                GAS_FOR_SETTLEMENT_PAYOUT_CALLBACK,
-            ),
-        )
+    /// Whether disputes on an identifier are voted on as a numerical price rather than
+    /// true/false: the owner has configured a resolution threshold for it.
+    fn is_numerical_identifier(&self, identifier: &Bytes32) -> bool {
+        self.resolution_thresholds.contains_key(identifier)
+    }
+
+    /// Records a DVM or escalation manager price on the assertion for numerical identifiers
+    /// and returns the resolution it implies: at or above the identifier's threshold, the asserter wins.
+    fn record_resolved_price(&mut self, assertion_id: Bytes32, price: i128) -> bool {
+        let identifier = self
+            .assertions
+            .get(&assertion_id)
+            .expect("Assertion does not exist")
+            .identifier;
+        if self.is_numerical_identifier(&identifier) {
+            self.assertions
+                .get_mut(&assertion_id)
+                .unwrap()
+                .resolved_price = Some(price);
+        }
+        price >= self.get_resolution_threshold(identifier)
+    }
+
+    /// Identifier string the DVM votes on: the identifier bytes without zero padding.
+    fn dvm_identifier(identifier: &Bytes32) -> String {
+        String::from_utf8_lossy(identifier)
+            .trim_end_matches('\0')
+            .to_string()
     }
 
+    /// Computes the recipient payouts for a settlement, plus whether it was disputed
+    /// and the oracle fee taken from the losing side's bond.
+    ///
+    /// When the asserter wins they collect every disputer bond (less the fee).
+    /// When the disputers win, each disputer gets their bond back plus a pro-rata
+    /// share of the asserter's bond (less the fee); rounding dust goes to the first disputer.
     fn compute_settlement_payout(
         &self,
         assertion: &Assertion,
         resolution: bool,
-    ) -> (AccountId, u128, bool, u128) {
-        if let Some(disputer) = &assertion.disputer {
-            let oracle_fee = (self.burned_bond_percentage * assertion.bond.0) / SCALE;
-            let bond_recipient_amount = assertion.bond.0 * 2 - oracle_fee;
-            let bond_recipient = if resolution {
-                assertion.asserter.clone()
-            } else {
-                disputer.clone()
-            };
-            (bond_recipient, bond_recipient_amount, true, oracle_fee)
-        } else {
+    ) -> (Vec<(AccountId, u128)>, bool, u128) {
+        if assertion.disputer.is_none() {
             // Undisputed assertions always settle to the asserter.
-            (assertion.asserter.clone(), assertion.bond.0, false, 0)
+            return (
+                vec![(assertion.asserter.clone(), assertion.bond.0)],
+                false,
+                0,
+            );
+        }
+
+        // Disputer bonds may differ from the assertion bond under a dispute bond multiplier,
+        // so each side's fee is taken from the losing side's actual bonds.
+        let total_disputed: u128 = assertion.disputers.iter().map(|(_, bond)| bond.0).sum();
+        let burned_bond_percentage = self
+            .get_burned_bond_percentage(assertion.currency.clone())
+            .0;
+        if resolution {
+            let oracle_fee = (burned_bond_percentage * total_disputed) / SCALE;
+            let payout = assertion.bond.0 + total_disputed - oracle_fee;
+            (vec![(assertion.asserter.clone(), payout)], true, oracle_fee)
+        } else {
+            let oracle_fee = (burned_bond_percentage * assertion.bond.0) / SCALE;
+            let reward_pool = assertion.bond.0 - oracle_fee;
+            let mut payouts: Vec<(AccountId, u128)> = assertion
+                .disputers
+                .iter()
+                .map(|(disputer, bond)| {
+                    let share = reward_pool.saturating_mul(bond.0) / total_disputed;
+                    (disputer.clone(), bond.0 + share)
+                })
+                .collect();
+            let distributed: u128 = payouts.iter().map(|(_, amount)| amount).sum();
+            payouts[0].1 += total_disputed + reward_pool - distributed;
+            (payouts, true, oracle_fee)
+        }
+    }
+
+    /// Default liveness for a domain: its configured value, else the global default.
+    fn resolve_default_liveness(&self, domain_id: &Bytes32) -> u64 {
+        self.domain_configs
+            .get(domain_id)
+            .map(|config| config.default_liveness_ns.0)
+            .unwrap_or(self.default_liveness_ns)
+    }
+
+    /// Dispute bond required against an assertion bond at the given multiplier.
+    fn required_dispute_bond(assertion_bond: u128, multiplier_bps: u32) -> u128 {
+        assertion_bond.saturating_mul(multiplier_bps as u128) / BASIS_POINTS_DENOMINATOR
+    }
+
+    fn assert_dispute_grace_elapsed(&self, assertion_id: &Bytes32) {
+        if let Some(extended_expiration) = self.extended_expirations.get(assertion_id) {
+            require!(
+                self.get_current_time() >= *extended_expiration,
+                "Dispute grace period not elapsed"
+            );
+        }
+    }
+
+    fn increase_total_bonded(&mut self, currency: &AccountId, amount: u128) {
+        let total = self.total_bonded.get(currency).copied().unwrap_or(0);
+        self.total_bonded
+            .insert(currency.clone(), total.saturating_add(amount));
+    }
+
+    fn decrease_total_bonded(&mut self, currency: &AccountId, amount: u128) {
+        let total = self.total_bonded.get(currency).copied().unwrap_or(0);
+        self.total_bonded
+            .insert(currency.clone(), total.saturating_sub(amount));
+    }
+
+    /// Best-effort transfer of a fee: the burn share to the burn sink, the rest to the
+    /// owner treasury.
+    fn route_oracle_fee(&self, currency: &AccountId, fee: u128) {
+        let (burn_amount, treasury_amount) = self.split_oracle_fee(fee);
+        if burn_amount > 0 {
+            if let Some(burn_sink) = self.burn_sink.clone() {
+                let _ = self.transfer_tokens_registered(currency.clone(), burn_sink, burn_amount);
+            }
         }
+        if treasury_amount > 0 {
+            let _ = self.transfer_tokens_registered(
+                currency.clone(),
+                self.owner.clone(),
+                treasury_amount,
+            );
+        }
+    }
+
+    /// Split an oracle fee into (burn share, treasury share) according to `fee_burn_bps`.
+    fn split_oracle_fee(&self, oracle_fee: u128) -> (u128, u128) {
+        let burn_amount =
+            oracle_fee.saturating_mul(self.fee_burn_bps as u128) / BASIS_POINTS_DENOMINATOR;
+        (burn_amount, oracle_fee - burn_amount)
     }
 
+    /// Callback after the joint recipient payout completes.
+    /// Successful payouts are cleared; failed ones stay pending for `retry_settlement_payout`.
+    #[allow(deprecated)]
     #[private]
-    pub fn on_settlement_payout_complete(
-        &mut self,
-        assertion_id: Bytes32,
-        #[callback_result] payout_result: Result<(), PromiseError>,
-    ) {
+    pub fn on_settlement_payout_complete(&mut self, assertion_id: Bytes32) {
         let assertion = self
             .assertions
             .get(&assertion_id)
@@ -910,59 +2646,215 @@ impl NestOptimisticOracle {
             "Settlement payout not in-flight"
         );
 
-        match payout_result {
-            Ok(()) => {
-                let resolution = assertion.pending_settlement_resolution;
-                let (bond_recipient, _, disputed, _) =
-                    self.compute_settlement_payout(&assertion, resolution);
-
-                let assertion_mut = self.assertions.get_mut(&assertion_id).unwrap();
-                assertion_mut.settlement_in_flight = false;
-                assertion_mut.settlement_pending = false;
-                assertion_mut.settled = true;
-                assertion_mut.settlement_resolution = resolution;
-
-                if !assertion.escalation_manager_settings.discard_oracle {
-                    if let Some(ref callback_recipient) = assertion.callback_recipient {
-                        let _ = self.call_assertion_resolved_callback(
-                            callback_recipient.clone(),
-                            assertion_id,
-                            resolution,
-                        );
-                    }
-                }
+        let payouts = self
+            .pending_payouts
+            .get(&assertion_id)
+            .expect("No pending payouts")
+            .clone();
+        require!(
+            env::promise_results_count() == payouts.len() as u64,
+            "Unexpected number of payout results"
+        );
+        let attempted_amount: u128 = payouts.iter().map(|(_, amount)| amount.0).sum();
+        let failed_payouts: Vec<(AccountId, U128)> = payouts
+            .into_iter()
+            .enumerate()
+            .filter(|(index, _)| {
+                !matches!(
+                    env::promise_result(*index as u64),
+                    PromiseResult::Successful(_)
+                )
+            })
+            .map(|(_, payout)| payout)
+            .collect();
 
-                Event::AssertionSettled {
-                    assertion_id: &assertion_id,
-                    bond_recipient: &bond_recipient,
-                    disputed,
-                    settlement_resolution: resolution,
-                    settle_caller: &env::predecessor_account_id(),
-                }
-                .emit();
-            }
-            Err(_) => {
-                let resolution = assertion.pending_settlement_resolution;
-                let (payout_recipient, payout_amount, disputed, _) =
-                    self.compute_settlement_payout(&assertion, resolution);
-                let assertion_mut = self.assertions.get_mut(&assertion_id).unwrap();
-                assertion_mut.settlement_in_flight = false;
+        let failed_amount: u128 = failed_payouts.iter().map(|(_, amount)| amount.0).sum();
+        self.decrease_total_bonded(&assertion.currency, attempted_amount - failed_amount);
 
-                Event::AssertionSettlementPayoutFailed {
-                    assertion_id: &assertion_id,
-                    disputed,
-                    settlement_resolution: resolution,
-                    payout_recipient: &payout_recipient,
-                    payout_amount: &U128(payout_amount),
+        let resolution = assertion.pending_settlement_resolution;
+        let (settlement_payouts, disputed, _) =
+            self.compute_settlement_payout(&assertion, resolution);
+
+        if failed_payouts.is_empty() {
+            self.pending_payouts.remove(&assertion_id);
+
+            let now = self.get_current_time();
+            let assertion_mut = self.assertions.get_mut(&assertion_id).unwrap();
+            assertion_mut.settlement_in_flight = false;
+            assertion_mut.settlement_pending = false;
+            assertion_mut.settled = true;
+            assertion_mut.settlement_resolution = resolution;
+            assertion_mut.settlement_time_ns = Some(now);
+
+            if !assertion.escalation_manager_settings.discard_oracle {
+                if let Some(ref callback_recipient) = assertion.callback_recipient {
+                    let _ = self.call_assertion_resolved_callback(
+                        callback_recipient.clone(),
+                        assertion_id,
+                        resolution,
+                    );
                 }
-                .emit();
+            }
+            // Managers are told of every settlement so they can drop per-assertion state
+            if let Some(ref escalation_manager) =
+                assertion.escalation_manager_settings.escalation_manager
+            {
+                let _ = Self::call_escalation_manager_resolved_callback(
+                    escalation_manager.clone(),
+                    assertion_id,
+                    resolution,
+                );
+            }
 
-                env::log_str(&format!(
-                    "Settlement payout failed for assertion {:?}; remains pending for retry",
-                    hex::encode(assertion_id)
-                ));
+            Event::AssertionSettled {
+                assertion_id: &assertion_id,
+                bond_recipient: &settlement_payouts[0].0,
+                disputed,
+                settlement_resolution: resolution,
+                settle_caller: &env::predecessor_account_id(),
+            }
+            .emit();
+        } else {
+            let assertion_mut = self.assertions.get_mut(&assertion_id).unwrap();
+            assertion_mut.settlement_in_flight = false;
+
+            Event::AssertionSettlementPayoutFailed {
+                assertion_id: &assertion_id,
+                disputed,
+                settlement_resolution: resolution,
+                payout_recipient: &failed_payouts[0].0,
+                payout_amount: &U128(failed_amount),
             }
+            .emit();
+            self.pending_payouts.insert(assertion_id, failed_payouts);
+            self.retryable_settlements.insert(assertion_id);
+
+            env::log_str(&format!(
+                "Settlement payout failed for assertion {:?}; remains pending for retry",
+                hex::encode(assertion_id)
+            ));
+        }
+    }
+
+    /// Remove a settled assertion's data and refund the storage deposit charged at creation
+    /// to the account that paid it (its asserting caller), capped at the storage actually
+    /// freed. Only the result and resolved price are kept, so `get_assertion_result` and
+    /// `get_assertion_numeric_result` keep answering and the id cannot be asserted again.
+    /// Requires the resolved callback, if any, to have been delivered.
+    /// Returns the refunded amount in yoctoNEAR.
+    pub fn prune_settled_assertion(&mut self, assertion_id: Bytes32) -> U128 {
+        let assertion = self
+            .assertions
+            .get(&assertion_id)
+            .expect("Assertion does not exist");
+        require!(assertion.settled, "Assertion not settled");
+        require!(
+            assertion.callback_delivered
+                || assertion.callback_recipient.is_none()
+                || assertion.escalation_manager_settings.discard_oracle,
+            "Resolved callback not delivered"
+        );
+        let storage_payer = assertion
+            .escalation_manager_settings
+            .asserting_caller
+            .clone();
+        let pruned = PrunedAssertion {
+            result: self.get_assertion_result(assertion_id),
+            resolved_price: self.get_assertion_numeric_result(assertion_id),
+        };
+
+        let initial_storage_usage = env::storage_usage();
+
+        self.assertions.remove(&assertion_id);
+        self.admin_resolved_assertions.remove(&assertion_id);
+        self.pruned_assertions.insert(assertion_id, pruned);
+        self.assertion_storage_bytes.remove(&assertion_id);
+        let deposit = self
+            .assertion_storage_deposits
+            .remove(&assertion_id)
+            .unwrap_or(NearToken::from_yoctonear(0));
+        self.dispute_escalation_times.remove(&assertion_id);
+        self.extended_expirations.remove(&assertion_id);
+        self.metadata_uris.remove(&assertion_id);
+        if let Some(request_id) = self.dispute_requests.remove(&assertion_id) {
+            self.request_to_assertion.remove(&request_id);
+        }
+        self.assertions.flush();
+        self.assertion_storage_bytes.flush();
+        self.assertion_storage_deposits.flush();
+        self.dispute_escalation_times.flush();
+        self.extended_expirations.flush();
+        self.metadata_uris.flush();
+        self.dispute_requests.flush();
+        self.request_to_assertion.flush();
+        self.pruned_assertions.flush();
+
+        let freed_bytes = initial_storage_usage.saturating_sub(env::storage_usage());
+        let refund = env::storage_byte_cost()
+            .saturating_mul(freed_bytes as u128)
+            .min(deposit);
+        if !refund.is_zero() {
+            let _ = Promise::new(storage_payer).transfer(refund);
+        }
+
+        U128(refund.as_yoctonear())
+    }
+
+    /// Prepay NEAR covering the storage of assertions created by `account_id` (the caller if
+    /// omitted). Each assertion draws its storage cost from this balance when it is created.
+    /// Returns the new balance in yoctoNEAR.
+    #[payable]
+    pub fn deposit_assertion_storage(&mut self, account_id: Option<AccountId>) -> U128 {
+        let account_id = account_id.unwrap_or_else(env::predecessor_account_id);
+        let deposit = env::attached_deposit();
+        require!(!deposit.is_zero(), "Requires attached deposit");
+        let balance = self
+            .get_assertion_storage_balance(account_id.clone())
+            .0
+            .saturating_add(deposit.as_yoctonear());
+        self.assertion_storage_balances
+            .insert(account_id, NearToken::from_yoctonear(balance));
+        U128(balance)
+    }
+
+    /// Withdraw unused assertion storage balance (all of it if `amount` is omitted).
+    /// Returns the remaining balance in yoctoNEAR.
+    pub fn withdraw_assertion_storage(&mut self, amount: Option<U128>) -> U128 {
+        let account_id = env::predecessor_account_id();
+        let balance = self.get_assertion_storage_balance(account_id.clone()).0;
+        let amount = amount.map(|a| a.0).unwrap_or(balance);
+        require!(
+            amount <= balance,
+            "Withdrawal exceeds assertion storage balance"
+        );
+        let remaining = balance - amount;
+        if remaining == 0 {
+            self.assertion_storage_balances.remove(&account_id);
+        } else {
+            self.assertion_storage_balances
+                .insert(account_id.clone(), NearToken::from_yoctonear(remaining));
+        }
+        if amount > 0 {
+            let _ = Promise::new(account_id).transfer(NearToken::from_yoctonear(amount));
         }
+        U128(remaining)
+    }
+
+    /// Unused assertion storage balance of an account, in yoctoNEAR
+    pub fn get_assertion_storage_balance(&self, account_id: AccountId) -> U128 {
+        U128(
+            self.assertion_storage_balances
+                .get(&account_id)
+                .map(|balance| balance.as_yoctonear())
+                .unwrap_or(0),
+        )
+    }
+
+    /// Storage bytes recorded for an assertion at creation time
+    pub fn get_assertion_storage_bytes(&self, assertion_id: Bytes32) -> Option<U64> {
+        self.assertion_storage_bytes
+            .get(&assertion_id)
+            .map(|bytes| U64(*bytes))
     }
 
     // ========================================================================
@@ -984,6 +2876,78 @@ impl NestOptimisticOracle {
         )
     }
 
+    /// Transfer NEP-141 tokens, first registering the recipient with the token via
+    /// `storage_deposit` when `payout_storage_deposit` is configured.
+    /// Registration is `registration_only`, so the deposit is refunded if already registered.
+    fn transfer_tokens_registered(
+        &self,
+        token: AccountId,
+        recipient: AccountId,
+        amount: u128,
+    ) -> Promise {
+        let Some(deposit) = self.payout_storage_deposit else {
+            return self.transfer_tokens(token, recipient, amount);
+        };
+        Promise::new(token.clone())
+            .function_call(
+                "storage_deposit".to_string(),
+                near_sdk::serde_json::json!({
+                    "account_id": recipient,
+                    "registration_only": true,
+                })
+                .to_string()
+                .into_bytes(),
+                deposit,
+                GAS_FOR_STORAGE_DEPOSIT,
+            )
+            .then(self.transfer_tokens(token, recipient, amount))
+    }
+
+    /// Re-send the resolved callback for a settled assertion whose `callback_recipient`
+    /// has not accepted it yet, e.g. because the recipient contract failed or was not deployed.
+    /// Callable by anyone.
+    pub fn retry_resolved_callback(&mut self, assertion_id: Bytes32) -> Promise {
+        let assertion = self
+            .assertions
+            .get(&assertion_id)
+            .expect("Assertion does not exist");
+        require!(assertion.settled, "Assertion not settled");
+        require!(
+            !assertion.escalation_manager_settings.discard_oracle,
+            "Oracle result discarded; no callback to deliver"
+        );
+        require!(!assertion.callback_delivered, "Callback already delivered");
+        let callback_recipient = assertion
+            .callback_recipient
+            .clone()
+            .expect("Assertion has no callback recipient");
+
+        self.call_assertion_resolved_callback(
+            callback_recipient,
+            assertion_id,
+            assertion.settlement_resolution,
+        )
+    }
+
+    /// Callback after `assertion_resolved_callback` completes on the recipient.
+    /// Records delivery so `retry_resolved_callback` is only needed after a failure.
+    #[allow(deprecated)]
+    #[private]
+    pub fn on_resolved_callback_complete(&mut self, assertion_id: Bytes32) {
+        let delivered = matches!(env::promise_result(0), PromiseResult::Successful(_));
+        let Some(assertion) = self.assertions.get_mut(&assertion_id) else {
+            return;
+        };
+        if delivered {
+            assertion.callback_delivered = true;
+        } else {
+            env::log_str(&format!(
+                "Resolved callback failed for assertion {:?}; call retry_resolved_callback",
+                hex::encode(assertion_id)
+            ));
+        }
+    }
+
     /// Call assertion resolved callback on recipient contract
     fn call_assertion_resolved_callback(
         &self,
@@ -994,10 +2958,43 @@ impl NestOptimisticOracle {
         // Convert assertion_id to hex string for callback
         let assertion_id_hex = hex::encode(assertion_id);
 
-        Promise::new(recipient).function_call(
+        Promise::new(recipient)
+            .function_call(
+                "assertion_resolved_callback".to_string(),
+                near_sdk::serde_json::json!({
+                    "assertion_id": assertion_id_hex,
+                    "asserted_truthfully": asserted_truthfully,
+                })
+                .to_string()
+                .into_bytes(),
+                NearToken::from_yoctonear(0),
+                GAS_FOR_CALLBACK,
+            )
+            .then(
+                Promise::new(env::current_account_id()).function_call(
+                    "on_resolved_callback_complete".to_string(),
+                    near_sdk::serde_json::json!({
+                        "assertion_id": assertion_id,
+                    })
+                    .to_string()
+                    .into_bytes(),
+                    NearToken::from_yoctonear(0),
+                    GAS_FOR_RESOLVED_CALLBACK_COMPLETE,
+                ),
+            )
+    }
+
+    /// Notify an escalation manager that an assertion it manages has settled.
+    /// The oracle does not track delivery; managers only use it for bookkeeping.
+    fn call_escalation_manager_resolved_callback(
+        escalation_manager: AccountId,
+        assertion_id: Bytes32,
+        asserted_truthfully: bool,
+    ) -> Promise {
+        Promise::new(escalation_manager).function_call(
             "assertion_resolved_callback".to_string(),
             near_sdk::serde_json::json!({
-                "assertion_id": assertion_id_hex,
+                "assertion_id": hex::encode(assertion_id),
                 "asserted_truthfully": asserted_truthfully,
             })
             .to_string()
@@ -1045,6 +3042,14 @@ impl NestOptimisticOracle {
             .expect("Hash should be 32 bytes")
     }
 
+    /// Fallback resolution and escalation time for a dispute whose DVM deadline has passed
+    fn due_dvm_fallback(&self, assertion_id: &Bytes32) -> Option<(bool, u64)> {
+        let resolution = self.dvm_fallback_resolution?;
+        let escalated_at = *self.dispute_escalation_times.get(assertion_id)?;
+        let deadline = escalated_at.saturating_add(self.dvm_resolution_deadline_ns);
+        (self.get_current_time() >= deadline).then_some((resolution, escalated_at))
+    }
+
     fn assert_owner(&self) {
         require!(
             env::predecessor_account_id() == self.owner,
@@ -1052,6 +3057,31 @@ impl NestOptimisticOracle {
         );
     }
 
+    /// Checks the caller is the owner and records the call in the admin action log,
+    /// overwriting the oldest entry once the log is full.
+    fn assert_admin(&mut self, method: &str) {
+        self.assert_owner();
+
+        let index = self.admin_action_count;
+        let caller = env::predecessor_account_id();
+        self.admin_action_log.insert(
+            index % MAX_ADMIN_ACTIONS,
+            AdminAction {
+                method: method.to_string(),
+                timestamp: self.get_current_time(),
+                caller: caller.clone(),
+            },
+        );
+        self.admin_action_count += 1;
+
+        Event::AdminActionRecorded {
+            index,
+            method,
+            caller: &caller,
+        }
+        .emit();
+    }
+
     /// Get current time in nanoseconds
     fn get_current_time(&self) -> u64 {
         env::block_timestamp()
@@ -1087,6 +3117,86 @@ mod tests {
         builder
     }
 
+    fn set_context_with_results(
+        predecessor: AccountId,
+        current_account: AccountId,
+        block_timestamp: u64,
+        promise_results: Vec<PromiseResult>,
+    ) {
+        testing_env!(
+            get_context_with_time(predecessor, current_account, block_timestamp).build(),
+            near_sdk::test_vm_config(),
+            near_sdk::RuntimeFeesConfig::test(),
+            Default::default(),
+            promise_results
+        );
+    }
+
+    /// Prepays 1 NEAR of assertion storage for `account`, calling as it at `time`.
+    fn fund_assertion_storage(contract: &mut NestOptimisticOracle, account: AccountId, time: u64) {
+        let oracle: AccountId = "oracle.near".parse().unwrap();
+        let mut context = get_context_with_time(account, oracle, time);
+        context.attached_deposit(NearToken::from_near(1));
+        testing_env!(context.build());
+        contract.deposit_assertion_storage(None);
+    }
+
+    /// Creates an oracle with a whitelisted currency and an undisputed assertion
+    /// (bond 10, expiring at 100). Returns the contract and the assertion id.
+    fn setup_assertion(voting: Option<AccountId>) -> (NestOptimisticOracle, Bytes32) {
+        let owner: AccountId = "owner.near".parse().unwrap();
+        let oracle: AccountId = "oracle.near".parse().unwrap();
+        let asserter: AccountId = "asserter.near".parse().unwrap();
+        let caller: AccountId = "caller.near".parse().unwrap();
+        let currency: AccountId = "usdc.near".parse().unwrap();
+
+        testing_env!(get_context_with_time(owner.clone(), oracle, 1).build());
+        let mut contract = NestOptimisticOracle::new(owner, currency.clone(), None, None, voting);
+        contract.whitelist_currency(currency.clone(), U128(1));
+
+        let assertion_id = contract.internal_assert_truth(
+            [7u8; 32],
+            asserter,
+            None,
+            None,
+            false,
+            false,
+            false,
+            Some(100),
+            Some(0),
+            currency,
+            10,
+            None,
+            None,
+            None,
+            None,
+            caller,
+        );
+
+        (contract, assertion_id)
+    }
+
+    /// Like `setup_assertion`, with the assertion disputed by `disputer.near` at time 10.
+    fn setup_disputed_assertion(voting: Option<AccountId>) -> (NestOptimisticOracle, Bytes32) {
+        let oracle: AccountId = "oracle.near".parse().unwrap();
+        let disputer: AccountId = "disputer.near".parse().unwrap();
+        let caller: AccountId = "caller.near".parse().unwrap();
+        let currency: AccountId = "usdc.near".parse().unwrap();
+        let (mut contract, assertion_id) = setup_assertion(voting);
+
+        testing_env!(get_context_with_time(caller, oracle, 10).build());
+        contract.internal_dispute_assertion(
+            assertion_id,
+            disputer.clone(),
+            currency,
+            10,
+            disputer,
+            None,
+        );
+
+        (contract, assertion_id)
+    }
+
     #[test]
     fn test_new() {
         let owner: AccountId = "owner.near".parse().unwrap();
@@ -1189,6 +3299,9 @@ mod tests {
             asserter.clone(),
             None,
             None,
+            false,
+            false,
+            false,
             Some(1),
             Some(0),
             currency.clone(),
@@ -1196,6 +3309,7 @@ mod tests {
             None,
             None,
             None,
+            None,
             caller,
         );
 
@@ -1207,8 +3321,13 @@ mod tests {
         assert!(pending.settlement_pending);
         assert!(pending.settlement_in_flight);
 
-        testing_env!(get_context_with_time(oracle.clone(), oracle.clone(), 6).build());
-        contract.on_settlement_payout_complete(assertion_id, Ok(()));
+        set_context_with_results(
+            oracle.clone(),
+            oracle.clone(),
+            6,
+            vec![PromiseResult::Successful(vec![])],
+        );
+        contract.on_settlement_payout_complete(assertion_id);
 
         let finalized = contract.get_assertion(assertion_id).unwrap();
         assert!(finalized.settled);
@@ -1235,6 +3354,9 @@ mod tests {
             asserter.clone(),
             None,
             None,
+            false,
+            false,
+            false,
             Some(1),
             Some(0),
             currency.clone(),
@@ -1242,14 +3364,20 @@ mod tests {
             None,
             None,
             None,
+            None,
             caller,
         );
 
         testing_env!(get_context_with_time(asserter.clone(), oracle.clone(), 5).build());
         contract.settle_assertion(assertion_id);
 
-        testing_env!(get_context_with_time(oracle.clone(), oracle.clone(), 6).build());
-        contract.on_settlement_payout_complete(assertion_id, Err(PromiseError::Failed));
+        set_context_with_results(
+            oracle.clone(),
+            oracle.clone(),
+            6,
+            vec![PromiseResult::Failed],
+        );
+        contract.on_settlement_payout_complete(assertion_id);
 
         let failed = contract.get_assertion(assertion_id).unwrap();
         assert!(!failed.settled);
@@ -1264,6 +3392,162 @@ mod tests {
         assert!(retried.settlement_in_flight);
     }
 
+    #[test]
+    fn test_total_bonded_tracks_assert_dispute_and_settlement() {
+        let owner: AccountId = "owner.near".parse().unwrap();
+        let oracle: AccountId = "oracle.near".parse().unwrap();
+        let caller: AccountId = "caller.near".parse().unwrap();
+        let disputer: AccountId = "disputer.near".parse().unwrap();
+        let currency: AccountId = "usdc.near".parse().unwrap();
+
+        let (mut contract, assertion_id) = setup_assertion(None);
+        assert_eq!(contract.get_total_bonded(currency.clone()).0, 10);
+
+        testing_env!(get_context_with_time(caller, oracle.clone(), 10).build());
+        contract.internal_dispute_assertion(
+            assertion_id,
+            disputer.clone(),
+            currency.clone(),
+            10,
+            disputer,
+            None,
+        );
+        assert_eq!(contract.get_total_bonded(currency.clone()).0, 20);
+
+        // Asserter wins: 50% of the dispute bond leaves as the oracle fee.
+        testing_env!(get_context_with_time(owner, oracle.clone(), 20).build());
+        contract.resolve_disputed_assertion(assertion_id, true, "no DVM".to_string());
+        assert_eq!(contract.get_total_bonded(currency.clone()).0, 15);
+
+        // A failed payout keeps the liability outstanding.
+        set_context_with_results(
+            oracle.clone(),
+            oracle.clone(),
+            21,
+            vec![PromiseResult::Failed],
+        );
+        contract.on_settlement_payout_complete(assertion_id);
+        assert_eq!(contract.get_total_bonded(currency.clone()).0, 15);
+
+        testing_env!(get_context_with_time(oracle.clone(), oracle.clone(), 22).build());
+        contract.retry_settlement_payout(assertion_id);
+        set_context_with_results(
+            oracle.clone(),
+            oracle,
+            23,
+            vec![PromiseResult::Successful(vec![])],
+        );
+        contract.on_settlement_payout_complete(assertion_id);
+        assert_eq!(contract.get_total_bonded(currency).0, 0);
+    }
+
+    #[test]
+    fn test_set_payout_storage_deposit() {
+        let owner: AccountId = "owner.near".parse().unwrap();
+        let oracle: AccountId = "oracle.near".parse().unwrap();
+        let (mut contract, _) = setup_assertion(None);
+        assert_eq!(contract.get_payout_storage_deposit(), None);
+
+        testing_env!(get_context_with_time(owner, oracle, 2).build());
+        contract.set_payout_storage_deposit(Some(NearToken::from_micronear(1_250)));
+        assert_eq!(
+            contract.get_payout_storage_deposit(),
+            Some(NearToken::from_micronear(1_250))
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Storage deposit exceeds 0.1 NEAR")]
+    fn test_set_payout_storage_deposit_rejects_large_deposit() {
+        let owner: AccountId = "owner.near".parse().unwrap();
+        let oracle: AccountId = "oracle.near".parse().unwrap();
+        let (mut contract, _) = setup_assertion(None);
+
+        testing_env!(get_context_with_time(owner, oracle, 2).build());
+        contract.set_payout_storage_deposit(Some(NearToken::from_near(1)));
+    }
+
+    #[test]
+    fn test_set_dvm_gas_overrides() {
+        let owner: AccountId = "owner.near".parse().unwrap();
+        let oracle: AccountId = "oracle.near".parse().unwrap();
+        let (mut contract, _) = setup_assertion(None);
+        assert_eq!(
+            contract.get_dvm_gas(),
+            (
+                GAS_FOR_DVM_REQUEST,
+                GAS_FOR_DVM_GET_PRICE,
+                GAS_FOR_DVM_PRICE_CALLBACK
+            )
+        );
+
+        testing_env!(get_context_with_time(owner, oracle, 2).build());
+        contract.set_dvm_gas(Some(Gas::from_tgas(60)), None, Some(Gas::from_tgas(200)));
+        assert_eq!(
+            contract.get_dvm_gas(),
+            (
+                Gas::from_tgas(60),
+                GAS_FOR_DVM_GET_PRICE,
+                Gas::from_tgas(200)
+            )
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "DVM gas must be positive and at most 250 TGas")]
+    fn test_set_dvm_gas_rejects_excessive_gas() {
+        let owner: AccountId = "owner.near".parse().unwrap();
+        let oracle: AccountId = "oracle.near".parse().unwrap();
+        let (mut contract, _) = setup_assertion(None);
+
+        testing_env!(get_context_with_time(owner, oracle, 2).build());
+        contract.set_dvm_gas(None, Some(Gas::from_tgas(300)), None);
+    }
+
+    #[test]
+    fn test_retryable_settlements_index() {
+        let oracle: AccountId = "oracle.near".parse().unwrap();
+        let keeper: AccountId = "keeper.near".parse().unwrap();
+
+        let (mut contract, assertion_id) = setup_assertion(None);
+        testing_env!(get_context_with_time(keeper.clone(), oracle.clone(), 200).build());
+        contract.settle_assertion(assertion_id);
+        assert!(contract.get_retryable_settlements(0, 10).is_empty());
+
+        set_context_with_results(
+            oracle.clone(),
+            oracle.clone(),
+            201,
+            vec![PromiseResult::Failed],
+        );
+        contract.on_settlement_payout_complete(assertion_id);
+        assert_eq!(
+            contract.get_retryable_settlements(0, 10),
+            vec![assertion_id]
+        );
+        assert!(contract.get_retryable_settlements(1, 10).is_empty());
+
+        testing_env!(get_context_with_time(keeper, oracle.clone(), 202).build());
+        assert_eq!(contract.retry_all_settlements(10), 1);
+        assert!(
+            contract
+                .get_assertion(assertion_id)
+                .unwrap()
+                .settlement_in_flight
+        );
+        assert!(contract.get_retryable_settlements(0, 10).is_empty());
+
+        set_context_with_results(
+            oracle.clone(),
+            oracle,
+            203,
+            vec![PromiseResult::Successful(vec![])],
+        );
+        contract.on_settlement_payout_complete(assertion_id);
+        assert!(contract.get_assertion(assertion_id).unwrap().settled);
+        assert!(contract.get_retryable_settlements(0, 10).is_empty());
+    }
+
     #[test]
     fn test_dispute_requires_exact_bond_amount() {
         let owner: AccountId = "owner.near".parse().unwrap();
@@ -1283,6 +3567,9 @@ mod tests {
             asserter,
             None,
             None,
+            false,
+            false,
+            false,
             Some(100),
             Some(0),
             currency.clone(),
@@ -1290,6 +3577,7 @@ mod tests {
             None,
             None,
             None,
+            None,
             caller.clone(),
         );
 
@@ -1300,6 +3588,7 @@ mod tests {
             currency,
             10,
             disputer.clone(),
+            None,
         );
 
         let assertion = contract.get_assertion(assertion_id).unwrap();
@@ -1307,7 +3596,7 @@ mod tests {
     }
 
     #[test]
-    #[should_panic(expected = "Dispute bond must match assertion bond")]
+    #[should_panic(expected = "Dispute bond must match required dispute bond")]
     fn test_dispute_rejects_overpayment_bond_amount() {
         let owner: AccountId = "owner.near".parse().unwrap();
         let oracle: AccountId = "oracle.near".parse().unwrap();
@@ -1326,6 +3615,9 @@ mod tests {
             asserter,
             None,
             None,
+            false,
+            false,
+            false,
             Some(100),
             Some(0),
             currency.clone(),
@@ -1333,10 +3625,2444 @@ mod tests {
             None,
             None,
             None,
+            None,
             caller.clone(),
         );
 
         testing_env!(get_context_with_time(caller, oracle, 10).build());
-        contract.internal_dispute_assertion(assertion_id, disputer.clone(), currency, 11, disputer);
+        contract.internal_dispute_assertion(
+            assertion_id,
+            disputer.clone(),
+            currency,
+            11,
+            disputer,
+            None,
+        );
+    }
+
+    #[test]
+    fn test_dispute_request_mapping_is_bidirectional() {
+        let owner: AccountId = "owner.near".parse().unwrap();
+        let oracle: AccountId = "oracle.near".parse().unwrap();
+        let asserter: AccountId = "asserter.near".parse().unwrap();
+        let disputer: AccountId = "disputer.near".parse().unwrap();
+        let caller: AccountId = "caller.near".parse().unwrap();
+        let currency: AccountId = "usdc.near".parse().unwrap();
+        let voting: AccountId = "voting.near".parse().unwrap();
+
+        testing_env!(get_context_with_time(owner.clone(), oracle.clone(), 1).build());
+        let mut contract =
+            NestOptimisticOracle::new(owner.clone(), currency.clone(), None, None, Some(voting));
+        contract.whitelist_currency(currency.clone(), U128(1));
+
+        let assertion_id = contract.internal_assert_truth(
+            [5u8; 32],
+            asserter,
+            None,
+            None,
+            false,
+            false,
+            false,
+            Some(100),
+            Some(0),
+            currency.clone(),
+            10,
+            None,
+            None,
+            None,
+            None,
+            caller.clone(),
+        );
+
+        testing_env!(get_context_with_time(caller, oracle.clone(), 10).build());
+        contract.internal_dispute_assertion(
+            assertion_id,
+            disputer.clone(),
+            currency,
+            10,
+            disputer,
+            None,
+        );
+
+        let request_id = [9u8; 32];
+        testing_env!(get_context_with_time(oracle.clone(), oracle, 11).build());
+        contract.on_dvm_request_complete(assertion_id, Ok(request_id));
+
+        assert_eq!(contract.get_dispute_request(assertion_id), Some(request_id));
+        assert_eq!(
+            contract.get_assertion_for_request(request_id),
+            Some(assertion_id)
+        );
+        assert_eq!(contract.get_assertion_for_request([8u8; 32]), None);
+    }
+
+    #[test]
+    fn test_last_second_dispute_extends_effective_expiration() {
+        let owner: AccountId = "owner.near".parse().unwrap();
+        let oracle: AccountId = "oracle.near".parse().unwrap();
+        let disputer: AccountId = "disputer.near".parse().unwrap();
+        let currency: AccountId = "usdc.near".parse().unwrap();
+
+        // Expires at 100; grace of 50 covers disputes from 50 onwards.
+        let (mut contract, assertion_id) = setup_assertion(None);
+        testing_env!(get_context_with_time(owner.clone(), oracle.clone(), 2).build());
+        contract.set_dispute_grace_period(U64(50));
+
+        testing_env!(get_context_with_time(disputer.clone(), oracle.clone(), 99).build());
+        contract.internal_dispute_assertion(
+            assertion_id,
+            disputer.clone(),
+            currency,
+            10,
+            disputer,
+            None,
+        );
+        assert_eq!(contract.get_effective_expiration(assertion_id).0, 150);
+        assert_eq!(
+            contract
+                .get_assertion(assertion_id)
+                .unwrap()
+                .expiration_time_ns,
+            100
+        );
+
+        testing_env!(get_context_with_time(owner, oracle, 150).build());
+        contract.resolve_disputed_assertion(assertion_id, true, "grace elapsed".to_string());
+        assert!(
+            contract
+                .get_assertion(assertion_id)
+                .unwrap()
+                .settlement_pending
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Dispute grace period not elapsed")]
+    fn test_resolution_blocked_during_dispute_grace() {
+        let owner: AccountId = "owner.near".parse().unwrap();
+        let oracle: AccountId = "oracle.near".parse().unwrap();
+        let disputer: AccountId = "disputer.near".parse().unwrap();
+        let currency: AccountId = "usdc.near".parse().unwrap();
+
+        let (mut contract, assertion_id) = setup_assertion(None);
+        testing_env!(get_context_with_time(owner.clone(), oracle.clone(), 2).build());
+        contract.set_dispute_grace_period(U64(50));
+
+        testing_env!(get_context_with_time(disputer.clone(), oracle.clone(), 99).build());
+        contract.internal_dispute_assertion(
+            assertion_id,
+            disputer.clone(),
+            currency,
+            10,
+            disputer,
+            None,
+        );
+
+        testing_env!(get_context_with_time(owner, oracle, 120).build());
+        contract.resolve_disputed_assertion(assertion_id, true, "too early".to_string());
+    }
+
+    #[test]
+    fn test_early_dispute_keeps_original_expiration() {
+        let owner: AccountId = "owner.near".parse().unwrap();
+        let oracle: AccountId = "oracle.near".parse().unwrap();
+        let disputer: AccountId = "disputer.near".parse().unwrap();
+        let currency: AccountId = "usdc.near".parse().unwrap();
+
+        let (mut contract, assertion_id) = setup_assertion(None);
+        testing_env!(get_context_with_time(owner, oracle.clone(), 2).build());
+        contract.set_dispute_grace_period(U64(50));
+
+        testing_env!(get_context_with_time(disputer.clone(), oracle, 10).build());
+        contract.internal_dispute_assertion(
+            assertion_id,
+            disputer.clone(),
+            currency,
+            10,
+            disputer,
+            None,
+        );
+        assert_eq!(contract.get_effective_expiration(assertion_id).0, 100);
+    }
+
+    #[test]
+    fn test_admin_override_without_dvm_request() {
+        let owner: AccountId = "owner.near".parse().unwrap();
+        let oracle: AccountId = "oracle.near".parse().unwrap();
+        let (mut contract, assertion_id) = setup_disputed_assertion(None);
+
+        testing_env!(get_context_with_time(owner, oracle, 20).build());
+        contract.resolve_disputed_assertion(assertion_id, false, "DVM unavailable".to_string());
+
+        let assertion = contract.get_assertion(assertion_id).unwrap();
+        assert!(assertion.settlement_pending);
+        assert!(!assertion.pending_settlement_resolution);
+    }
+
+    #[test]
+    fn test_admin_override_allowed_after_stale_dvm_request() {
+        let owner: AccountId = "owner.near".parse().unwrap();
+        let oracle: AccountId = "oracle.near".parse().unwrap();
+        let voting: AccountId = "voting.near".parse().unwrap();
+        let (mut contract, assertion_id) = setup_disputed_assertion(Some(voting.clone()));
+
+        testing_env!(get_context_with_time(oracle.clone(), oracle.clone(), 11).build());
+        contract.on_dvm_request_complete(assertion_id, Ok([1u8; 32]));
+
+        testing_env!(get_context_with_time(
+            owner,
+            oracle.clone(),
+            11 + DEFAULT_ADMIN_OVERRIDE_STALE_NS
+        )
+        .build());
+        contract.resolve_disputed_assertion(assertion_id, true, "DVM stalled".to_string());
+        // The DVM is queried first
+        assert!(near_sdk::test_utils::get_created_receipts()
+            .iter()
+            .any(|receipt| receipt.receiver_id == voting));
+        assert!(
+            !contract
+                .get_assertion(assertion_id)
+                .unwrap()
+                .settlement_pending
+        );
+
+        testing_env!(get_context_with_time(
+            oracle.clone(),
+            oracle,
+            12 + DEFAULT_ADMIN_OVERRIDE_STALE_NS
+        )
+        .build());
+        contract.on_admin_override_dvm_checked(
+            assertion_id,
+            true,
+            "DVM stalled".to_string(),
+            Ok(None),
+        );
+
+        let assertion = contract.get_assertion(assertion_id).unwrap();
+        assert!(assertion.settlement_pending);
+        assert!(assertion.pending_settlement_resolution);
+    }
+
+    #[test]
+    #[should_panic(expected = "DVM has resolved this dispute - use settle_assertion")]
+    fn test_admin_override_rejected_once_dvm_resolved() {
+        let oracle: AccountId = "oracle.near".parse().unwrap();
+        let voting: AccountId = "voting.near".parse().unwrap();
+        let (mut contract, assertion_id) = setup_disputed_assertion(Some(voting));
+
+        testing_env!(get_context_with_time(oracle.clone(), oracle.clone(), 11).build());
+        contract.on_dvm_request_complete(assertion_id, Ok([1u8; 32]));
+
+        testing_env!(get_context_with_time(
+            oracle.clone(),
+            oracle,
+            12 + DEFAULT_ADMIN_OVERRIDE_STALE_NS
+        )
+        .build());
+        contract.on_admin_override_dvm_checked(
+            assertion_id,
+            true,
+            "DVM stalled".to_string(),
+            Ok(Some(0)),
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "DVM request is not yet stale")]
+    fn test_admin_override_rejected_while_dvm_request_in_flight() {
+        let owner: AccountId = "owner.near".parse().unwrap();
+        let oracle: AccountId = "oracle.near".parse().unwrap();
+        let voting: AccountId = "voting.near".parse().unwrap();
+        // `request_price` was sent on dispute but `on_dvm_request_complete` has not landed
+        let (mut contract, assertion_id) = setup_disputed_assertion(Some(voting));
+
+        testing_env!(get_context_with_time(owner, oracle, 200).build());
+        contract.resolve_disputed_assertion(assertion_id, true, "Front-run".to_string());
+    }
+
+    #[test]
+    #[should_panic(expected = "Stale period below minimum")]
+    fn test_admin_override_stale_period_has_minimum() {
+        let owner: AccountId = "owner.near".parse().unwrap();
+        let oracle: AccountId = "oracle.near".parse().unwrap();
+        let (mut contract, _) = setup_assertion(None);
+
+        testing_env!(get_context_with_time(owner, oracle, 2).build());
+        contract.set_admin_override_stale_period(U64(0));
+    }
+
+    #[test]
+    #[should_panic(expected = "DVM request is not yet stale")]
+    fn test_admin_override_rejected_for_fresh_dvm_request() {
+        let owner: AccountId = "owner.near".parse().unwrap();
+        let oracle: AccountId = "oracle.near".parse().unwrap();
+        let voting: AccountId = "voting.near".parse().unwrap();
+        let (mut contract, assertion_id) = setup_disputed_assertion(Some(voting));
+
+        testing_env!(get_context_with_time(oracle.clone(), oracle.clone(), 11).build());
+        contract.on_dvm_request_complete(assertion_id, Ok([1u8; 32]));
+
+        testing_env!(get_context_with_time(owner, oracle, 12).build());
+        contract.resolve_disputed_assertion(assertion_id, true, "Front-run".to_string());
+    }
+
+    #[test]
+    fn test_prune_settled_assertion_frees_storage() {
+        let owner: AccountId = "owner.near".parse().unwrap();
+        let oracle: AccountId = "oracle.near".parse().unwrap();
+        let asserter: AccountId = "asserter.near".parse().unwrap();
+        let caller: AccountId = "caller.near".parse().unwrap();
+        let currency: AccountId = "usdc.near".parse().unwrap();
+
+        testing_env!(get_context_with_time(owner.clone(), oracle.clone(), 1).build());
+        let mut contract =
+            NestOptimisticOracle::new(owner.clone(), currency.clone(), None, None, None);
+        contract.whitelist_currency(currency.clone(), U128(1));
+        fund_assertion_storage(&mut contract, caller.clone(), 1);
+        let funded = contract.get_assertion_storage_balance(caller.clone()).0;
+
+        let assertion_id = [6u8; 32];
+        let claim: Bytes32 = [6u8; 32];
+        let msg = near_sdk::serde_json::json!({
+            "action": "AssertTruth",
+            "claim": claim,
+            "asserter": asserter,
+            "liveness_ns": U64(1),
+            "assertion_time_ns": U64(0),
+            "assertion_id_override": assertion_id,
+        })
+        .to_string();
+        testing_env!(get_context_with_time(currency, oracle.clone(), 2).build());
+        let _ = contract.ft_on_transfer(caller.clone(), U128(10), msg);
+
+        // The assertion's storage is charged to the caller's prepaid balance
+        let bytes = contract
+            .get_assertion_storage_bytes(assertion_id)
+            .unwrap()
+            .0;
+        assert!(bytes > 0);
+        let charged = env::storage_byte_cost().as_yoctonear() * bytes as u128;
+        assert_eq!(
+            contract.get_assertion_storage_balance(caller.clone()).0,
+            funded - charged
+        );
+
+        testing_env!(get_context_with_time(asserter.clone(), oracle.clone(), 5).build());
+        contract.settle_assertion(assertion_id);
+        set_context_with_results(
+            oracle.clone(),
+            oracle.clone(),
+            6,
+            vec![PromiseResult::Successful(vec![])],
+        );
+        contract.on_settlement_payout_complete(assertion_id);
+
+        testing_env!(get_context_with_time(asserter, oracle, 7).build());
+        let storage_before = env::storage_usage();
+        let refund = contract.prune_settled_assertion(assertion_id);
+
+        assert!(env::storage_usage() < storage_before);
+        // At most the charged deposit comes back; the kept result still occupies storage
+        assert!(refund.0 > 0 && refund.0 <= charged);
+        assert!(contract.get_assertion(assertion_id).is_none());
+        assert!(contract.get_assertion_storage_bytes(assertion_id).is_none());
+        // The refund goes to the account that paid the deposit, not the pruner
+        assert!(near_sdk::test_utils::get_created_receipts()
+            .iter()
+            .any(|receipt| receipt.receiver_id == caller));
+        // The result outlives the record
+        assert!(contract.get_assertion_result(assertion_id));
+        assert!(contract.settle_and_get_assertion_result(assertion_id));
+    }
+
+    #[test]
+    fn test_prune_keeps_numeric_result_and_refunds_only_deposits() {
+        let owner: AccountId = "owner.near".parse().unwrap();
+        let oracle: AccountId = "oracle.near".parse().unwrap();
+        let voting: AccountId = "voting.near".parse().unwrap();
+        let (mut contract, assertion_id) = setup_disputed_assertion(Some(voting));
+
+        testing_env!(get_context_with_time(owner, oracle.clone(), 11).build());
+        contract.set_resolution_threshold(DEFAULT_IDENTIFIER, 2_000 * NUMERICAL_TRUE);
+        let price = 2_150 * NUMERICAL_TRUE;
+        testing_env!(get_context_with_time(oracle.clone(), oracle.clone(), 200).build());
+        contract.on_dvm_price_received(assertion_id, Ok(Some(price)));
+        set_context_with_results(
+            oracle.clone(),
+            oracle.clone(),
+            201,
+            vec![PromiseResult::Successful(vec![])],
+        );
+        contract.on_settlement_payout_complete(assertion_id);
+
+        // Created without a storage deposit, so pruning refunds nothing
+        testing_env!(get_context_with_time(oracle.clone(), oracle, 202).build());
+        assert_eq!(contract.prune_settled_assertion(assertion_id).0, 0);
+        assert!(near_sdk::test_utils::get_created_receipts().is_empty());
+
+        assert!(contract.get_assertion_result(assertion_id));
+        assert_eq!(
+            contract.get_assertion_numeric_result(assertion_id),
+            Some(price)
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Insufficient assertion storage balance for caller.near")]
+    fn test_assertion_requires_storage_balance() {
+        let owner: AccountId = "owner.near".parse().unwrap();
+        let oracle: AccountId = "oracle.near".parse().unwrap();
+        let currency: AccountId = "usdc.near".parse().unwrap();
+        let caller: AccountId = "caller.near".parse().unwrap();
+
+        testing_env!(get_context_with_time(owner.clone(), oracle.clone(), 1).build());
+        let mut contract = NestOptimisticOracle::new(owner, currency.clone(), None, None, None);
+        contract.whitelist_currency(currency.clone(), U128(1));
+
+        let claim: Bytes32 = [5u8; 32];
+        let msg = near_sdk::serde_json::json!({
+            "action": "AssertTruth",
+            "claim": claim,
+            "asserter": "asserter.near",
+        })
+        .to_string();
+        testing_env!(get_context_with_time(currency, oracle, 2).build());
+        let _ = contract.ft_on_transfer(caller, U128(10), msg);
+    }
+
+    #[test]
+    fn test_withdraw_assertion_storage() {
+        let owner: AccountId = "owner.near".parse().unwrap();
+        let oracle: AccountId = "oracle.near".parse().unwrap();
+        let currency: AccountId = "usdc.near".parse().unwrap();
+        let caller: AccountId = "caller.near".parse().unwrap();
+
+        testing_env!(get_context_with_time(owner.clone(), oracle.clone(), 1).build());
+        let mut contract = NestOptimisticOracle::new(owner, currency, None, None, None);
+        fund_assertion_storage(&mut contract, caller.clone(), 1);
+        assert_eq!(
+            contract.get_assertion_storage_balance(caller.clone()).0,
+            NearToken::from_near(1).as_yoctonear()
+        );
+
+        testing_env!(get_context_with_time(caller.clone(), oracle, 2).build());
+        let remaining = contract
+            .withdraw_assertion_storage(Some(U128(NearToken::from_millinear(400).as_yoctonear())));
+        assert_eq!(remaining.0, NearToken::from_millinear(600).as_yoctonear());
+        assert_eq!(contract.withdraw_assertion_storage(None).0, 0);
+        assert_eq!(contract.get_assertion_storage_balance(caller.clone()).0, 0);
+        assert_eq!(
+            near_sdk::test_utils::get_created_receipts()
+                .iter()
+                .filter(|receipt| receipt.receiver_id == caller)
+                .count(),
+            2
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Resolved callback not delivered")]
+    fn test_prune_waits_for_resolved_callback() {
+        let owner: AccountId = "owner.near".parse().unwrap();
+        let oracle: AccountId = "oracle.near".parse().unwrap();
+        let asserter: AccountId = "asserter.near".parse().unwrap();
+        let caller: AccountId = "caller.near".parse().unwrap();
+        let recipient: AccountId = "recipient.near".parse().unwrap();
+        let currency: AccountId = "usdc.near".parse().unwrap();
+
+        testing_env!(get_context_with_time(owner.clone(), oracle.clone(), 1).build());
+        let mut contract = NestOptimisticOracle::new(owner, currency.clone(), None, None, None);
+        contract.whitelist_currency(currency.clone(), U128(1));
+
+        let assertion_id = contract.internal_assert_truth(
+            [6u8; 32],
+            asserter.clone(),
+            Some(recipient),
+            None,
+            false,
+            false,
+            false,
+            Some(1),
+            Some(0),
+            currency,
+            10,
+            None,
+            None,
+            None,
+            None,
+            caller,
+        );
+
+        testing_env!(get_context_with_time(asserter.clone(), oracle.clone(), 5).build());
+        contract.settle_assertion(assertion_id);
+        set_context_with_results(
+            oracle.clone(),
+            oracle.clone(),
+            6,
+            vec![PromiseResult::Successful(vec![])],
+        );
+        contract.on_settlement_payout_complete(assertion_id);
+
+        testing_env!(get_context_with_time(asserter, oracle, 7).build());
+        contract.prune_settled_assertion(assertion_id);
+    }
+
+    #[test]
+    #[should_panic(expected = "Assertion not settled")]
+    fn test_prune_rejects_unsettled_assertion() {
+        let (mut contract, assertion_id) = setup_disputed_assertion(None);
+        contract.prune_settled_assertion(assertion_id);
+    }
+
+    #[test]
+    fn test_oracle_fee_split_between_burn_and_treasury() {
+        let owner: AccountId = "owner.near".parse().unwrap();
+        let currency: AccountId = "usdc.near".parse().unwrap();
+        let sink: AccountId = "burn.near".parse().unwrap();
+
+        testing_env!(get_context(owner.clone()).build());
+        let mut contract = NestOptimisticOracle::new(owner, currency, None, None, None);
+
+        // Default: the whole fee goes to the treasury.
+        assert_eq!(contract.split_oracle_fee(1_000), (0, 1_000));
+
+        contract.set_fee_burn_config(2_500, Some(sink.clone()));
+        assert_eq!(contract.get_fee_burn_config(), (2_500, Some(sink)));
+        assert_eq!(contract.split_oracle_fee(1_000), (250, 750));
+        assert_eq!(contract.split_oracle_fee(3), (0, 3));
+    }
+
+    #[test]
+    #[should_panic(expected = "Burn sink required when burning fees")]
+    fn test_fee_burn_requires_sink() {
+        let owner: AccountId = "owner.near".parse().unwrap();
+        let currency: AccountId = "usdc.near".parse().unwrap();
+
+        testing_env!(get_context(owner.clone()).build());
+        let mut contract = NestOptimisticOracle::new(owner, currency, None, None, None);
+        contract.set_fee_burn_config(1_000, None);
+    }
+
+    #[test]
+    fn test_multiple_disputers_split_payout_pro_rata() {
+        let owner: AccountId = "owner.near".parse().unwrap();
+        let oracle: AccountId = "oracle.near".parse().unwrap();
+        let disputer: AccountId = "disputer.near".parse().unwrap();
+        let second_disputer: AccountId = "disputer2.near".parse().unwrap();
+        let currency: AccountId = "usdc.near".parse().unwrap();
+        let (mut contract, assertion_id) = setup_disputed_assertion(None);
+
+        // Second disputer posts a matching bond; the first one tops up theirs.
+        contract.internal_dispute_assertion(
+            assertion_id,
+            second_disputer.clone(),
+            currency.clone(),
+            10,
+            second_disputer.clone(),
+            None,
+        );
+        contract.internal_dispute_assertion(
+            assertion_id,
+            disputer.clone(),
+            currency,
+            10,
+            disputer.clone(),
+            None,
+        );
+
+        let assertion = contract.get_assertion(assertion_id).unwrap();
+        assert_eq!(assertion.disputer, Some(disputer.clone()));
+        assert_eq!(
+            assertion.disputers,
+            vec![
+                (disputer.clone(), U128(20)),
+                (second_disputer.clone(), U128(10))
+            ]
+        );
+
+        // Disputers win: asserter bond 10, 50% fee => reward pool 5 split 20:10.
+        let (payouts, disputed, oracle_fee) = contract.compute_settlement_payout(&assertion, false);
+        assert!(disputed);
+        assert_eq!(oracle_fee, 5);
+        assert_eq!(
+            payouts,
+            vec![
+                (disputer.clone(), 20 + 4),
+                (second_disputer.clone(), 10 + 1)
+            ]
+        );
+
+        // Asserter wins: collects all disputer bonds less 50% fee.
+        let (payouts, _, oracle_fee) = contract.compute_settlement_payout(&assertion, true);
+        assert_eq!(oracle_fee, 15);
+        assert_eq!(payouts, vec![(assertion.asserter.clone(), 10 + 30 - 15)]);
+
+        testing_env!(get_context_with_time(owner, oracle.clone(), 20).build());
+        contract.resolve_disputed_assertion(assertion_id, false, "Test".to_string());
+
+        // Only the second disputer's transfer fails; the retry resends just that one.
+        set_context_with_results(
+            oracle.clone(),
+            oracle.clone(),
+            21,
+            vec![PromiseResult::Successful(vec![]), PromiseResult::Failed],
+        );
+        contract.on_settlement_payout_complete(assertion_id);
+        assert!(!contract.get_assertion(assertion_id).unwrap().settled);
+
+        testing_env!(get_context_with_time(disputer, oracle.clone(), 22).build());
+        contract.retry_settlement_payout(assertion_id);
+        set_context_with_results(
+            oracle.clone(),
+            oracle,
+            23,
+            vec![PromiseResult::Successful(vec![])],
+        );
+        contract.on_settlement_payout_complete(assertion_id);
+
+        let settled = contract.get_assertion(assertion_id).unwrap();
+        assert!(settled.settled);
+        assert!(!settled.settlement_resolution);
+    }
+
+    #[test]
+    fn test_dispute_evidence_uri_propagates_to_event() {
+        let oracle: AccountId = "oracle.near".parse().unwrap();
+        let disputer: AccountId = "disputer.near".parse().unwrap();
+        let currency: AccountId = "usdc.near".parse().unwrap();
+        let (mut contract, assertion_id) = setup_assertion(None);
+
+        testing_env!(get_context_with_time(disputer.clone(), oracle, 10).build());
+        contract.internal_dispute_assertion(
+            assertion_id,
+            disputer.clone(),
+            currency,
+            10,
+            disputer,
+            Some("ipfs://bafyevidence".to_string()),
+        );
+
+        let assertion = contract.get_assertion(assertion_id).unwrap();
+        assert_eq!(
+            assertion.dispute_evidence_uri,
+            Some("ipfs://bafyevidence".to_string())
+        );
+        let logs = near_sdk::test_utils::get_logs();
+        assert!(logs.iter().any(|log| log.contains("assertion_disputed")
+            && log.contains("\"evidence_uri\":\"ipfs://bafyevidence\"")));
+    }
+
+    #[test]
+    #[should_panic(expected = "Evidence URI too long")]
+    fn test_dispute_evidence_uri_length_capped() {
+        let disputer: AccountId = "disputer.near".parse().unwrap();
+        let currency: AccountId = "usdc.near".parse().unwrap();
+        let (mut contract, assertion_id) = setup_disputed_assertion(None);
+        contract.internal_dispute_assertion(
+            assertion_id,
+            disputer.clone(),
+            currency,
+            10,
+            disputer,
+            Some("x".repeat(MAX_EVIDENCE_URI_LEN + 1)),
+        );
+    }
+
+    #[test]
+    fn test_get_resolution_source_undisputed() {
+        let (contract, assertion_id) = setup_assertion(None);
+        assert_eq!(
+            contract.get_resolution_source(assertion_id),
+            ResolutionSource::Undisputed
+        );
+    }
+
+    #[test]
+    fn test_get_resolution_source_without_dvm() {
+        // Disputed without DVM escalation: only the owner fallback can resolve.
+        let (mut contract, assertion_id) = setup_disputed_assertion(None);
+        assert_eq!(
+            contract.get_resolution_source(assertion_id),
+            ResolutionSource::AdminOverride
+        );
+
+        let mut assertion = contract.get_assertion(assertion_id).unwrap();
+        assertion
+            .escalation_manager_settings
+            .arbitrate_via_escalation_manager = true;
+        contract.assertions.insert(assertion_id, assertion.clone());
+        assert_eq!(
+            contract.get_resolution_source(assertion_id),
+            ResolutionSource::EscalationManager
+        );
+
+        assertion.escalation_manager_settings.discard_oracle = true;
+        contract.assertions.insert(assertion_id, assertion);
+        assert_eq!(
+            contract.get_resolution_source(assertion_id),
+            ResolutionSource::DiscardedOracle
+        );
+    }
+
+    #[test]
+    fn test_get_resolution_source_with_dvm() {
+        let owner: AccountId = "owner.near".parse().unwrap();
+        let oracle: AccountId = "oracle.near".parse().unwrap();
+        let voting: AccountId = "voting.near".parse().unwrap();
+
+        // Escalated to the DVM.
+        let (mut contract, assertion_id) = setup_disputed_assertion(Some(voting));
+        testing_env!(get_context_with_time(oracle.clone(), oracle.clone(), 11).build());
+        contract.on_dvm_request_complete(assertion_id, Ok([1u8; 32]));
+        assert_eq!(
+            contract.get_resolution_source(assertion_id),
+            ResolutionSource::Dvm
+        );
+
+        // Owner override once the DVM request is stale and still unresolved.
+        testing_env!(get_context_with_time(
+            owner,
+            oracle.clone(),
+            11 + DEFAULT_ADMIN_OVERRIDE_STALE_NS
+        )
+        .build());
+        contract.resolve_disputed_assertion(assertion_id, true, "DVM stalled".to_string());
+        testing_env!(get_context_with_time(
+            oracle.clone(),
+            oracle,
+            12 + DEFAULT_ADMIN_OVERRIDE_STALE_NS
+        )
+        .build());
+        contract.on_admin_override_dvm_checked(
+            assertion_id,
+            true,
+            "DVM stalled".to_string(),
+            Ok(None),
+        );
+        assert_eq!(
+            contract.get_resolution_source(assertion_id),
+            ResolutionSource::AdminOverride
+        );
+    }
+
+    #[test]
+    fn test_discard_oracle_skips_resolved_callback() {
+        let owner: AccountId = "owner.near".parse().unwrap();
+        let oracle: AccountId = "oracle.near".parse().unwrap();
+        let asserter: AccountId = "asserter.near".parse().unwrap();
+        let caller: AccountId = "caller.near".parse().unwrap();
+        let currency: AccountId = "usdc.near".parse().unwrap();
+        let recipient: AccountId = "recipient.near".parse().unwrap();
+        let manager: AccountId = "manager.near".parse().unwrap();
+
+        testing_env!(get_context_with_time(owner.clone(), oracle.clone(), 1).build());
+        let mut contract = NestOptimisticOracle::new(owner, currency.clone(), None, None, None);
+        contract.whitelist_currency(currency.clone(), U128(1));
+
+        let assertion_id = contract.internal_assert_truth(
+            [8u8; 32],
+            asserter.clone(),
+            Some(recipient.clone()),
+            Some(manager),
+            false,
+            true,
+            false,
+            Some(1),
+            Some(0),
+            currency,
+            10,
+            None,
+            None,
+            None,
+            None,
+            caller,
+        );
+        assert!(
+            contract
+                .get_assertion(assertion_id)
+                .unwrap()
+                .escalation_manager_settings
+                .discard_oracle
+        );
+
+        testing_env!(get_context_with_time(asserter, oracle.clone(), 5).build());
+        contract.settle_assertion(assertion_id);
+        set_context_with_results(
+            oracle.clone(),
+            oracle,
+            6,
+            vec![PromiseResult::Successful(vec![])],
+        );
+        contract.on_settlement_payout_complete(assertion_id);
+
+        assert!(contract.get_assertion(assertion_id).unwrap().settled);
+        assert!(near_sdk::test_utils::get_created_receipts()
+            .iter()
+            .all(|receipt| receipt.receiver_id != recipient));
+    }
+
+    #[test]
+    #[should_panic(expected = "Escalation manager settings require an escalation manager")]
+    fn test_escalation_settings_require_manager() {
+        let owner: AccountId = "owner.near".parse().unwrap();
+        let oracle: AccountId = "oracle.near".parse().unwrap();
+        let currency: AccountId = "usdc.near".parse().unwrap();
+
+        testing_env!(get_context_with_time(owner.clone(), oracle, 1).build());
+        let mut contract = NestOptimisticOracle::new(owner, currency.clone(), None, None, None);
+        contract.whitelist_currency(currency.clone(), U128(1));
+
+        contract.internal_assert_truth(
+            [9u8; 32],
+            "asserter.near".parse().unwrap(),
+            None,
+            None,
+            false,
+            true,
+            false,
+            Some(1),
+            Some(0),
+            currency,
+            10,
+            None,
+            None,
+            None,
+            None,
+            "caller.near".parse().unwrap(),
+        );
+    }
+
+    #[test]
+    fn test_will_notify_callback() {
+        let owner: AccountId = "owner.near".parse().unwrap();
+        let oracle: AccountId = "oracle.near".parse().unwrap();
+        let currency: AccountId = "usdc.near".parse().unwrap();
+        let recipient: AccountId = "recipient.near".parse().unwrap();
+        let manager: AccountId = "manager.near".parse().unwrap();
+
+        testing_env!(get_context_with_time(owner.clone(), oracle, 1).build());
+        let mut contract = NestOptimisticOracle::new(owner, currency.clone(), None, None, None);
+        contract.whitelist_currency(currency.clone(), U128(1));
+
+        let cases = [
+            (None, false, false),
+            (None, true, false),
+            (Some(recipient.clone()), false, true),
+            (Some(recipient), true, false),
+        ];
+        for (index, (callback_recipient, discard_oracle, expected)) in cases.into_iter().enumerate()
+        {
+            let assertion_id = contract.internal_assert_truth(
+                [index as u8; 32],
+                "asserter.near".parse().unwrap(),
+                callback_recipient,
+                Some(manager.clone()),
+                false,
+                discard_oracle,
+                false,
+                Some(100),
+                Some(0),
+                currency.clone(),
+                10,
+                None,
+                None,
+                None,
+                None,
+                "caller.near".parse().unwrap(),
+            );
+            assert_eq!(contract.will_notify_callback(assertion_id), expected);
+        }
+    }
+
+    #[test]
+    fn test_dispute_bond_multiplier_payouts() {
+        let owner: AccountId = "owner.near".parse().unwrap();
+        let oracle: AccountId = "oracle.near".parse().unwrap();
+        let asserter: AccountId = "asserter.near".parse().unwrap();
+        let caller: AccountId = "caller.near".parse().unwrap();
+        let disputer: AccountId = "disputer.near".parse().unwrap();
+        let currency: AccountId = "usdc.near".parse().unwrap();
+
+        // (multiplier_bps, dispute bond, asserter-wins payout, disputer-wins payout)
+        // Assertion bond 10 with a 50% fee taken from the losing side.
+        let cases = [
+            (10_000, 10, 10 + 10 - 5, 10 + 5),
+            (20_000, 20, 10 + 20 - 10, 20 + 5),
+            (5_000, 5, 10 + 5 - 2, 5 + 5),
+        ];
+        let (mut contract, _) = setup_assertion(None);
+        for (i, (multiplier_bps, dispute_bond, asserter_payout, disputer_payout)) in
+            cases.into_iter().enumerate()
+        {
+            testing_env!(get_context_with_time(owner.clone(), oracle.clone(), 2).build());
+            contract.set_dispute_bond_multiplier(multiplier_bps);
+            assert_eq!(contract.get_dispute_bond_multiplier(), multiplier_bps);
+
+            // Fresh assertion per case; mocked storage persists across the loop.
+            let assertion_id = contract.internal_assert_truth(
+                [i as u8; 32],
+                asserter.clone(),
+                None,
+                None,
+                false,
+                false,
+                false,
+                Some(100),
+                Some(0),
+                currency.clone(),
+                10,
+                None,
+                None,
+                None,
+                None,
+                caller.clone(),
+            );
+            let bonded_before = contract.get_total_bonded(currency.clone()).0;
+
+            testing_env!(get_context_with_time(disputer.clone(), oracle.clone(), 10).build());
+            contract.internal_dispute_assertion(
+                assertion_id,
+                disputer.clone(),
+                currency.clone(),
+                dispute_bond,
+                disputer.clone(),
+                None,
+            );
+            assert_eq!(
+                contract.get_total_bonded(currency.clone()).0,
+                bonded_before + dispute_bond
+            );
+
+            let assertion = contract.get_assertion(assertion_id).unwrap();
+            let (payouts, _, _) = contract.compute_settlement_payout(&assertion, true);
+            assert_eq!(payouts, vec![(assertion.asserter.clone(), asserter_payout)]);
+            let (payouts, _, _) = contract.compute_settlement_payout(&assertion, false);
+            assert_eq!(payouts, vec![(disputer.clone(), disputer_payout)]);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "Dispute bond must match required dispute bond")]
+    fn test_dispute_bond_multiplier_rejects_unscaled_bond() {
+        let owner: AccountId = "owner.near".parse().unwrap();
+        let oracle: AccountId = "oracle.near".parse().unwrap();
+        let disputer: AccountId = "disputer.near".parse().unwrap();
+        let currency: AccountId = "usdc.near".parse().unwrap();
+        let (mut contract, assertion_id) = setup_assertion(None);
+
+        testing_env!(get_context_with_time(owner, oracle.clone(), 2).build());
+        contract.set_dispute_bond_multiplier(20_000);
+
+        testing_env!(get_context_with_time(disputer.clone(), oracle, 10).build());
+        contract.internal_dispute_assertion(
+            assertion_id,
+            disputer.clone(),
+            currency,
+            10,
+            disputer,
+            None,
+        );
+    }
+
+    #[test]
+    fn test_registry_check_gates_assertion_callers() {
+        let owner: AccountId = "owner.near".parse().unwrap();
+        let oracle: AccountId = "oracle.near".parse().unwrap();
+        let currency: AccountId = "usdc.near".parse().unwrap();
+        let registry: AccountId = "registry.near".parse().unwrap();
+        let caller: AccountId = "caller.near".parse().unwrap();
+
+        testing_env!(get_context_with_time(owner.clone(), oracle.clone(), 1).build());
+        let mut contract = NestOptimisticOracle::new(owner, currency.clone(), None, None, None);
+        contract.whitelist_currency(currency.clone(), U128(1));
+        contract.set_registry(Some(registry.clone()));
+        assert_eq!(contract.get_registry(), Some(registry));
+        fund_assertion_storage(&mut contract, caller.clone(), 1);
+
+        let assertion_id = [3u8; 32];
+        let claim: Bytes32 = [1u8; 32];
+        let msg = near_sdk::serde_json::json!({
+            "action": "AssertTruth",
+            "claim": claim,
+            "asserter": "asserter.near",
+            "assertion_id_override": assertion_id,
+        })
+        .to_string();
+
+        testing_env!(get_context_with_time(oracle.clone(), oracle.clone(), 2).build());
+        let refund = contract.on_registry_checked(
+            caller.clone(),
+            currency.clone(),
+            U128(10),
+            msg.clone(),
+            Ok(false),
+        );
+        assert_eq!(refund.0, 10);
+        assert!(contract.get_assertion(assertion_id).is_none());
+
+        let refund =
+            contract.on_registry_checked(caller, currency.clone(), U128(10), msg, Ok(true));
+        assert_eq!(refund.0, 0);
+        assert!(contract.get_assertion(assertion_id).is_some());
+        assert_eq!(contract.get_total_bonded(currency).0, 10);
+    }
+
+    #[test]
+    fn test_dispute_cost_matches_required_bond() {
+        let owner: AccountId = "owner.near".parse().unwrap();
+        let oracle: AccountId = "oracle.near".parse().unwrap();
+        let disputer: AccountId = "disputer.near".parse().unwrap();
+        let (mut contract, assertion_id) = setup_assertion(None);
+        assert_eq!(contract.get_dispute_cost(assertion_id).0, 10);
+
+        testing_env!(get_context_with_time(owner, oracle.clone(), 2).build());
+        contract.set_dispute_bond_multiplier(15_000);
+        let cost = contract.get_dispute_cost(assertion_id);
+        assert_eq!(cost.0, 15);
+
+        let currency = contract.get_assertion(assertion_id).unwrap().currency;
+        testing_env!(get_context_with_time(disputer.clone(), oracle, 10).build());
+        contract.internal_dispute_assertion(
+            assertion_id,
+            disputer.clone(),
+            currency,
+            cost.0,
+            disputer.clone(),
+            None,
+        );
+        assert_eq!(
+            contract.get_assertion(assertion_id).unwrap().disputer,
+            Some(disputer)
+        );
+    }
+
+    #[test]
+    fn test_domain_config_overrides_global_defaults() {
+        let owner: AccountId = "owner.near".parse().unwrap();
+        let oracle: AccountId = "oracle.near".parse().unwrap();
+        let currency: AccountId = "usdc.near".parse().unwrap();
+        let domain_currency: AccountId = "wnear.near".parse().unwrap();
+        let domain_id = [5u8; 32];
+        let other_domain = [6u8; 32];
+
+        testing_env!(get_context_with_time(owner.clone(), oracle, 1).build());
+        let mut contract =
+            NestOptimisticOracle::new(owner, currency.clone(), Some(U64(1_000)), None, None);
+        contract.whitelist_currency(currency.clone(), U128(1));
+        contract.set_domain_config(
+            domain_id,
+            DomainConfig {
+                default_currency: domain_currency.clone(),
+                default_liveness_ns: U64(5_000),
+            },
+        );
+        assert_eq!(contract.domain_default_currency(domain_id), domain_currency);
+        assert_eq!(contract.domain_default_currency(other_domain), currency);
+        assert_eq!(contract.domain_default_liveness(other_domain).0, 1_000);
+
+        let assert_in_domain = |contract: &mut NestOptimisticOracle,
+                                claim: u8,
+                                domain: Bytes32,
+                                liveness: Option<u64>| {
+            contract.internal_assert_truth(
+                [claim; 32],
+                "asserter.near".parse().unwrap(),
+                None,
+                None,
+                false,
+                false,
+                false,
+                liveness,
+                Some(0),
+                "usdc.near".parse().unwrap(),
+                10,
+                None,
+                Some(domain),
+                None,
+                None,
+                "caller.near".parse().unwrap(),
+            )
+        };
+
+        let in_domain = assert_in_domain(&mut contract, 1, domain_id, None);
+        let outside_domain = assert_in_domain(&mut contract, 2, other_domain, None);
+        let explicit = assert_in_domain(&mut contract, 3, domain_id, Some(300));
+        let expiration = |id| contract.get_assertion(id).unwrap().expiration_time_ns;
+        assert_eq!(expiration(in_domain), 5_000);
+        assert_eq!(expiration(outside_domain), 1_000);
+        assert_eq!(expiration(explicit), 300);
+
+        contract.remove_domain_config(domain_id);
+        assert!(contract.get_domain_config(domain_id).is_none());
+        assert_eq!(contract.domain_default_liveness(domain_id).0, 1_000);
+    }
+
+    #[test]
+    #[should_panic(expected = "Bond amount too low: need 2 got 1")]
+    fn test_bond_too_low_error_reports_amounts() {
+        let owner: AccountId = "owner.near".parse().unwrap();
+        let oracle: AccountId = "oracle.near".parse().unwrap();
+        let currency: AccountId = "usdc.near".parse().unwrap();
+
+        testing_env!(get_context_with_time(owner.clone(), oracle, 1).build());
+        let mut contract = NestOptimisticOracle::new(owner, currency.clone(), None, None, None);
+        contract.whitelist_currency(currency.clone(), U128(1));
+
+        contract.internal_assert_truth(
+            [1u8; 32],
+            "asserter.near".parse().unwrap(),
+            None,
+            None,
+            false,
+            false,
+            false,
+            Some(100),
+            Some(0),
+            currency,
+            1,
+            None,
+            None,
+            None,
+            None,
+            "caller.near".parse().unwrap(),
+        );
+    }
+
+    #[test]
+    fn test_oracle_error_messages_include_context() {
+        let assertion_id = [0xabu8; 32];
+        assert_eq!(
+            OracleError::AssertionAlreadyExists { assertion_id }.to_string(),
+            format!("Assertion already exists: {}", "ab".repeat(32))
+        );
+        assert_eq!(
+            OracleError::DisputeBondMismatch {
+                required: 20,
+                provided: 10
+            }
+            .to_string(),
+            "Dispute bond must match required dispute bond: need 20 got 10"
+        );
+        assert_eq!(
+            OracleError::AssertionExpired {
+                assertion_id,
+                expiration_time_ns: 100
+            }
+            .to_string(),
+            format!("Assertion is expired: {} expired at 100", "ab".repeat(32))
+        );
+    }
+
+    #[test]
+    fn test_reassign_escalation_manager_before_dispute() {
+        let owner: AccountId = "owner.near".parse().unwrap();
+        let oracle: AccountId = "oracle.near".parse().unwrap();
+        let asserter: AccountId = "asserter.near".parse().unwrap();
+        let caller: AccountId = "caller.near".parse().unwrap();
+        let disputer: AccountId = "disputer.near".parse().unwrap();
+        let currency: AccountId = "usdc.near".parse().unwrap();
+        let buggy_manager: AccountId = "buggy-manager.near".parse().unwrap();
+        let safe_manager: AccountId = "safe-manager.near".parse().unwrap();
+
+        testing_env!(get_context_with_time(owner.clone(), oracle.clone(), 1).build());
+        let mut contract = NestOptimisticOracle::new(owner, currency.clone(), None, None, None);
+        contract.whitelist_currency(currency.clone(), U128(1));
+
+        let assertion_id = contract.internal_assert_truth(
+            [9u8; 32],
+            asserter.clone(),
+            None,
+            Some(buggy_manager.clone()),
+            true,
+            false,
+            false,
+            Some(100),
+            Some(0),
+            currency.clone(),
+            10,
+            None,
+            None,
+            None,
+            None,
+            caller,
+        );
+
+        testing_env!(get_context_with_time(asserter.clone(), oracle.clone(), 5).build());
+        contract.reassign_escalation_manager(assertion_id, safe_manager.clone());
+        let logs = near_sdk::test_utils::get_logs();
+        assert!(logs
+            .iter()
+            .any(|log| log.contains("escalation_manager_reassigned")
+                && log.contains(buggy_manager.as_str())
+                && log.contains(safe_manager.as_str())));
+
+        testing_env!(get_context_with_time(disputer.clone(), oracle, 10).build());
+        contract.internal_dispute_assertion(
+            assertion_id,
+            disputer.clone(),
+            currency,
+            10,
+            disputer,
+            None,
+        );
+
+        let assertion = contract.get_assertion(assertion_id).unwrap();
+        assert_eq!(
+            assertion.escalation_manager_settings.escalation_manager,
+            Some(safe_manager)
+        );
+        assert_eq!(
+            contract.get_resolution_source(assertion_id),
+            ResolutionSource::EscalationManager
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Cannot reassign escalation manager after dispute")]
+    fn test_reassign_escalation_manager_rejected_after_dispute() {
+        let owner: AccountId = "owner.near".parse().unwrap();
+        let oracle: AccountId = "oracle.near".parse().unwrap();
+        let (mut contract, assertion_id) = setup_disputed_assertion(None);
+
+        testing_env!(get_context_with_time(owner, oracle, 11).build());
+        contract.reassign_escalation_manager(assertion_id, "safe-manager.near".parse().unwrap());
+    }
+
+    #[test]
+    #[should_panic(expected = "Only owner or asserter can reassign the escalation manager")]
+    fn test_reassign_escalation_manager_requires_owner_or_asserter() {
+        let oracle: AccountId = "oracle.near".parse().unwrap();
+        let (mut contract, assertion_id) = setup_assertion(None);
+
+        testing_env!(get_context_with_time("stranger.near".parse().unwrap(), oracle, 5).build());
+        contract.reassign_escalation_manager(assertion_id, "safe-manager.near".parse().unwrap());
+    }
+
+    #[test]
+    fn test_arbitrate_via_escalation_manager_skips_dvm() {
+        let owner: AccountId = "owner.near".parse().unwrap();
+        let oracle: AccountId = "oracle.near".parse().unwrap();
+        let asserter: AccountId = "asserter.near".parse().unwrap();
+        let caller: AccountId = "caller.near".parse().unwrap();
+        let disputer: AccountId = "disputer.near".parse().unwrap();
+        let currency: AccountId = "usdc.near".parse().unwrap();
+        let voting: AccountId = "voting.near".parse().unwrap();
+        let manager: AccountId = "manager.near".parse().unwrap();
+
+        testing_env!(get_context_with_time(owner.clone(), oracle.clone(), 1).build());
+        let mut contract =
+            NestOptimisticOracle::new(owner, currency.clone(), None, None, Some(voting.clone()));
+        contract.whitelist_currency(currency.clone(), U128(1));
+
+        let assertion_id = contract.internal_assert_truth(
+            [10u8; 32],
+            asserter,
+            None,
+            Some(manager.clone()),
+            true,
+            false,
+            false,
+            Some(100),
+            Some(0),
+            currency.clone(),
+            10,
+            None,
+            None,
+            None,
+            None,
+            caller,
+        );
+
+        testing_env!(get_context_with_time(disputer.clone(), oracle, 10).build());
+        contract.internal_dispute_assertion(
+            assertion_id,
+            disputer.clone(),
+            currency,
+            10,
+            disputer,
+            None,
+        );
+
+        let receipts = near_sdk::test_utils::get_created_receipts();
+        assert!(receipts.iter().all(|receipt| receipt.receiver_id != voting));
+        assert!(receipts
+            .iter()
+            .any(|receipt| receipt.receiver_id == manager));
+        assert_eq!(
+            contract.get_resolution_source(assertion_id),
+            ResolutionSource::EscalationManager
+        );
+    }
+
+    #[test]
+    fn test_arbitrated_dispute_settles_from_escalation_manager_price() {
+        let owner: AccountId = "owner.near".parse().unwrap();
+        let oracle: AccountId = "oracle.near".parse().unwrap();
+        let asserter: AccountId = "asserter.near".parse().unwrap();
+        let caller: AccountId = "caller.near".parse().unwrap();
+        let disputer: AccountId = "disputer.near".parse().unwrap();
+        let currency: AccountId = "usdc.near".parse().unwrap();
+        let voting: AccountId = "voting.near".parse().unwrap();
+        let manager: AccountId = "manager.near".parse().unwrap();
+
+        testing_env!(get_context_with_time(owner.clone(), oracle.clone(), 1).build());
+        let mut contract =
+            NestOptimisticOracle::new(owner, currency.clone(), None, None, Some(voting.clone()));
+        contract.whitelist_currency(currency.clone(), U128(1));
+
+        let assertion_id = contract.internal_assert_truth(
+            [10u8; 32],
+            asserter,
+            None,
+            Some(manager.clone()),
+            true,
+            false,
+            false,
+            Some(100),
+            Some(0),
+            currency.clone(),
+            10,
+            None,
+            None,
+            None,
+            None,
+            caller,
+        );
+
+        testing_env!(get_context_with_time(disputer.clone(), oracle.clone(), 10).build());
+        contract.internal_dispute_assertion(
+            assertion_id,
+            disputer.clone(),
+            currency,
+            10,
+            disputer.clone(),
+            None,
+        );
+
+        // Settling queries the manager rather than the DVM
+        testing_env!(get_context_with_time(disputer.clone(), oracle.clone(), 200).build());
+        contract.settle_assertion(assertion_id);
+        let receipts = near_sdk::test_utils::get_created_receipts();
+        assert!(receipts.iter().all(|receipt| receipt.receiver_id != voting));
+        assert!(receipts
+            .iter()
+            .any(|receipt| receipt.receiver_id == manager));
+
+        testing_env!(get_context_with_time(oracle.clone(), oracle, 201).build());
+        contract.on_escalation_manager_price_received(assertion_id, Ok(0));
+
+        let assertion = contract.get_assertion(assertion_id).unwrap();
+        assert!(assertion.settlement_pending);
+        assert!(!assertion.pending_settlement_resolution);
+        assert_eq!(
+            contract.pending_payouts.get(&assertion_id).unwrap()[0].0,
+            disputer
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "DVM has not resolved this dispute yet")]
+    fn test_dvm_fallback_waits_before_deadline() {
+        let owner: AccountId = "owner.near".parse().unwrap();
+        let oracle: AccountId = "oracle.near".parse().unwrap();
+        let voting: AccountId = "voting.near".parse().unwrap();
+        let (mut contract, assertion_id) = setup_disputed_assertion(Some(voting));
+
+        testing_env!(get_context_with_time(oracle.clone(), oracle.clone(), 11).build());
+        contract.on_dvm_request_complete(assertion_id, Ok([1u8; 32]));
+        testing_env!(get_context_with_time(owner, oracle.clone(), 12).build());
+        contract.set_dvm_fallback(U64(1_000), Some(false));
+
+        testing_env!(get_context_with_time(oracle.clone(), oracle, 11 + 999).build());
+        contract.on_dvm_price_received(assertion_id, Ok(None));
+    }
+
+    #[test]
+    fn test_dvm_fallback_applies_after_deadline() {
+        let owner: AccountId = "owner.near".parse().unwrap();
+        let oracle: AccountId = "oracle.near".parse().unwrap();
+        let voting: AccountId = "voting.near".parse().unwrap();
+        let (mut contract, assertion_id) = setup_disputed_assertion(Some(voting));
+
+        testing_env!(get_context_with_time(oracle.clone(), oracle.clone(), 11).build());
+        contract.on_dvm_request_complete(assertion_id, Ok([1u8; 32]));
+        testing_env!(get_context_with_time(owner, oracle.clone(), 12).build());
+        contract.set_dvm_fallback(U64(1_000), Some(false));
+        assert_eq!(contract.get_dvm_fallback(), (U64(1_000), Some(false)));
+
+        testing_env!(get_context_with_time(oracle.clone(), oracle, 11 + 1_000).build());
+        contract.on_dvm_price_received(assertion_id, Ok(None));
+
+        let logs = near_sdk::test_utils::get_logs();
+        assert!(logs
+            .iter()
+            .any(|log| log.contains("dvm_resolution_fallback")));
+        let assertion = contract.get_assertion(assertion_id).unwrap();
+        assert!(assertion.settlement_pending);
+        assert!(!assertion.pending_settlement_resolution);
+    }
+
+    #[test]
+    fn test_get_escalation_settings() {
+        let owner: AccountId = "owner.near".parse().unwrap();
+        let oracle: AccountId = "oracle.near".parse().unwrap();
+        let caller: AccountId = "caller.near".parse().unwrap();
+        let currency: AccountId = "usdc.near".parse().unwrap();
+        let manager: AccountId = "manager.near".parse().unwrap();
+
+        testing_env!(get_context_with_time(owner.clone(), oracle, 1).build());
+        let mut contract = NestOptimisticOracle::new(owner, currency.clone(), None, None, None);
+        contract.whitelist_currency(currency.clone(), U128(1));
+
+        let assertion_id = contract.internal_assert_truth(
+            [11u8; 32],
+            "asserter.near".parse().unwrap(),
+            None,
+            Some(manager.clone()),
+            false,
+            true,
+            true,
+            Some(100),
+            Some(0),
+            currency,
+            10,
+            None,
+            None,
+            None,
+            None,
+            caller.clone(),
+        );
+
+        let settings = contract.get_escalation_settings(assertion_id);
+        assert!(!settings.arbitrate_via_escalation_manager);
+        assert!(settings.discard_oracle);
+        assert!(settings.validate_disputers);
+        assert_eq!(settings.asserting_caller, caller);
+        assert_eq!(settings.escalation_manager, Some(manager));
+    }
+
+    #[test]
+    fn test_resolution_threshold_default_and_custom() {
+        let owner: AccountId = "owner.near".parse().unwrap();
+        let oracle: AccountId = "oracle.near".parse().unwrap();
+        let voting: AccountId = "voting.near".parse().unwrap();
+        let (mut contract, assertion_id) = setup_disputed_assertion(Some(voting));
+        assert_eq!(
+            contract.get_resolution_threshold(DEFAULT_IDENTIFIER),
+            NUMERICAL_TRUE
+        );
+
+        testing_env!(get_context_with_time(owner, oracle.clone(), 11).build());
+        contract.set_resolution_threshold(DEFAULT_IDENTIFIER, NUMERICAL_TRUE / 2);
+        assert_eq!(
+            contract.get_resolution_threshold(DEFAULT_IDENTIFIER),
+            NUMERICAL_TRUE / 2
+        );
+        assert!(near_sdk::test_utils::get_logs()
+            .iter()
+            .any(|log| log.contains("resolution_threshold_set")));
+
+        // A price below NUMERICAL_TRUE but at the custom threshold settles for the asserter
+        testing_env!(get_context_with_time(oracle.clone(), oracle, 200).build());
+        contract.on_dvm_price_received(assertion_id, Ok(Some(NUMERICAL_TRUE / 2)));
+        assert!(
+            contract
+                .get_assertion(assertion_id)
+                .unwrap()
+                .pending_settlement_resolution
+        );
+    }
+
+    fn assert_with_identifier(contract: &mut NestOptimisticOracle, identifier: Bytes32) -> Bytes32 {
+        contract.internal_assert_truth(
+            [12u8; 32],
+            "asserter.near".parse().unwrap(),
+            None,
+            None,
+            false,
+            false,
+            false,
+            Some(100),
+            Some(0),
+            "usdc.near".parse().unwrap(),
+            10,
+            Some(identifier),
+            None,
+            None,
+            None,
+            "caller.near".parse().unwrap(),
+        )
+    }
+
+    #[test]
+    #[should_panic(expected = "Unsupported identifier")]
+    fn test_unlisted_identifier_rejected_by_default() {
+        let (mut contract, _) = setup_assertion(None);
+        assert!(contract.get_require_identifier_whitelist());
+        assert_with_identifier(&mut contract, [3u8; 32]);
+    }
+
+    #[test]
+    fn test_unlisted_identifier_accepted_when_whitelist_not_required() {
+        let owner: AccountId = "owner.near".parse().unwrap();
+        let oracle: AccountId = "oracle.near".parse().unwrap();
+        let (mut contract, _) = setup_assertion(None);
+
+        testing_env!(get_context_with_time(owner, oracle, 2).build());
+        contract.set_require_identifier_whitelist(false);
+        assert!(!contract.get_require_identifier_whitelist());
+        assert!(near_sdk::test_utils::get_logs()
+            .iter()
+            .any(|log| log.contains("identifier_whitelist_requirement_set")));
+
+        let assertion_id = assert_with_identifier(&mut contract, [3u8; 32]);
+        assert_eq!(
+            contract.get_assertion(assertion_id).unwrap().identifier,
+            [3u8; 32]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Bond amount too low")]
+    fn test_absolute_min_bond_applies_with_zero_final_fee() {
+        let owner: AccountId = "owner.near".parse().unwrap();
+        let oracle: AccountId = "oracle.near".parse().unwrap();
+        let currency: AccountId = "usdc.near".parse().unwrap();
+
+        testing_env!(get_context_with_time(owner.clone(), oracle, 1).build());
+        let mut contract = NestOptimisticOracle::new(owner, currency.clone(), None, None, None);
+        contract.whitelist_currency(currency.clone(), U128(0));
+        assert_eq!(contract.get_minimum_bond(currency.clone()).0, 0);
+
+        contract.set_absolute_min_bond(currency.clone(), U128(11));
+        assert_eq!(contract.get_absolute_min_bond(currency.clone()).0, 11);
+        assert_eq!(contract.get_minimum_bond(currency).0, 11);
+
+        // Bond of 10 satisfies the zero fee-derived minimum but not the floor
+        assert_with_identifier(&mut contract, DEFAULT_IDENTIFIER);
+    }
+
+    #[test]
+    #[should_panic(expected = "Assertion already settling")]
+    fn test_dispute_rejected_once_dvm_resolution_is_settling() {
+        let oracle: AccountId = "oracle.near".parse().unwrap();
+        let voting: AccountId = "voting.near".parse().unwrap();
+        let disputer: AccountId = "second-disputer.near".parse().unwrap();
+        let currency: AccountId = "usdc.near".parse().unwrap();
+        let (mut contract, assertion_id) = setup_disputed_assertion(Some(voting));
+
+        // DVM resolves while the assertion is still within liveness
+        testing_env!(get_context_with_time(oracle.clone(), oracle.clone(), 20).build());
+        contract.on_dvm_price_received(assertion_id, Ok(Some(0)));
+        assert!(
+            contract
+                .get_assertion(assertion_id)
+                .unwrap()
+                .settlement_pending
+        );
+
+        testing_env!(get_context_with_time(disputer.clone(), oracle, 21).build());
+        contract.internal_dispute_assertion(
+            assertion_id,
+            disputer.clone(),
+            currency,
+            10,
+            disputer,
+            None,
+        );
+    }
+
+    #[test]
+    fn test_assert_eligibility_reports_currency_whitelisting() {
+        let owner: AccountId = "owner.near".parse().unwrap();
+        let oracle: AccountId = "oracle.near".parse().unwrap();
+        let caller: AccountId = "caller.near".parse().unwrap();
+        let usdc: AccountId = "usdc.near".parse().unwrap();
+        let dai: AccountId = "dai.near".parse().unwrap();
+
+        testing_env!(get_context_with_time(owner.clone(), oracle, 1).build());
+        let mut contract = NestOptimisticOracle::new(owner, usdc.clone(), None, None, None);
+        contract.whitelist_currency(usdc.clone(), U128(1));
+
+        assert_eq!(
+            contract.assert_eligibility(caller.clone(), usdc.clone(), None),
+            AssertEligibility::Eligible
+        );
+        assert!(contract.can_assert(caller.clone(), usdc.clone(), None));
+
+        assert_eq!(
+            contract.assert_eligibility(caller.clone(), dai.clone(), None),
+            AssertEligibility::UnsupportedCurrency
+        );
+        assert!(!contract.can_assert(caller.clone(), dai, None));
+
+        assert_eq!(
+            contract.assert_eligibility(caller.clone(), usdc.clone(), Some([9u8; 32])),
+            AssertEligibility::UnsupportedIdentifier
+        );
+        assert!(!contract.can_assert(caller, usdc, Some([9u8; 32])));
+    }
+
+    #[test]
+    fn test_assert_eligibility_defers_registry_check() {
+        let owner: AccountId = "owner.near".parse().unwrap();
+        let oracle: AccountId = "oracle.near".parse().unwrap();
+        let caller: AccountId = "caller.near".parse().unwrap();
+        let registry: AccountId = "registry.near".parse().unwrap();
+        let usdc: AccountId = "usdc.near".parse().unwrap();
+
+        testing_env!(get_context_with_time(owner.clone(), oracle, 1).build());
+        let mut contract = NestOptimisticOracle::new(owner, usdc.clone(), None, None, None);
+        contract.whitelist_currency(usdc.clone(), U128(1));
+        contract.set_registry(Some(registry.clone()));
+
+        assert_eq!(
+            contract.assert_eligibility(caller.clone(), usdc.clone(), None),
+            AssertEligibility::PendingRegistryCheck {
+                registry,
+                caller: caller.clone()
+            }
+        );
+        assert!(contract.can_assert(caller, usdc, None));
+    }
+
+    #[test]
+    fn test_minimum_bond_uses_token_base_units() {
+        let owner: AccountId = "owner.near".parse().unwrap();
+        let usdc: AccountId = "usdc.near".parse().unwrap();
+        let one_usdc: u128 = 1_000_000; // 6 decimals
+
+        testing_env!(get_context(owner.clone()).build());
+        let mut contract = NestOptimisticOracle::new(owner, usdc.clone(), None, None, None);
+        contract.whitelist_currency(usdc.clone(), U128(one_usdc));
+
+        // 1 USDC fee at 50% burn -> 2 USDC, not off by the 1e18 percentage scale
+        assert_eq!(contract.get_minimum_bond(usdc.clone()).0, 2 * one_usdc);
+
+        // 30% burn does not divide evenly; the bond rounds up so its burned share covers the fee
+        contract.set_admin_properties(usdc.clone(), U64(DEFAULT_LIVENESS_NS), U128(SCALE * 3 / 10));
+        let min_bond = contract.get_minimum_bond(usdc).0;
+        assert_eq!(min_bond, 3_333_334);
+        assert!(min_bond * (SCALE * 3 / 10) / SCALE >= one_usdc);
+    }
+
+    #[test]
+    #[should_panic(expected = "Dispute cooldown active")]
+    fn test_dispute_cooldown_rejects_rapid_second_dispute() {
+        let owner: AccountId = "owner.near".parse().unwrap();
+        let oracle: AccountId = "oracle.near".parse().unwrap();
+        let disputer: AccountId = "disputer.near".parse().unwrap();
+        let currency: AccountId = "usdc.near".parse().unwrap();
+        let (mut contract, first_id) = setup_assertion(None);
+        contract.set_dispute_cooldown(U64(50));
+        assert_eq!(contract.get_dispute_cooldown(), U64(50));
+        let second_id = assert_with_identifier(&mut contract, DEFAULT_IDENTIFIER);
+
+        testing_env!(get_context_with_time(owner.clone(), oracle.clone(), 10).build());
+        contract.internal_dispute_assertion(
+            first_id,
+            disputer.clone(),
+            currency.clone(),
+            10,
+            disputer.clone(),
+            None,
+        );
+
+        testing_env!(get_context_with_time(owner, oracle, 20).build());
+        contract.internal_dispute_assertion(
+            second_id,
+            disputer.clone(),
+            currency,
+            10,
+            disputer,
+            None,
+        );
+    }
+
+    #[test]
+    fn test_dispute_allowed_after_cooldown() {
+        let owner: AccountId = "owner.near".parse().unwrap();
+        let oracle: AccountId = "oracle.near".parse().unwrap();
+        let disputer: AccountId = "disputer.near".parse().unwrap();
+        let currency: AccountId = "usdc.near".parse().unwrap();
+        let (mut contract, first_id) = setup_assertion(None);
+        contract.set_dispute_cooldown(U64(50));
+        let second_id = assert_with_identifier(&mut contract, DEFAULT_IDENTIFIER);
+
+        testing_env!(get_context_with_time(owner.clone(), oracle.clone(), 10).build());
+        contract.internal_dispute_assertion(
+            first_id,
+            disputer.clone(),
+            currency.clone(),
+            10,
+            disputer.clone(),
+            None,
+        );
+
+        testing_env!(get_context_with_time(owner, oracle, 60).build());
+        contract.internal_dispute_assertion(
+            second_id,
+            disputer.clone(),
+            currency,
+            10,
+            disputer.clone(),
+            None,
+        );
+        assert_eq!(
+            contract.get_assertion(second_id).unwrap().disputer,
+            Some(disputer)
+        );
+    }
+
+    #[test]
+    fn test_get_config_matches_constructor_inputs() {
+        let owner: AccountId = "owner.near".parse().unwrap();
+        let currency: AccountId = "usdc.near".parse().unwrap();
+        let voting: AccountId = "voting.near".parse().unwrap();
+
+        testing_env!(get_context(owner.clone()).build());
+        let contract = NestOptimisticOracle::new(
+            owner.clone(),
+            currency.clone(),
+            Some(U64(3_600_000_000_000)),
+            Some(U128(SCALE / 4)),
+            Some(voting.clone()),
+        );
+
+        assert_eq!(
+            contract.get_config(),
+            OracleConfig {
+                owner,
+                default_currency: currency,
+                default_liveness_ns: U64(3_600_000_000_000),
+                burned_bond_percentage: U128(SCALE / 4),
+                voting_contract: Some(voting),
+                registry: None,
+                fee_burn_bps: 0,
+                burn_sink: None,
+                require_identifier_whitelist: true,
+                dispute_bond_multiplier_bps: BASIS_POINTS_DENOMINATOR as u32,
+                dispute_grace_ns: U64(0),
+                dispute_cooldown_ns: U64(0),
+                max_assertion_extensions: DEFAULT_MAX_ASSERTION_EXTENSIONS,
+                assertion_creation_fee_bps: 0,
+                escalation_delay_ns: U64(0),
+            }
+        );
+    }
+
+    #[test]
+    fn test_two_step_ownership_transfer() {
+        let owner: AccountId = "owner.near".parse().unwrap();
+        let new_owner: AccountId = "new-owner.near".parse().unwrap();
+        let currency: AccountId = "usdc.near".parse().unwrap();
+
+        testing_env!(get_context(owner.clone()).build());
+        let mut contract = NestOptimisticOracle::new(owner.clone(), currency, None, None, None);
+        contract.propose_owner(new_owner.clone());
+        assert_eq!(contract.get_pending_owner(), Some(new_owner.clone()));
+        assert_eq!(contract.get_owner(), owner);
+
+        testing_env!(get_context(new_owner.clone()).build());
+        contract.accept_ownership();
+        assert_eq!(contract.get_owner(), new_owner);
+        assert_eq!(contract.get_pending_owner(), None);
+        assert!(near_sdk::test_utils::get_logs()
+            .iter()
+            .any(|log| log.contains("ownership_transferred")));
+    }
+
+    #[test]
+    #[should_panic(expected = "Only the pending owner can accept ownership")]
+    fn test_accept_ownership_rejects_other_accounts() {
+        let owner: AccountId = "owner.near".parse().unwrap();
+        let new_owner: AccountId = "new-owner.near".parse().unwrap();
+        let currency: AccountId = "usdc.near".parse().unwrap();
+
+        testing_env!(get_context(owner.clone()).build());
+        let mut contract = NestOptimisticOracle::new(owner, currency, None, None, None);
+        contract.propose_owner(new_owner);
+
+        testing_env!(get_context("mallory.near".parse().unwrap()).build());
+        contract.accept_ownership();
+    }
+
+    #[test]
+    fn test_currency_burned_bond_percentage_override() {
+        let owner: AccountId = "owner.near".parse().unwrap();
+        let oracle: AccountId = "oracle.near".parse().unwrap();
+        let usdc: AccountId = "usdc.near".parse().unwrap();
+        let wnear: AccountId = "wnear.near".parse().unwrap();
+        let (mut contract, assertion_id) = setup_disputed_assertion(None);
+
+        testing_env!(get_context_with_time(owner, oracle, 20).build());
+        contract.whitelist_currency(wnear.clone(), U128(1));
+        contract.set_currency_burned_bond_percentage(wnear.clone(), Some(U128(SCALE / 5)));
+        assert_eq!(
+            contract.get_burned_bond_percentage(usdc.clone()).0,
+            SCALE / 2
+        );
+        assert_eq!(
+            contract.get_burned_bond_percentage(wnear.clone()).0,
+            SCALE / 5
+        );
+        assert_eq!(contract.get_minimum_bond(wnear.clone()).0, 5);
+
+        // Same disputed bond of 10 in each currency: 50% global fee vs 20% override.
+        let usdc_assertion = contract.get_assertion(assertion_id).unwrap();
+        let mut wnear_assertion = usdc_assertion.clone();
+        wnear_assertion.currency = wnear.clone();
+
+        let (payouts, _, oracle_fee) = contract.compute_settlement_payout(&usdc_assertion, true);
+        assert_eq!(oracle_fee, 5);
+        assert_eq!(payouts[0].1, 10 + 10 - 5);
+        let (payouts, _, oracle_fee) = contract.compute_settlement_payout(&wnear_assertion, true);
+        assert_eq!(oracle_fee, 2);
+        assert_eq!(payouts[0].1, 10 + 10 - 2);
+
+        // Re-whitelisting keeps the override; clearing it restores the global value.
+        contract.whitelist_currency(wnear.clone(), U128(2));
+        assert_eq!(
+            contract.get_burned_bond_percentage(wnear.clone()).0,
+            SCALE / 5
+        );
+        contract.set_currency_burned_bond_percentage(wnear.clone(), None);
+        let (_, _, oracle_fee) = contract.compute_settlement_payout(&wnear_assertion, true);
+        assert_eq!(oracle_fee, 5);
+    }
+
+    #[test]
+    #[should_panic(expected = "Burned bond percentage > 100%")]
+    fn test_currency_burned_bond_percentage_rejects_above_scale() {
+        let owner: AccountId = "owner.near".parse().unwrap();
+        let currency: AccountId = "usdc.near".parse().unwrap();
+        testing_env!(get_context(owner.clone()).build());
+        let mut contract = NestOptimisticOracle::new(owner, currency.clone(), None, None, None);
+        contract.whitelist_currency(currency.clone(), U128(1));
+        contract.set_currency_burned_bond_percentage(currency, Some(U128(SCALE + 1)));
+    }
+
+    #[test]
+    fn test_preview_dispute_request_id() {
+        let (contract, assertion_id) = setup_assertion(None);
+
+        let mut ancillary_data = assertion_id.to_vec();
+        ancillary_data.extend_from_slice(b",evidence_uri:ipfs://evidence");
+        assert_eq!(
+            contract.preview_dispute_request_id(
+                assertion_id,
+                U64(10),
+                U64(3),
+                Some("ipfs://evidence".to_string())
+            ),
+            Some(dvm_request_id("ASSERT_TRUTH", 10, &ancillary_data, 3))
+        );
+        // A different nonce (another DVM request landing first) changes the id
+        assert_ne!(
+            contract.preview_dispute_request_id(assertion_id, U64(10), U64(4), None),
+            contract.preview_dispute_request_id(assertion_id, U64(10), U64(3), None)
+        );
+        assert_eq!(
+            contract.preview_dispute_request_id([0u8; 32], U64(10), U64(3), None),
+            None
+        );
+    }
+
+    #[test]
+    fn test_whitelist_currencies_batch() {
+        let owner: AccountId = "owner.near".parse().unwrap();
+        let usdc: AccountId = "usdc.near".parse().unwrap();
+        let wnear: AccountId = "wnear.near".parse().unwrap();
+        let nest: AccountId = "nest.near".parse().unwrap();
+        testing_env!(get_context(owner.clone()).build());
+        let mut contract = NestOptimisticOracle::new(owner, usdc.clone(), None, None, None);
+
+        contract.whitelist_currencies(vec![
+            (usdc.clone(), U128(1_000_000)),
+            (wnear.clone(), U128(SCALE / 10)),
+            (nest.clone(), U128(SCALE)),
+        ]);
+
+        // min_bond = final_fee / 50%
+        assert_eq!(contract.get_minimum_bond(usdc).0, 2_000_000);
+        assert_eq!(contract.get_minimum_bond(wnear).0, SCALE / 5);
+        assert_eq!(contract.get_minimum_bond(nest).0, 2 * SCALE);
+        let logs = near_sdk::test_utils::get_logs();
+        assert_eq!(
+            logs.iter()
+                .filter(|log| log.contains("currency_whitelisted"))
+                .count(),
+            3
+        );
+    }
+
+    #[test]
+    fn test_max_disputers_payout_fits_price_callback_gas() {
+        // Every disputer payout plus the burn and treasury fee transfers, each registering
+        // its recipient first
+        let registered_transfer = GAS_FOR_STORAGE_DEPOSIT.as_gas() + GAS_FOR_FT_TRANSFER.as_gas();
+        let worst_case = (MAX_DISPUTERS as u64 + 2) * registered_transfer
+            + GAS_FOR_SETTLEMENT_PAYOUT_CALLBACK.as_gas();
+        assert!(worst_case < GAS_FOR_DVM_PRICE_CALLBACK.as_gas());
+    }
+
+    #[test]
+    #[should_panic(expected = "Too many currencies in batch")]
+    fn test_whitelist_currencies_rejects_oversized_batch() {
+        let owner: AccountId = "owner.near".parse().unwrap();
+        let currency: AccountId = "usdc.near".parse().unwrap();
+        testing_env!(get_context(owner.clone()).build());
+        let mut contract = NestOptimisticOracle::new(owner, currency, None, None, None);
+
+        let entries = (0..=MAX_CURRENCY_WHITELIST_BATCH)
+            .map(|i| (format!("token{i}.near").parse().unwrap(), U128(1)))
+            .collect();
+        contract.whitelist_currencies(entries);
+    }
+
+    #[test]
+    fn test_price_resolved_callback_settles_linked_assertion() {
+        let oracle: AccountId = "oracle.near".parse().unwrap();
+        let voting: AccountId = "voting.near".parse().unwrap();
+        let (mut contract, assertion_id) = setup_disputed_assertion(Some(voting.clone()));
+        let request_id = [4u8; 32];
+
+        testing_env!(get_context_with_time(oracle.clone(), oracle.clone(), 20).build());
+        contract.on_dvm_request_complete(assertion_id, Ok(request_id));
+
+        // Unknown requests are ignored
+        testing_env!(get_context_with_time(voting.clone(), oracle.clone(), 200).build());
+        contract.price_resolved_callback([5u8; 32], 0);
+        assert!(
+            !contract
+                .get_assertion(assertion_id)
+                .unwrap()
+                .settlement_pending
+        );
+
+        contract.price_resolved_callback(request_id, 0);
+        let assertion = contract.get_assertion(assertion_id).unwrap();
+        assert!(assertion.settlement_pending);
+        assert!(!assertion.pending_settlement_resolution);
+
+        // A repeated notification leaves the pending settlement alone
+        contract.price_resolved_callback(request_id, NUMERICAL_TRUE);
+        assert!(
+            !contract
+                .get_assertion(assertion_id)
+                .unwrap()
+                .pending_settlement_resolution
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Only the voting contract can call this method")]
+    fn test_price_resolved_callback_rejects_other_callers() {
+        let oracle: AccountId = "oracle.near".parse().unwrap();
+        let voting: AccountId = "voting.near".parse().unwrap();
+        let (mut contract, assertion_id) = setup_disputed_assertion(Some(voting));
+        let request_id = [4u8; 32];
+
+        testing_env!(get_context_with_time(oracle.clone(), oracle.clone(), 20).build());
+        contract.on_dvm_request_complete(assertion_id, Ok(request_id));
+
+        testing_env!(get_context_with_time("mallory.near".parse().unwrap(), oracle, 200).build());
+        contract.price_resolved_callback(request_id, 0);
+    }
+
+    #[test]
+    fn test_assertion_metadata_uri_round_trips_and_is_emitted() {
+        let owner: AccountId = "owner.near".parse().unwrap();
+        let oracle: AccountId = "oracle.near".parse().unwrap();
+        let currency: AccountId = "usdc.near".parse().unwrap();
+        let caller: AccountId = "caller.near".parse().unwrap();
+
+        testing_env!(get_context_with_time(owner.clone(), oracle.clone(), 1).build());
+        let mut contract = NestOptimisticOracle::new(owner, currency.clone(), None, None, None);
+        contract.whitelist_currency(currency.clone(), U128(1));
+        fund_assertion_storage(&mut contract, caller.clone(), 1);
+
+        let assertion_id = [4u8; 32];
+        let claim: Bytes32 = [1u8; 32];
+        let msg = near_sdk::serde_json::json!({
+            "action": "AssertTruth",
+            "claim": claim,
+            "asserter": "asserter.near",
+            "assertion_id_override": assertion_id,
+            "metadata_uri": "ipfs://bafyspec",
+        })
+        .to_string();
+        testing_env!(get_context_with_time(currency.clone(), oracle, 2).build());
+        let _ = contract.ft_on_transfer(caller, U128(10), msg);
+
+        assert_eq!(
+            contract.get_assertion_metadata_uri(assertion_id),
+            Some("ipfs://bafyspec".to_string())
+        );
+        let logs = near_sdk::test_utils::get_logs();
+        assert!(logs.iter().any(|log| log.contains("assertion_made")
+            && log.contains("\"metadata_uri\":\"ipfs://bafyspec\"")));
+
+        let (contract, assertion_id) = setup_assertion(None);
+        assert_eq!(contract.get_assertion_metadata_uri(assertion_id), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "Metadata URI too long")]
+    fn test_assertion_metadata_uri_length_capped() {
+        let (mut contract, _) = setup_assertion(None);
+        contract.internal_assert_truth(
+            [8u8; 32],
+            "asserter.near".parse().unwrap(),
+            None,
+            None,
+            false,
+            false,
+            false,
+            Some(100),
+            Some(0),
+            "usdc.near".parse().unwrap(),
+            10,
+            None,
+            None,
+            None,
+            Some("x".repeat(MAX_METADATA_URI_LEN + 1)),
+            "caller.near".parse().unwrap(),
+        );
+    }
+
+    #[test]
+    fn test_dispute_grace_extension_reaches_cap() {
+        let owner: AccountId = "owner.near".parse().unwrap();
+        let oracle: AccountId = "oracle.near".parse().unwrap();
+        let disputer: AccountId = "disputer.near".parse().unwrap();
+        let currency: AccountId = "usdc.near".parse().unwrap();
+
+        let (mut contract, assertion_id) = setup_assertion(None);
+        testing_env!(get_context_with_time(owner, oracle.clone(), 2).build());
+        contract.set_dispute_grace_period(U64(50));
+        assert_eq!(
+            contract.get_max_assertion_extensions(),
+            DEFAULT_MAX_ASSERTION_EXTENSIONS
+        );
+
+        testing_env!(get_context_with_time(disputer.clone(), oracle.clone(), 99).build());
+        contract.internal_dispute_assertion(
+            assertion_id,
+            disputer.clone(),
+            currency.clone(),
+            10,
+            disputer,
+            None,
+        );
+        let assertion = contract.get_assertion(assertion_id).unwrap();
+        assert_eq!(assertion.extension_count, DEFAULT_MAX_ASSERTION_EXTENSIONS);
+        assert_eq!(contract.get_effective_expiration(assertion_id).0, 150);
+
+        // A further last-second dispute joins the escalation without extending again
+        let second_disputer: AccountId = "disputer2.near".parse().unwrap();
+        testing_env!(get_context_with_time(second_disputer.clone(), oracle, 99).build());
+        contract.internal_dispute_assertion(
+            assertion_id,
+            second_disputer.clone(),
+            currency,
+            10,
+            second_disputer,
+            None,
+        );
+        let assertion = contract.get_assertion(assertion_id).unwrap();
+        assert_eq!(assertion.extension_count, DEFAULT_MAX_ASSERTION_EXTENSIONS);
+        assert_eq!(contract.get_effective_expiration(assertion_id).0, 150);
+    }
+
+    #[test]
+    fn test_assertion_at_extension_cap_settles_at_expiration() {
+        let owner: AccountId = "owner.near".parse().unwrap();
+        let oracle: AccountId = "oracle.near".parse().unwrap();
+        let disputer: AccountId = "disputer.near".parse().unwrap();
+        let currency: AccountId = "usdc.near".parse().unwrap();
+
+        let (mut contract, assertion_id) = setup_assertion(None);
+        testing_env!(get_context_with_time(owner.clone(), oracle.clone(), 2).build());
+        contract.set_dispute_grace_period(U64(50));
+        contract.set_max_assertion_extensions(0);
+        assert_eq!(contract.get_config().max_assertion_extensions, 0);
+
+        testing_env!(get_context_with_time(disputer.clone(), oracle.clone(), 99).build());
+        contract.internal_dispute_assertion(
+            assertion_id,
+            disputer.clone(),
+            currency,
+            10,
+            disputer,
+            None,
+        );
+        assert_eq!(
+            contract
+                .get_assertion(assertion_id)
+                .unwrap()
+                .extension_count,
+            0
+        );
+        assert_eq!(contract.get_effective_expiration(assertion_id).0, 100);
+
+        // No grace applies, so resolution proceeds at the original expiration
+        testing_env!(get_context_with_time(owner, oracle, 100).build());
+        contract.resolve_disputed_assertion(assertion_id, true, "no extension".to_string());
+        assert!(
+            contract
+                .get_assertion(assertion_id)
+                .unwrap()
+                .settlement_pending
+        );
+    }
+
+    #[test]
+    fn test_numerical_assertion_settles_with_resolved_price() {
+        let owner: AccountId = "owner.near".parse().unwrap();
+        let oracle: AccountId = "oracle.near".parse().unwrap();
+        let voting: AccountId = "voting.near".parse().unwrap();
+        let (mut contract, assertion_id) = setup_disputed_assertion(Some(voting));
+
+        testing_env!(get_context_with_time(owner, oracle.clone(), 11).build());
+        contract.set_resolution_threshold(DEFAULT_IDENTIFIER, 2_000 * NUMERICAL_TRUE);
+
+        let price = 2_150 * NUMERICAL_TRUE;
+        testing_env!(get_context_with_time(oracle.clone(), oracle.clone(), 200).build());
+        contract.on_dvm_price_received(assertion_id, Ok(Some(price)));
+        assert_eq!(contract.get_assertion_numeric_result(assertion_id), None);
+
+        set_context_with_results(
+            oracle.clone(),
+            oracle,
+            201,
+            vec![PromiseResult::Successful(vec![])],
+        );
+        contract.on_settlement_payout_complete(assertion_id);
+
+        assert!(contract.get_assertion_result(assertion_id));
+        assert_eq!(
+            contract.get_assertion_numeric_result(assertion_id),
+            Some(price)
+        );
+    }
+
+    #[test]
+    fn test_binary_assertion_has_no_numeric_result() {
+        let oracle: AccountId = "oracle.near".parse().unwrap();
+        let voting: AccountId = "voting.near".parse().unwrap();
+        let (mut contract, assertion_id) = setup_disputed_assertion(Some(voting));
+
+        testing_env!(get_context_with_time(oracle.clone(), oracle.clone(), 200).build());
+        contract.on_dvm_price_received(assertion_id, Ok(Some(NUMERICAL_TRUE)));
+        set_context_with_results(
+            oracle.clone(),
+            oracle,
+            201,
+            vec![PromiseResult::Successful(vec![])],
+        );
+        contract.on_settlement_payout_complete(assertion_id);
+
+        assert!(contract.get_assertion_result(assertion_id));
+        assert_eq!(contract.get_assertion_numeric_result(assertion_id), None);
+    }
+
+    #[test]
+    fn test_assertion_creation_fee_reduces_recorded_bond() {
+        let owner: AccountId = "owner.near".parse().unwrap();
+        let oracle: AccountId = "oracle.near".parse().unwrap();
+        let currency: AccountId = "usdc.near".parse().unwrap();
+
+        testing_env!(get_context_with_time(owner.clone(), oracle, 1).build());
+        let mut contract = NestOptimisticOracle::new(owner, currency.clone(), None, None, None);
+        contract.whitelist_currency(currency.clone(), U128(0));
+        contract.set_assertion_creation_fee_bps(1_000);
+        assert_eq!(contract.get_config().assertion_creation_fee_bps, 1_000);
+
+        // 10% of the posted 10 is taken as the fee
+        let assertion_id = assert_with_identifier(&mut contract, DEFAULT_IDENTIFIER);
+        assert_eq!(contract.get_assertion(assertion_id).unwrap().bond.0, 9);
+        assert_eq!(contract.get_total_bonded(currency.clone()).0, 9);
+        assert!(near_sdk::test_utils::get_logs()
+            .iter()
+            .any(|log| log.contains("\"creation_fee\":\"1\"")));
+        assert!(near_sdk::test_utils::get_created_receipts()
+            .iter()
+            .any(|receipt| receipt.receiver_id == currency));
+    }
+
+    #[test]
+    #[should_panic(expected = "Bond amount too low")]
+    fn test_assertion_creation_fee_counts_against_minimum_bond() {
+        let owner: AccountId = "owner.near".parse().unwrap();
+        let oracle: AccountId = "oracle.near".parse().unwrap();
+        let currency: AccountId = "usdc.near".parse().unwrap();
+
+        testing_env!(get_context_with_time(owner.clone(), oracle, 1).build());
+        let mut contract = NestOptimisticOracle::new(owner, currency.clone(), None, None, None);
+        contract.whitelist_currency(currency.clone(), U128(0));
+        contract.set_absolute_min_bond(currency, U128(10));
+        contract.set_assertion_creation_fee_bps(1_000);
+
+        // The posted 10 meets the floor, but the 9 left after the fee does not
+        assert_with_identifier(&mut contract, DEFAULT_IDENTIFIER);
+    }
+
+    #[test]
+    fn test_final_fee_update_bumps_currency_params_version() {
+        let owner: AccountId = "owner.near".parse().unwrap();
+        let oracle: AccountId = "oracle.near".parse().unwrap();
+        let currency: AccountId = "usdc.near".parse().unwrap();
+
+        testing_env!(get_context_with_time(owner.clone(), oracle, 1).build());
+        let mut contract = NestOptimisticOracle::new(owner, currency.clone(), None, None, None);
+        assert_eq!(
+            contract.get_currency_params(currency.clone()),
+            (false, U128(0), U64(0))
+        );
+
+        contract.whitelist_currency(currency.clone(), U128(1));
+        let (whitelisted, final_fee, quoted_version) =
+            contract.get_currency_params(currency.clone());
+        assert!(whitelisted);
+        assert_eq!(final_fee, U128(1));
+
+        contract.whitelist_currency(currency.clone(), U128(3));
+        let (_, final_fee, version) = contract.get_currency_params(currency.clone());
+        assert_eq!(final_fee, U128(3));
+        assert_eq!(version.0, quoted_version.0 + 1);
+        assert!(near_sdk::test_utils::get_logs().iter().any(|log| {
+            log.contains("currency_params_updated")
+                && log.contains(&format!("\"version\":{}", version.0))
+        }));
+
+        // A bond floor also moves the minimum bond
+        contract.set_absolute_min_bond(currency.clone(), U128(100));
+        assert_eq!(contract.get_currency_params(currency).2 .0, version.0 + 1);
+    }
+
+    fn setup_delayed_dispute() -> (NestOptimisticOracle, Bytes32, AccountId) {
+        let owner: AccountId = "owner.near".parse().unwrap();
+        let oracle: AccountId = "oracle.near".parse().unwrap();
+        let disputer: AccountId = "disputer.near".parse().unwrap();
+        let currency: AccountId = "usdc.near".parse().unwrap();
+        let voting: AccountId = "voting.near".parse().unwrap();
+        let (mut contract, assertion_id) = setup_assertion(Some(voting.clone()));
+
+        testing_env!(get_context_with_time(owner, oracle.clone(), 2).build());
+        contract.set_escalation_delay(U64(50));
+
+        testing_env!(get_context_with_time(disputer.clone(), oracle, 10).build());
+        contract.internal_dispute_assertion(
+            assertion_id,
+            disputer.clone(),
+            currency,
+            10,
+            disputer,
+            None,
+        );
+
+        (contract, assertion_id, voting)
+    }
+
+    #[test]
+    fn test_dispute_escalates_after_delay() {
+        let (mut contract, assertion_id, voting) = setup_delayed_dispute();
+
+        assert_eq!(contract.get_pending_escalation(assertion_id), Some(U64(60)));
+        assert!(near_sdk::test_utils::get_created_receipts()
+            .iter()
+            .all(|receipt| receipt.receiver_id != voting));
+        assert!(near_sdk::test_utils::get_logs()
+            .iter()
+            .any(|log| log.contains("dispute_escalation_scheduled")));
+
+        let keeper: AccountId = "keeper.near".parse().unwrap();
+        let oracle: AccountId = "oracle.near".parse().unwrap();
+        testing_env!(get_context_with_time(keeper, oracle, 60).build());
+        contract.escalate_dispute(assertion_id);
+
+        assert!(near_sdk::test_utils::get_created_receipts()
+            .iter()
+            .any(|receipt| receipt.receiver_id == voting));
+        assert_eq!(contract.get_pending_escalation(assertion_id), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "Escalation delay not elapsed")]
+    fn test_escalate_dispute_before_delay_fails() {
+        let (mut contract, assertion_id, _) = setup_delayed_dispute();
+
+        let keeper: AccountId = "keeper.near".parse().unwrap();
+        let oracle: AccountId = "oracle.near".parse().unwrap();
+        testing_env!(get_context_with_time(keeper, oracle, 59).build());
+        contract.escalate_dispute(assertion_id);
+    }
+
+    #[test]
+    #[should_panic(expected = "Dispute awaiting escalation to the DVM")]
+    fn test_settle_pending_escalation_fails() {
+        let (mut contract, assertion_id, _) = setup_delayed_dispute();
+
+        let keeper: AccountId = "keeper.near".parse().unwrap();
+        let oracle: AccountId = "oracle.near".parse().unwrap();
+        testing_env!(get_context_with_time(keeper, oracle, 200).build());
+        contract.settle_assertion(assertion_id);
+    }
+
+    #[test]
+    fn test_assertion_timeline_tracks_lifecycle() {
+        let oracle: AccountId = "oracle.near".parse().unwrap();
+        let voting: AccountId = "voting.near".parse().unwrap();
+        let disputer: AccountId = "disputer.near".parse().unwrap();
+        let caller: AccountId = "caller.near".parse().unwrap();
+        let currency: AccountId = "usdc.near".parse().unwrap();
+        let (mut contract, assertion_id) = setup_assertion(Some(voting));
+
+        assert_eq!(
+            contract.get_assertion_timeline(assertion_id),
+            AssertionTimeline {
+                assertion_time_ns: U64(0),
+                expiration_time_ns: U64(100),
+                dispute_time_ns: None,
+                dvm_request_time_ns: None,
+                settlement_time_ns: None,
+            }
+        );
+
+        testing_env!(get_context_with_time(caller, oracle.clone(), 10).build());
+        contract.internal_dispute_assertion(
+            assertion_id,
+            disputer.clone(),
+            currency,
+            10,
+            disputer,
+            None,
+        );
+        testing_env!(get_context_with_time(oracle.clone(), oracle.clone(), 11).build());
+        contract.on_dvm_request_complete(assertion_id, Ok([1u8; 32]));
+
+        testing_env!(get_context_with_time(oracle.clone(), oracle.clone(), 200).build());
+        contract.on_dvm_price_received(assertion_id, Ok(Some(NUMERICAL_TRUE)));
+        assert_eq!(
+            contract
+                .get_assertion_timeline(assertion_id)
+                .settlement_time_ns,
+            None
+        );
+
+        set_context_with_results(
+            oracle.clone(),
+            oracle,
+            201,
+            vec![PromiseResult::Successful(vec![])],
+        );
+        contract.on_settlement_payout_complete(assertion_id);
+
+        assert_eq!(
+            contract.get_assertion_timeline(assertion_id),
+            AssertionTimeline {
+                assertion_time_ns: U64(0),
+                expiration_time_ns: U64(100),
+                dispute_time_ns: Some(U64(10)),
+                dvm_request_time_ns: Some(U64(11)),
+                settlement_time_ns: Some(U64(201)),
+            }
+        );
+    }
+
+    #[test]
+    fn test_admin_actions_are_recorded() {
+        let owner: AccountId = "owner.near".parse().unwrap();
+        let oracle: AccountId = "oracle.near".parse().unwrap();
+        let currency: AccountId = "usdc.near".parse().unwrap();
+
+        testing_env!(get_context_with_time(owner.clone(), oracle.clone(), 5).build());
+        let mut contract =
+            NestOptimisticOracle::new(owner.clone(), currency.clone(), None, None, None);
+        contract.whitelist_currency(currency, U128(1));
+        testing_env!(get_context_with_time(owner.clone(), oracle, 7).build());
+        contract.set_dispute_cooldown(U64(10));
+
+        assert_eq!(
+            contract.get_admin_actions(0, 10),
+            vec![
+                AdminAction {
+                    method: "whitelist_currency".to_string(),
+                    timestamp: 5,
+                    caller: owner.clone(),
+                },
+                AdminAction {
+                    method: "set_dispute_cooldown".to_string(),
+                    timestamp: 7,
+                    caller: owner,
+                },
+            ]
+        );
+        assert_eq!(contract.get_admin_actions(1, 10).len(), 1);
+        assert!(near_sdk::test_utils::get_logs()
+            .iter()
+            .any(|log| log.contains("admin_action_recorded")));
+    }
+
+    #[test]
+    fn test_admin_action_log_is_bounded() {
+        let owner: AccountId = "owner.near".parse().unwrap();
+        let currency: AccountId = "usdc.near".parse().unwrap();
+
+        testing_env!(get_context(owner.clone()).build());
+        let mut contract = NestOptimisticOracle::new(owner.clone(), currency, None, None, None);
+        for cooldown in 0..MAX_ADMIN_ACTIONS + 4 {
+            // Fresh context per call keeps the emitted events under the mocked log limit
+            testing_env!(get_context(owner.clone()).build());
+            contract.set_dispute_cooldown(U64(cooldown));
+        }
+
+        assert_eq!(
+            contract.get_admin_actions(0, 1_000).len() as u64,
+            MAX_ADMIN_ACTIONS
+        );
+        assert!(contract
+            .get_admin_actions(MAX_ADMIN_ACTIONS + 4, 10)
+            .is_empty());
+    }
+
+    #[test]
+    fn test_identifier_min_bond_raises_minimum() {
+        let (mut contract, _) = setup_assertion(None);
+        let currency: AccountId = "usdc.near".parse().unwrap();
+        let risky = [4u8; 32];
+        contract.whitelist_identifier(risky);
+
+        contract.set_identifier_min_bond(risky, U128(50));
+        assert_eq!(contract.get_identifier_min_bond(risky), U128(50));
+        assert_eq!(
+            contract.get_minimum_bond_for_identifier(currency.clone(), risky),
+            U128(50)
+        );
+        // Other identifiers keep the currency minimum
+        assert_eq!(
+            contract.get_minimum_bond_for_identifier(currency.clone(), DEFAULT_IDENTIFIER),
+            contract.get_minimum_bond(currency)
+        );
+        assert!(near_sdk::test_utils::get_logs()
+            .iter()
+            .any(|log| log.contains("identifier_min_bond_set")));
+
+        // A bond of 10 still clears the currency minimum on the default identifier
+        assert_with_identifier(&mut contract, DEFAULT_IDENTIFIER);
+    }
+
+    #[test]
+    #[should_panic(expected = "Bond amount too low: need 50 got 10")]
+    fn test_identifier_min_bond_rejects_smaller_bond() {
+        let (mut contract, _) = setup_assertion(None);
+        let risky = [4u8; 32];
+        contract.whitelist_identifier(risky);
+        contract.set_identifier_min_bond(risky, U128(50));
+
+        assert_with_identifier(&mut contract, risky);
     }
 }
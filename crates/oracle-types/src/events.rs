@@ -10,10 +10,10 @@ use near_sdk::{json_types::U128, log, serde::Serialize, serde_json::json, Accoun
 use crate::types::{Bytes32, CryptoHash};
 
 /// Event standard identifier for Nest oracle events.
-const EVENT_STANDARD: &str = "nest-oracle";
+pub const EVENT_STANDARD: &str = "nest-oracle";
 
 /// Event standard identifier for Nest DVM voting events.
-const VOTING_EVENT_STANDARD: &str = "nest-voting";
+pub const VOTING_EVENT_STANDARD: &str = "nest-voting";
 
 /// Current version of the event standard.
 const EVENT_STANDARD_VERSION: &str = "1.0.0";
@@ -54,6 +54,10 @@ pub enum Event<'a> {
         bond: &'a U128,
         /// Identifier type for this assertion (e.g., ASSERT_TRUTH).
         identifier: &'a Bytes32,
+        /// Optional pointer (e.g. IPFS CID) to structured context for the claim.
+        metadata_uri: &'a Option<String>,
+        /// Fee taken from the posted amount at creation; `bond` excludes it.
+        creation_fee: &'a U128,
     },
 
     /// Emitted when an assertion is disputed.
@@ -67,6 +71,18 @@ pub enum Event<'a> {
         caller: &'a AccountId,
         /// Account designated as the disputer (receives bond if dispute succeeds).
         disputer: &'a AccountId,
+        /// Optional pointer (e.g. IPFS CID) to the disputer's rationale.
+        evidence_uri: &'a Option<String>,
+    },
+
+    /// Emitted when a dispute's DVM escalation is deferred by the escalation delay.
+    ///
+    /// Anyone can call `escalate_dispute` once `escalate_after_ns` has passed.
+    DisputeEscalationScheduled {
+        /// The disputed assertion.
+        assertion_id: &'a Bytes32,
+        /// Earliest time (in nanoseconds) the dispute can be escalated to the DVM.
+        escalate_after_ns: u64,
     },
 
     /// Emitted when an assertion is settled.
@@ -126,6 +142,79 @@ pub enum Event<'a> {
         caller: &'a AccountId,
     },
 
+    /// Emitted when the owner manually resolves a disputed assertion.
+    AdminResolutionOverride {
+        /// The assertion being resolved.
+        assertion_id: &'a Bytes32,
+        /// Resolution chosen by the owner (true = asserter wins).
+        resolution: bool,
+        /// Human-readable justification recorded for auditability.
+        reason: &'a str,
+    },
+
+    /// Emitted when a dispute the DVM never resolved settles with the configured fallback.
+    DvmResolutionFallback {
+        /// The assertion being settled.
+        assertion_id: &'a Bytes32,
+        /// Fallback resolution applied (true = asserter wins).
+        resolution: bool,
+        /// Timestamp (in nanoseconds) when the dispute was escalated to the DVM.
+        escalated_at_ns: u64,
+    },
+
+    /// Emitted when an undisputed assertion is repointed to a different escalation manager.
+    EscalationManagerReassigned {
+        /// The assertion being reassigned.
+        assertion_id: &'a Bytes32,
+        /// Escalation manager the assertion pointed to before the change.
+        previous_manager: &'a Option<AccountId>,
+        /// Escalation manager the assertion now points to.
+        new_manager: &'a AccountId,
+        /// Owner or asserter that performed the reassignment.
+        caller: &'a AccountId,
+    },
+
+    /// Emitted when the owner changes the DVM price at which the asserter wins for an identifier.
+    ResolutionThresholdSet {
+        /// Identifier the threshold applies to.
+        identifier: &'a Bytes32,
+        /// Minimum DVM price that resolves in the asserter's favor, as a decimal string
+        /// (thresholds above 1e19 do not fit a JSON number).
+        threshold: &'a str,
+    },
+
+    /// Emitted when the owner sets (or removes, with zero) an identifier's minimum bond.
+    IdentifierMinBondSet {
+        /// Identifier the minimum bond applies to.
+        identifier: &'a Bytes32,
+        /// Minimum bond, in any currency's base units; zero means none.
+        minimum_bond: &'a U128,
+    },
+
+    /// Emitted when the owner whitelists a bond currency or updates its final fee.
+    CurrencyWhitelisted {
+        /// NEP-141 token accepted for bonds.
+        currency: &'a AccountId,
+        /// Final fee for the currency, in its base units.
+        final_fee: u128,
+    },
+
+    /// Emitted when a currency's final fee, burned bond percentage or bond floor changes.
+    CurrencyParamsUpdated {
+        /// NEP-141 token whose bond parameters changed.
+        currency: &'a AccountId,
+        /// Minimum bond for the currency after the change.
+        minimum_bond: &'a U128,
+        /// New currency params version.
+        version: u64,
+    },
+
+    /// Emitted when the owner changes whether assertions must use a whitelisted identifier.
+    IdentifierWhitelistRequirementSet {
+        /// Whether unlisted identifiers are rejected.
+        required: bool,
+    },
+
     /// Emitted when the contract owner updates administrative properties.
     ///
     /// These properties affect default values for new assertions.
@@ -136,6 +225,20 @@ pub enum Event<'a> {
         default_liveness_ns: u64,
         /// Percentage of bond burned on dispute (scaled by 1e18, e.g., 0.5e18 = 50%).
         burned_bond_percentage: u128,
+        /// Currency params version after the update; bumped if the burned bond
+        /// percentage changed.
+        currency_params_version: u64,
+    },
+
+    /// Emitted when an owner-only method is called, alongside its entry in the admin
+    /// action log (`get_admin_actions`).
+    AdminActionRecorded {
+        /// Position of the entry in the log.
+        index: u64,
+        /// Name of the owner-only method.
+        method: &'a str,
+        /// Account that called it.
+        caller: &'a AccountId,
     },
 }
 
@@ -190,6 +293,26 @@ pub enum VotingEvent<'a> {
         reveal_start_time: u64,
     },
 
+    /// Emitted when the owner extends a request's reveal window.
+    RevealPhaseExtended {
+        /// The request whose reveal phase was extended.
+        request_id: &'a CryptoHash,
+        /// Additional reveal time granted in nanoseconds.
+        additional_ns: u64,
+        /// New reveal deadline in nanoseconds.
+        new_reveal_deadline: u64,
+    },
+
+    /// Emitted when the owner extends a request's commit window.
+    CommitPhaseExtended {
+        /// The request whose commit phase was extended.
+        request_id: &'a CryptoHash,
+        /// Additional commit time granted in nanoseconds.
+        additional_ns: u64,
+        /// New commit deadline in nanoseconds.
+        new_commit_deadline: u64,
+    },
+
     /// Emitted when a voter reveals their vote.
     VoteRevealed {
         /// The request being voted on.
@@ -220,6 +343,24 @@ pub enum VotingEvent<'a> {
         reveal_phase_duration_ns: u64,
     },
 
+    /// Emitted when a request leaves its commit phase without any commitments and is
+    /// routed to emergency resolution.
+    RequestExpiredNoCommits {
+        /// The affected request.
+        request_id: &'a CryptoHash,
+    },
+
+    /// Emitted when the owner routes a request that ended its commit phase with too few
+    /// committers to emergency resolution.
+    InsufficientCommits {
+        /// The affected request.
+        request_id: &'a CryptoHash,
+        /// Number of distinct committers for the request.
+        committers: u64,
+        /// Minimum number of committers required to advance to reveal.
+        required_committers: u64,
+    },
+
     /// Emitted when participation is too low to finalize normally.
     LowParticipationTriggered {
         /// The affected request.
@@ -230,6 +371,10 @@ pub enum VotingEvent<'a> {
         revealed_stake: &'a U128,
         /// Minimum revealed stake required for normal resolution.
         required_stake: &'a U128,
+        /// Number of distinct committers for the request.
+        committers: u64,
+        /// Minimum number of committers required for normal resolution.
+        required_committers: u64,
         /// True if the request moved to emergency-only path.
         emergency_required: bool,
     },
@@ -243,6 +388,85 @@ pub enum VotingEvent<'a> {
         /// Human-readable reason recorded for auditability.
         reason: &'a str,
     },
+
+    /// Emitted when a voter reclaims stake from an emergency-resolved request.
+    EmergencyRefundClaimed {
+        /// The emergency-resolved request.
+        request_id: &'a CryptoHash,
+        /// The voter receiving the refund.
+        voter: &'a AccountId,
+        /// Stake refunded to the voter.
+        amount: &'a U128,
+    },
+
+    /// Emitted when a winning voter withdraws its returned stake and reward.
+    RewardClaimed {
+        /// The resolved request.
+        request_id: &'a CryptoHash,
+        /// The voter receiving the payout.
+        voter: &'a AccountId,
+        /// Stake and reward paid to the voter.
+        amount: &'a U128,
+    },
+
+    /// Emitted when a token transfer out of the voting contract fails; the amount is kept
+    /// for `retry_transfer`.
+    TransferFailed {
+        /// The request the transfer paid out for.
+        request_id: &'a CryptoHash,
+        /// The account that was not paid.
+        recipient: &'a AccountId,
+        /// Amount owed to the recipient.
+        amount: &'a U128,
+    },
+
+    /// Emitted when a failed transfer is re-attempted with `retry_transfer`.
+    TransferRetried {
+        /// The request the transfer pays out for.
+        request_id: &'a CryptoHash,
+        /// The account being paid.
+        recipient: &'a AccountId,
+        /// Amount re-sent to the recipient.
+        amount: &'a U128,
+    },
+
+    /// Emitted when the owner slashes a voter's committed stake outside resolution.
+    VoterSlashed {
+        /// The request the slashed stake was committed to.
+        request_id: &'a CryptoHash,
+        /// The slashed voter.
+        voter: &'a AccountId,
+        /// Stake burned from the voter's commitment.
+        amount: &'a U128,
+        /// Human-readable reason recorded for auditability.
+        reason: &'a str,
+    },
+
+    /// Emitted when a share of slashed stake is burned at resolution.
+    StakeBurned {
+        /// The request whose slashed stake was burned.
+        request_id: &'a CryptoHash,
+        /// Amount of voting token burned.
+        amount: &'a U128,
+    },
+
+    /// Emitted when a resolution fee is taken from winning voters' returned stake.
+    ResolutionFeeCollected {
+        /// The resolved request.
+        request_id: &'a CryptoHash,
+        /// Total fee routed to the treasury.
+        amount: &'a U128,
+    },
+
+    /// Emitted when the caller of a successful resolution is paid the resolver reward.
+    ResolverRewarded {
+        /// The resolved request.
+        request_id: &'a CryptoHash,
+        /// Account that resolved the request.
+        resolver: &'a AccountId,
+        /// Reward paid out of the treasury cut.
+        amount: &'a U128,
+    },
 }
 
 impl VotingEvent<'_> {
@@ -261,7 +485,7 @@ impl VotingEvent<'_> {
 /// - `data`: Array of event data objects
 ///
 /// The output is logged with the "EVENT_JSON:" prefix for indexer detection.
-fn emit_event<T: ?Sized + Serialize>(standard: &str, data: &T) {
+pub(crate) fn emit_event<T: ?Sized + Serialize>(standard: &str, data: &T) {
     let result = json!(data);
     let event_json = json!({
         "standard": standard,
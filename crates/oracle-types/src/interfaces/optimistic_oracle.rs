@@ -84,9 +84,114 @@ pub struct Assertion {
     /// Optional contract to notify when the assertion is resolved.
     pub callback_recipient: Option<AccountId>,
 
-    /// Account that disputed the assertion, if any.
+    /// Account that first disputed the assertion, if any.
     /// If Some, the assertion has been disputed and awaits resolution.
     pub disputer: Option<AccountId>,
+
+    /// Every disputer with the total bond they posted.
+    /// On a disputer win, the asserter's bond is split pro-rata across these bonds.
+    pub disputers: Vec<(AccountId, U128)>,
+
+    /// Optional evidence pointer (e.g. IPFS CID) supplied by the first disputer.
+    pub dispute_evidence_uri: Option<String>,
+
+    /// Whether `callback_recipient` accepted the resolved callback.
+    /// If false after settlement, the callback can be re-sent with `retry_resolved_callback`.
+    pub callback_delivered: bool,
+
+    /// Number of times the expiration has been extended (e.g. by the dispute grace window).
+    pub extension_count: u32,
+
+    /// Raw DVM price the dispute resolved with, recorded for numerical identifiers
+    /// (those with a resolution threshold configured).
+    pub resolved_price: Option<i128>,
+
+    /// Timestamp (in nanoseconds) when the first dispute landed, if disputed.
+    pub dispute_time_ns: Option<u64>,
+
+    /// Timestamp (in nanoseconds) when settlement completed, if settled.
+    pub settlement_time_ns: Option<u64>,
+}
+
+/// Lifecycle timestamps of an assertion, returned by `get_assertion_timeline`.
+///
+/// Steps that have not happened yet are `None`.
+#[near(serializers = [json])]
+#[derive(Clone, PartialEq, Debug)]
+pub struct AssertionTimeline {
+    /// When the assertion was created.
+    pub assertion_time_ns: U64,
+
+    /// When the assertion can be settled, including any dispute grace extension.
+    pub expiration_time_ns: U64,
+
+    /// When the first dispute landed.
+    pub dispute_time_ns: Option<U64>,
+
+    /// When the DVM accepted the price request for the dispute.
+    pub dvm_request_time_ns: Option<U64>,
+
+    /// When settlement completed.
+    pub settlement_time_ns: Option<U64>,
+}
+
+/// An owner-only method call recorded in the oracle's admin action log.
+#[near(serializers = [json, borsh])]
+#[derive(Clone, PartialEq, Debug)]
+pub struct AdminAction {
+    /// Name of the owner-only method.
+    pub method: String,
+
+    /// Timestamp (in nanoseconds) of the call.
+    pub timestamp: u64,
+
+    /// Account that made the call.
+    pub caller: AccountId,
+}
+
+/// What the oracle keeps of an assertion once it has been pruned.
+#[near(serializers = [borsh])]
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct PrunedAssertion {
+    /// Result as `get_assertion_result` returned it.
+    pub result: bool,
+
+    /// Raw DVM or escalation manager price, for numerical identifiers.
+    pub resolved_price: Option<i128>,
+}
+
+/// Mechanism that resolves (or resolved) an assertion.
+#[near(serializers = [json, borsh])]
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum ResolutionSource {
+    /// Not disputed; settles in favor of the asserter after liveness.
+    Undisputed,
+    /// Dispute escalated to the DVM vote.
+    Dvm,
+    /// Dispute arbitrated by the assertion's escalation manager.
+    EscalationManager,
+    /// Oracle result is discarded in favor of the escalation manager's decision.
+    DiscardedOracle,
+    /// Dispute resolved (or only resolvable) by the oracle owner.
+    AdminOverride,
+}
+
+/// Outcome of the oracle's local pre-flight checks for a new assertion.
+#[near(serializers = [json, borsh])]
+#[derive(Clone, PartialEq, Debug)]
+pub enum AssertEligibility {
+    /// All checks pass; the assertion is created on transfer.
+    Eligible,
+    /// Local checks pass, but the oracle will only create the assertion once `registry`
+    /// confirms the caller with `is_contract_registered`; otherwise the bond is refunded.
+    PendingRegistryCheck {
+        registry: AccountId,
+        caller: AccountId,
+    },
+    /// The identifier is not approved (`whitelist_identifier`).
+    UnsupportedIdentifier,
+    /// The bond currency is not whitelisted (`whitelist_currency`).
+    UnsupportedCurrency,
 }
 
 /// Information about a whitelisted currency.
@@ -98,9 +203,81 @@ pub struct WhitelistedCurrency {
     /// Whether this currency is currently whitelisted.
     pub is_whitelisted: bool,
 
-    /// The fee charged when disputes are resolved.
-    /// Used to calculate minimum bond: `min_bond = final_fee * 1e18 / burned_bond_percentage`
+    /// The fee charged when disputes are resolved, in the currency's base units.
+    /// Used to calculate minimum bond: `min_bond = ceil(final_fee * 1e18 / burned_bond_percentage)`,
+    /// with the currency's own `burned_bond_percentage` if set.
     pub final_fee: U128,
+
+    /// Share of a losing bond in this currency taken as the oracle fee (scaled by 1e18).
+    /// If None, the oracle-wide `burned_bond_percentage` is used.
+    pub burned_bond_percentage: Option<U128>,
+}
+
+/// Per-domain assertion defaults.
+///
+/// Applied to assertions whose `domain_id` has a config, in place of the oracle-wide defaults.
+#[near(serializers = [json, borsh])]
+#[derive(Clone)]
+pub struct DomainConfig {
+    /// Bond currency integrators should use for this domain.
+    pub default_currency: AccountId,
+
+    /// Liveness period (nanoseconds) used when an assertion omits `liveness_ns`.
+    pub default_liveness_ns: U64,
+}
+
+/// Snapshot of the oracle's contract-wide settings.
+///
+/// Returned by `get_config` so operators and indexers can read (and diff) every
+/// admin-controlled setting in one call. Per-currency, per-identifier and per-domain
+/// settings have their own views.
+#[near(serializers = [json])]
+#[derive(Clone, PartialEq, Debug)]
+pub struct OracleConfig {
+    /// Account allowed to change oracle settings.
+    pub owner: AccountId,
+
+    /// Default bond currency.
+    pub default_currency: AccountId,
+
+    /// Default liveness period in nanoseconds.
+    pub default_liveness_ns: U64,
+
+    /// Share of the losing bond taken as the oracle fee (scaled by 1e18).
+    pub burned_bond_percentage: U128,
+
+    /// DVM voting contract disputes escalate to.
+    pub voting_contract: Option<AccountId>,
+
+    /// Registry assertion callers must be registered with, if any.
+    pub registry: Option<AccountId>,
+
+    /// Portion of the oracle fee sent to `burn_sink` (bps).
+    pub fee_burn_bps: u16,
+
+    /// Account receiving the burned share of the oracle fee.
+    pub burn_sink: Option<AccountId>,
+
+    /// Whether assertions must use a whitelisted identifier.
+    pub require_identifier_whitelist: bool,
+
+    /// Dispute bond as a multiple of the assertion bond (bps).
+    pub dispute_bond_multiplier_bps: u32,
+
+    /// Window before expiration in which a dispute extends the expiration (nanoseconds).
+    pub dispute_grace_ns: U64,
+
+    /// Minimum time between two disputes by the same disputer (nanoseconds).
+    pub dispute_cooldown_ns: U64,
+
+    /// Maximum number of times an assertion's expiration may be extended.
+    pub max_assertion_extensions: u32,
+
+    /// Share of each posted bond taken as a fee at assertion creation (bps).
+    pub assertion_creation_fee_bps: u16,
+
+    /// Delay between a first dispute and its DVM escalation (nanoseconds).
+    pub escalation_delay_ns: U64,
 }
 
 /// The main Optimistic Oracle interface.
@@ -223,7 +400,8 @@ pub trait OptimisticOracle {
 
     /// Get the minimum bond required for a currency.
     ///
-    /// Calculated as: `final_fee * 1e18 / burned_bond_percentage`
+    /// Calculated as: `ceil(final_fee * 1e18 / burned_bond_percentage)`, in the currency's
+    /// base units whatever its decimals.
     ///
     /// # Arguments
     ///
@@ -8,8 +8,10 @@
 //!
 //! - [`events`] - NEP-297 compliant event definitions for indexing
 //! - [`interfaces`] - Trait definitions for oracle and callback contracts
-//! - [`types`] - Core type aliases and definitions
+//! - [`ownership`] - Two-step ownership transfer shared by the contracts
+//! - [`types`] - Core type aliases and definitions, and the DVM request ID hash
 
 pub mod events;
 pub mod interfaces;
+pub mod ownership;
 pub mod types;
@@ -0,0 +1,58 @@
+//! Two-step ownership transfer shared by the Nest contracts.
+//!
+//! The owner proposes a successor, which only becomes owner once it accepts. A transfer
+//! to a mistyped or uncontrolled account therefore never takes effect. Contracts keep
+//! their own `owner` field and owner check, and store the proposal in a [`PendingOwner`].
+
+use near_sdk::{env, near, require, serde::Serialize, AccountId};
+
+use crate::events::emit_event;
+
+/// Account proposed as the next owner of a contract, if any.
+#[near(serializers = [borsh])]
+#[derive(Default)]
+pub struct PendingOwner(Option<AccountId>);
+
+#[derive(Serialize)]
+#[serde(crate = "near_sdk::serde")]
+#[serde(tag = "event", content = "data")]
+#[serde(rename_all = "snake_case")]
+enum OwnershipEvent<'a> {
+    OwnershipTransferred {
+        previous_owner: &'a AccountId,
+        new_owner: &'a AccountId,
+    },
+}
+
+impl PendingOwner {
+    /// Record `new_owner` as the proposed owner, replacing any earlier proposal.
+    /// The caller must have checked that the current owner is calling.
+    pub fn propose(&mut self, new_owner: AccountId) {
+        self.0 = Some(new_owner);
+    }
+
+    /// Make the predecessor the owner if it is the proposed owner, and emit an
+    /// `ownership_transferred` event under the contract's event `standard`.
+    pub fn accept(&mut self, owner: &mut AccountId, standard: &str) {
+        let caller = env::predecessor_account_id();
+        require!(
+            self.0.as_ref() == Some(&caller),
+            "Only the pending owner can accept ownership"
+        );
+        self.0 = None;
+        let previous_owner = std::mem::replace(owner, caller);
+
+        emit_event(
+            standard,
+            &OwnershipEvent::OwnershipTransferred {
+                previous_owner: &previous_owner,
+                new_owner: owner,
+            },
+        );
+    }
+
+    /// The proposed owner, if any.
+    pub fn get(&self) -> Option<AccountId> {
+        self.0.clone()
+    }
+}
@@ -1,5 +1,7 @@
 //! Core type definitions for the Nest Optimistic Oracle.
 
+use near_sdk::env;
+
 /// A 32-byte fixed-size array used for identifiers, claims, and hashes.
 ///
 /// This type is used throughout the oracle for:
@@ -17,3 +19,24 @@ pub type Bytes32 = [u8; 32];
 /// - Vote request IDs
 /// - Commit hashes in commit-reveal voting
 pub type CryptoHash = [u8; 32];
+
+/// Computes the DVM vote request ID for a `request_price` call.
+///
+/// The voting contract hashes the identifier, timestamp, ancillary data and its
+/// `request_nonce` at the time of the request. Shared so the oracle can preview the ID
+/// a dispute escalates to.
+pub fn dvm_request_id(
+    identifier: &str,
+    timestamp: u64,
+    ancillary_data: &[u8],
+    request_nonce: u64,
+) -> CryptoHash {
+    let mut data = Vec::new();
+    data.extend_from_slice(identifier.as_bytes());
+    data.extend_from_slice(&timestamp.to_le_bytes());
+    data.extend_from_slice(ancillary_data);
+    data.extend_from_slice(&request_nonce.to_le_bytes());
+    env::sha256(&data)
+        .try_into()
+        .expect("Hash should be 32 bytes")
+}
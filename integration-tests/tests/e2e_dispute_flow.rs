@@ -8,11 +8,16 @@
 //! 5. Resolving the dispute based on DVM outcome
 
 use serde_json::json;
+use sha2::{Digest, Sha256};
 
 // WASM paths (built with `cargo near build non-reproducible-wasm`)
 const ORACLE_WASM: &str = "../target/near/optimistic_oracle/optimistic_oracle.wasm";
 const VOTING_TOKEN_WASM: &str = "../target/near/voting_token/voting_token.wasm";
 const VOTING_WASM: &str = "../target/near/voting/voting.wasm";
+const REGISTRY_WASM: &str = "../target/near/registry/registry.wasm";
+const BASIC_ASSERTION_WASM: &str = "../target/near/basic_assertion/basic_assertion.wasm";
+const BASE_ESCALATION_MANAGER_WASM: &str =
+    "../target/near/base_escalation_manager/base_escalation_manager.wasm";
 
 /// Helper to read WASM file
 async fn read_wasm(path: &str) -> Vec<u8> {
@@ -24,6 +29,21 @@ async fn read_wasm(path: &str) -> Vec<u8> {
     })
 }
 
+/// Prepay oracle storage for the assertions `account` creates
+async fn deposit_assertion_storage(
+    account: &near_workspaces::Account,
+    oracle: &near_workspaces::AccountId,
+) -> Result<(), Box<dyn std::error::Error>> {
+    account
+        .call(oracle, "deposit_assertion_storage")
+        .args_json(json!({}))
+        .deposit(near_workspaces::types::NearToken::from_millinear(100))
+        .transact()
+        .await?
+        .into_result()?;
+    Ok(())
+}
+
 /// Test the DVM voting flow in isolation
 #[tokio::test]
 async fn test_dvm_voting_flow() -> Result<(), Box<dyn std::error::Error>> {
@@ -433,6 +453,7 @@ async fn test_full_dvm_dispute_flow() -> Result<(), Box<dyn std::error::Error>>
         "asserter": asserter.id()
     });
 
+    deposit_assertion_storage(&asserter, oracle.id()).await?;
     let outcome = asserter
         .call(token.id(), "ft_transfer_call")
         .args_json(json!({
@@ -488,6 +509,1521 @@ async fn test_full_dvm_dispute_flow() -> Result<(), Box<dyn std::error::Error>>
     Ok(())
 }
 
+/// Test that a settlement payout registers an unregistered winner with the bond token
+#[tokio::test]
+async fn test_payout_registers_unregistered_asserter() -> Result<(), Box<dyn std::error::Error>> {
+    let sandbox = near_workspaces::sandbox().await?;
+
+    let oracle_wasm = read_wasm(ORACLE_WASM).await;
+    let token_wasm = read_wasm(VOTING_TOKEN_WASM).await;
+
+    let oracle = sandbox.dev_deploy(&oracle_wasm).await?;
+    let token = sandbox.dev_deploy(&token_wasm).await?;
+
+    let owner = sandbox.dev_create_account().await?;
+    let funder = sandbox.dev_create_account().await?;
+    // Never registered with the bond token
+    let asserter = sandbox.dev_create_account().await?;
+
+    token
+        .call("new")
+        .args_json(json!({
+            "owner": owner.id(),
+            "total_supply": "0"
+        }))
+        .transact()
+        .await?
+        .into_result()?;
+
+    owner
+        .call(token.id(), "set_transfer_restricted")
+        .args_json(json!({ "restricted": false }))
+        .transact()
+        .await?
+        .into_result()?;
+
+    oracle
+        .call("new")
+        .args_json(json!({
+            "owner": owner.id(),
+            "default_currency": token.id()
+        }))
+        .transact()
+        .await?
+        .into_result()?;
+
+    owner
+        .call(oracle.id(), "whitelist_currency")
+        .args_json(json!({
+            "currency": token.id(),
+            "final_fee": "1000000000000000000" // 1 token
+        }))
+        .transact()
+        .await?
+        .into_result()?;
+
+    owner
+        .call(oracle.id(), "set_payout_storage_deposit")
+        .args_json(json!({ "deposit": "1250000000000000000000" })) // 0.00125 NEAR
+        .transact()
+        .await?
+        .into_result()?;
+
+    owner
+        .call(token.id(), "add_minter")
+        .args_json(json!({ "account_id": owner.id() }))
+        .transact()
+        .await?
+        .into_result()?;
+
+    for account in [&funder, &oracle.as_account()] {
+        account
+            .call(token.id(), "storage_deposit")
+            .args_json(json!({}))
+            .deposit(near_workspaces::types::NearToken::from_millinear(10))
+            .transact()
+            .await?
+            .into_result()?;
+    }
+
+    let bond_amount = "2000000000000000000"; // 2 tokens (min bond)
+    owner
+        .call(token.id(), "mint")
+        .args_json(json!({
+            "account_id": funder.id(),
+            "amount": bond_amount
+        }))
+        .transact()
+        .await?
+        .into_result()?;
+
+    // Funder posts the bond on behalf of the unregistered asserter
+    let assertion_id = [9u8; 32];
+    let claim = [1u8; 32];
+    let assert_msg = json!({
+        "action": "AssertTruth",
+        "claim": claim,
+        "asserter": asserter.id(),
+        "liveness_ns": "1000000000", // 1 second
+        "assertion_id_override": assertion_id
+    });
+    deposit_assertion_storage(&funder, oracle.id()).await?;
+    funder
+        .call(token.id(), "ft_transfer_call")
+        .args_json(json!({
+            "receiver_id": oracle.id(),
+            "amount": bond_amount,
+            "msg": assert_msg.to_string()
+        }))
+        .deposit(near_workspaces::types::NearToken::from_yoctonear(1))
+        .gas(near_workspaces::types::Gas::from_tgas(100))
+        .transact()
+        .await?
+        .into_result()?;
+
+    sandbox.fast_forward(10).await?;
+
+    owner
+        .call(oracle.id(), "settle_assertion")
+        .args_json(json!({ "assertion_id": assertion_id }))
+        .gas(near_workspaces::types::Gas::from_tgas(200))
+        .transact()
+        .await?
+        .into_result()?;
+
+    let asserter_storage: Option<serde_json::Value> = token
+        .view("storage_balance_of")
+        .args_json(json!({ "account_id": asserter.id() }))
+        .await?
+        .json()?;
+    assert!(asserter_storage.is_some(), "Asserter should be registered");
+
+    let asserter_balance: String = token
+        .view("ft_balance_of")
+        .args_json(json!({ "account_id": asserter.id() }))
+        .await?
+        .json()?;
+    assert_eq!(asserter_balance, bond_amount);
+
+    let settled: bool = oracle
+        .view("get_assertion_result")
+        .args_json(json!({ "assertion_id": assertion_id }))
+        .await?
+        .json()?;
+    assert!(settled);
+    println!("✅ Unregistered asserter auto-registered and paid");
+
+    Ok(())
+}
+
+/// Test that a registry-gated oracle refunds unregistered callers and accepts registered ones
+#[tokio::test]
+async fn test_registry_gates_assertion_callers() -> Result<(), Box<dyn std::error::Error>> {
+    let sandbox = near_workspaces::sandbox().await?;
+
+    let oracle_wasm = read_wasm(ORACLE_WASM).await;
+    let token_wasm = read_wasm(VOTING_TOKEN_WASM).await;
+    let registry_wasm = read_wasm(REGISTRY_WASM).await;
+
+    let oracle = sandbox.dev_deploy(&oracle_wasm).await?;
+    let token = sandbox.dev_deploy(&token_wasm).await?;
+    let registry = sandbox.dev_deploy(&registry_wasm).await?;
+
+    let owner = sandbox.dev_create_account().await?;
+    let registered = sandbox.dev_create_account().await?;
+    let unregistered = sandbox.dev_create_account().await?;
+
+    token
+        .call("new")
+        .args_json(json!({
+            "owner": owner.id(),
+            "total_supply": "0"
+        }))
+        .transact()
+        .await?
+        .into_result()?;
+
+    owner
+        .call(token.id(), "set_transfer_restricted")
+        .args_json(json!({ "restricted": false }))
+        .transact()
+        .await?
+        .into_result()?;
+
+    registry
+        .call("new")
+        .args_json(json!({ "owner": owner.id() }))
+        .transact()
+        .await?
+        .into_result()?;
+
+    owner
+        .call(registry.id(), "register_contract")
+        .args_json(json!({ "contract_address": registered.id() }))
+        .transact()
+        .await?
+        .into_result()?;
+
+    oracle
+        .call("new")
+        .args_json(json!({
+            "owner": owner.id(),
+            "default_currency": token.id()
+        }))
+        .transact()
+        .await?
+        .into_result()?;
+
+    owner
+        .call(oracle.id(), "whitelist_currency")
+        .args_json(json!({
+            "currency": token.id(),
+            "final_fee": "1000000000000000000" // 1 token
+        }))
+        .transact()
+        .await?
+        .into_result()?;
+
+    owner
+        .call(oracle.id(), "set_registry")
+        .args_json(json!({ "registry": registry.id() }))
+        .transact()
+        .await?
+        .into_result()?;
+
+    owner
+        .call(token.id(), "add_minter")
+        .args_json(json!({ "account_id": owner.id() }))
+        .transact()
+        .await?
+        .into_result()?;
+
+    let bond_amount = "2000000000000000000"; // 2 tokens (min bond)
+    for account in [&registered, &unregistered, &oracle.as_account()] {
+        account
+            .call(token.id(), "storage_deposit")
+            .args_json(json!({}))
+            .deposit(near_workspaces::types::NearToken::from_millinear(10))
+            .transact()
+            .await?
+            .into_result()?;
+    }
+    for account in [&registered, &unregistered] {
+        owner
+            .call(token.id(), "mint")
+            .args_json(json!({
+                "account_id": account.id(),
+                "amount": bond_amount
+            }))
+            .transact()
+            .await?
+            .into_result()?;
+    }
+
+    let claim = [1u8; 32];
+    let unregistered_assertion_id = [1u8; 32];
+    let registered_assertion_id = [2u8; 32];
+    for (caller, assertion_id) in [
+        (&unregistered, unregistered_assertion_id),
+        (&registered, registered_assertion_id),
+    ] {
+        let assert_msg = json!({
+            "action": "AssertTruth",
+            "claim": claim,
+            "asserter": caller.id(),
+            "assertion_id_override": assertion_id
+        });
+        deposit_assertion_storage(&caller, oracle.id()).await?;
+        caller
+            .call(token.id(), "ft_transfer_call")
+            .args_json(json!({
+                "receiver_id": oracle.id(),
+                "amount": bond_amount,
+                "msg": assert_msg.to_string()
+            }))
+            .deposit(near_workspaces::types::NearToken::from_yoctonear(1))
+            .gas(near_workspaces::types::Gas::from_tgas(150))
+            .transact()
+            .await?
+            .into_result()?;
+    }
+
+    // Unregistered caller: no assertion and the full bond is refunded
+    let rejected: Option<serde_json::Value> = oracle
+        .view("get_assertion")
+        .args_json(json!({ "assertion_id": unregistered_assertion_id }))
+        .await?
+        .json()?;
+    assert!(
+        rejected.is_none(),
+        "Unregistered caller's assertion should not exist"
+    );
+    let unregistered_balance: String = token
+        .view("ft_balance_of")
+        .args_json(json!({ "account_id": unregistered.id() }))
+        .await?
+        .json()?;
+    assert_eq!(unregistered_balance, bond_amount);
+    println!("✅ Unregistered caller refunded");
+
+    // Registered caller: assertion created and the bond is held by the oracle
+    let accepted: Option<serde_json::Value> = oracle
+        .view("get_assertion")
+        .args_json(json!({ "assertion_id": registered_assertion_id }))
+        .await?
+        .json()?;
+    assert!(
+        accepted.is_some(),
+        "Registered caller's assertion should exist"
+    );
+    let registered_balance: String = token
+        .view("ft_balance_of")
+        .args_json(json!({ "account_id": registered.id() }))
+        .await?
+        .json()?;
+    assert_eq!(registered_balance, "0");
+    println!("✅ Registered caller's assertion created");
+
+    Ok(())
+}
+
+/// Test that rescue_tokens only releases voting tokens not backing a commitment
+#[tokio::test]
+async fn test_rescue_stranded_voting_tokens() -> Result<(), Box<dyn std::error::Error>> {
+    let sandbox = near_workspaces::sandbox().await?;
+
+    let token_wasm = read_wasm(VOTING_TOKEN_WASM).await;
+    let voting_wasm = read_wasm(VOTING_WASM).await;
+    let token = sandbox.dev_deploy(&token_wasm).await?;
+    let voting = sandbox.dev_deploy(&voting_wasm).await?;
+
+    let owner = sandbox.dev_create_account().await?;
+    let voter = sandbox.dev_create_account().await?;
+
+    token
+        .call("new")
+        .args_json(json!({
+            "owner": owner.id(),
+            "total_supply": "1000000000000000000000000",
+            "name": "Voting Token",
+            "symbol": "VOTE",
+            "decimals": 18
+        }))
+        .transact()
+        .await?
+        .into_result()?;
+    owner
+        .call(token.id(), "set_transfer_restricted")
+        .args_json(json!({ "restricted": false }))
+        .transact()
+        .await?
+        .into_result()?;
+    owner
+        .call(token.id(), "add_minter")
+        .args_json(json!({ "account_id": owner.id() }))
+        .transact()
+        .await?
+        .into_result()?;
+
+    voting
+        .call("new")
+        .args_json(json!({ "owner": owner.id() }))
+        .transact()
+        .await?
+        .into_result()?;
+    owner
+        .call(voting.id(), "set_voting_token")
+        .args_json(json!({ "voting_token": token.id() }))
+        .transact()
+        .await?
+        .into_result()?;
+
+    for account in [voter.id(), voting.id()] {
+        owner
+            .call(token.id(), "storage_deposit")
+            .args_json(json!({ "account_id": account }))
+            .deposit(near_workspaces::types::NearToken::from_millinear(10))
+            .transact()
+            .await?
+            .into_result()?;
+    }
+    owner
+        .call(token.id(), "mint")
+        .args_json(json!({
+            "account_id": voter.id(),
+            "amount": "100000000000000000000" // 100 tokens
+        }))
+        .transact()
+        .await?
+        .into_result()?;
+
+    // Commit 60 tokens of stake
+    let request_id: Vec<u8> = owner
+        .call(voting.id(), "request_price")
+        .args_json(json!({
+            "identifier": "YES_OR_NO_QUERY",
+            "timestamp": 1000u64,
+            "ancillary_data": [116, 101, 115, 116]
+        }))
+        .transact()
+        .await?
+        .json()?;
+    let commit_hash = [0u8; 32];
+    let commit_msg = json!({
+        "action": "CommitVote",
+        "request_id": request_id,
+        "commit_hash": commit_hash
+    });
+    voter
+        .call(token.id(), "ft_transfer_call")
+        .args_json(json!({
+            "receiver_id": voting.id(),
+            "amount": "60000000000000000000",
+            "msg": commit_msg.to_string()
+        }))
+        .deposit(near_workspaces::types::NearToken::from_yoctonear(1))
+        .gas(near_workspaces::types::Gas::from_tgas(100))
+        .transact()
+        .await?
+        .into_result()?;
+
+    // Strand 15 tokens with a plain transfer
+    voter
+        .call(token.id(), "ft_transfer")
+        .args_json(json!({
+            "receiver_id": voting.id(),
+            "amount": "15000000000000000000"
+        }))
+        .deposit(near_workspaces::types::NearToken::from_yoctonear(1))
+        .transact()
+        .await?
+        .into_result()?;
+
+    // Rescuing more than the stranded surplus would dip into committed stake
+    let outcome = owner
+        .call(voting.id(), "rescue_tokens")
+        .args_json(json!({
+            "token": token.id(),
+            "receiver_id": voter.id(),
+            "amount": "16000000000000000000"
+        }))
+        .gas(near_workspaces::types::Gas::from_tgas(100))
+        .transact()
+        .await?;
+    assert!(outcome.is_failure(), "rescue beyond surplus should fail");
+
+    owner
+        .call(voting.id(), "rescue_tokens")
+        .args_json(json!({
+            "token": token.id(),
+            "receiver_id": voter.id(),
+            "amount": "15000000000000000000"
+        }))
+        .gas(near_workspaces::types::Gas::from_tgas(100))
+        .transact()
+        .await?
+        .into_result()?;
+
+    let voting_balance: String = token
+        .view("ft_balance_of")
+        .args_json(json!({ "account_id": voting.id() }))
+        .await?
+        .json()?;
+    assert_eq!(voting_balance, "60000000000000000000");
+    let voter_balance: String = token
+        .view("ft_balance_of")
+        .args_json(json!({ "account_id": voter.id() }))
+        .await?
+        .json()?;
+    assert_eq!(voter_balance, "40000000000000000000");
+    println!("✅ Only the stranded surplus was rescued");
+
+    Ok(())
+}
+
+/// Test that sweep_to_treasury moves a voting token surplus to the treasury but never
+/// committed stake
+#[tokio::test]
+async fn test_sweep_surplus_to_treasury() -> Result<(), Box<dyn std::error::Error>> {
+    let sandbox = near_workspaces::sandbox().await?;
+
+    let token_wasm = read_wasm(VOTING_TOKEN_WASM).await;
+    let voting_wasm = read_wasm(VOTING_WASM).await;
+    let token = sandbox.dev_deploy(&token_wasm).await?;
+    let voting = sandbox.dev_deploy(&voting_wasm).await?;
+
+    let owner = sandbox.dev_create_account().await?;
+    let voter = sandbox.dev_create_account().await?;
+    let treasury = sandbox.dev_create_account().await?;
+
+    token
+        .call("new")
+        .args_json(json!({
+            "owner": owner.id(),
+            "total_supply": "1000000000000000000000000",
+            "name": "Voting Token",
+            "symbol": "VOTE",
+            "decimals": 18
+        }))
+        .transact()
+        .await?
+        .into_result()?;
+    owner
+        .call(token.id(), "set_transfer_restricted")
+        .args_json(json!({ "restricted": false }))
+        .transact()
+        .await?
+        .into_result()?;
+    owner
+        .call(token.id(), "add_minter")
+        .args_json(json!({ "account_id": owner.id() }))
+        .transact()
+        .await?
+        .into_result()?;
+
+    voting
+        .call("new")
+        .args_json(json!({ "owner": owner.id() }))
+        .transact()
+        .await?
+        .into_result()?;
+    owner
+        .call(voting.id(), "set_voting_token")
+        .args_json(json!({ "voting_token": token.id() }))
+        .transact()
+        .await?
+        .into_result()?;
+    owner
+        .call(voting.id(), "set_treasury")
+        .args_json(json!({ "treasury": treasury.id() }))
+        .transact()
+        .await?
+        .into_result()?;
+
+    for account in [voter.id(), voting.id(), treasury.id()] {
+        owner
+            .call(token.id(), "storage_deposit")
+            .args_json(json!({ "account_id": account }))
+            .deposit(near_workspaces::types::NearToken::from_millinear(10))
+            .transact()
+            .await?
+            .into_result()?;
+    }
+    owner
+        .call(token.id(), "mint")
+        .args_json(json!({
+            "account_id": voter.id(),
+            "amount": "100000000000000000000" // 100 tokens
+        }))
+        .transact()
+        .await?
+        .into_result()?;
+
+    // Commit 60 tokens of stake
+    let request_id: Vec<u8> = owner
+        .call(voting.id(), "request_price")
+        .args_json(json!({
+            "identifier": "YES_OR_NO_QUERY",
+            "timestamp": 1000u64,
+            "ancillary_data": [116, 101, 115, 116]
+        }))
+        .transact()
+        .await?
+        .json()?;
+    let commit_hash = [0u8; 32];
+    let commit_msg = json!({
+        "action": "CommitVote",
+        "request_id": request_id,
+        "commit_hash": commit_hash
+    });
+    voter
+        .call(token.id(), "ft_transfer_call")
+        .args_json(json!({
+            "receiver_id": voting.id(),
+            "amount": "60000000000000000000",
+            "msg": commit_msg.to_string()
+        }))
+        .deposit(near_workspaces::types::NearToken::from_yoctonear(1))
+        .gas(near_workspaces::types::Gas::from_tgas(100))
+        .transact()
+        .await?
+        .into_result()?;
+
+    // Accumulate a 25 token surplus outside of any commitment, as a failed treasury
+    // transfer would leave behind
+    voter
+        .call(token.id(), "ft_transfer")
+        .args_json(json!({
+            "receiver_id": voting.id(),
+            "amount": "25000000000000000000"
+        }))
+        .deposit(near_workspaces::types::NearToken::from_yoctonear(1))
+        .transact()
+        .await?
+        .into_result()?;
+
+    // Sweeping more than the surplus would dip into committed stake
+    let outcome = owner
+        .call(voting.id(), "sweep_to_treasury")
+        .args_json(json!({
+            "token": token.id(),
+            "amount": "26000000000000000000"
+        }))
+        .gas(near_workspaces::types::Gas::from_tgas(100))
+        .transact()
+        .await?;
+    assert!(outcome.is_failure(), "sweep beyond surplus should fail");
+
+    owner
+        .call(voting.id(), "sweep_to_treasury")
+        .args_json(json!({
+            "token": token.id(),
+            "amount": "25000000000000000000"
+        }))
+        .gas(near_workspaces::types::Gas::from_tgas(100))
+        .transact()
+        .await?
+        .into_result()?;
+
+    let voting_balance: String = token
+        .view("ft_balance_of")
+        .args_json(json!({ "account_id": voting.id() }))
+        .await?
+        .json()?;
+    assert_eq!(voting_balance, "60000000000000000000");
+    let treasury_balance: String = token
+        .view("ft_balance_of")
+        .args_json(json!({ "account_id": treasury.id() }))
+        .await?
+        .json()?;
+    assert_eq!(treasury_balance, "25000000000000000000");
+    println!("✅ Only the surplus was swept to the treasury");
+
+    Ok(())
+}
+
+/// Test that a payout to a recipient not registered with the voting token is recorded and
+/// can be retried once the recipient registers
+#[tokio::test]
+async fn test_failed_treasury_transfer_is_retried() -> Result<(), Box<dyn std::error::Error>> {
+    let sandbox = near_workspaces::sandbox().await?;
+
+    let token_wasm = read_wasm(VOTING_TOKEN_WASM).await;
+    let voting_wasm = read_wasm(VOTING_WASM).await;
+    let token = sandbox.dev_deploy(&token_wasm).await?;
+    let voting = sandbox.dev_deploy(&voting_wasm).await?;
+
+    let owner = sandbox.dev_create_account().await?;
+    let voter = sandbox.dev_create_account().await?;
+    // Not registered with the voting token until after resolution
+    let treasury = sandbox.dev_create_account().await?;
+
+    token
+        .call("new")
+        .args_json(json!({
+            "owner": owner.id(),
+            "total_supply": "0"
+        }))
+        .transact()
+        .await?
+        .into_result()?;
+    owner
+        .call(token.id(), "set_transfer_restricted")
+        .args_json(json!({ "restricted": false }))
+        .transact()
+        .await?
+        .into_result()?;
+    owner
+        .call(token.id(), "add_minter")
+        .args_json(json!({ "account_id": owner.id() }))
+        .transact()
+        .await?
+        .into_result()?;
+
+    voting
+        .call("new")
+        .args_json(json!({ "owner": owner.id() }))
+        .transact()
+        .await?
+        .into_result()?;
+    for (method, args) in [
+        (
+            "set_commit_phase_duration",
+            json!({ "duration_ns": 1_000_000_000u64 }),
+        ),
+        (
+            "set_reveal_phase_duration",
+            json!({ "duration_ns": 1_000_000_000u64 }),
+        ),
+        ("set_voting_token", json!({ "voting_token": token.id() })),
+        ("set_treasury", json!({ "treasury": treasury.id() })),
+        // 10% of the winner's stake goes to the treasury at resolution
+        ("set_resolution_fee_bps", json!({ "bps": 1_000 })),
+    ] {
+        owner
+            .call(voting.id(), method)
+            .args_json(args)
+            .transact()
+            .await?
+            .into_result()?;
+    }
+
+    for account in [voter.id(), voting.id()] {
+        owner
+            .call(token.id(), "storage_deposit")
+            .args_json(json!({ "account_id": account }))
+            .deposit(near_workspaces::types::NearToken::from_millinear(10))
+            .transact()
+            .await?
+            .into_result()?;
+    }
+    owner
+        .call(token.id(), "mint")
+        .args_json(json!({
+            "account_id": voter.id(),
+            "amount": "100000000000000000000" // 100 tokens
+        }))
+        .transact()
+        .await?
+        .into_result()?;
+
+    let request_id: [u8; 32] = owner
+        .call(voting.id(), "request_price")
+        .args_json(json!({
+            "identifier": "PRICE_QUERY",
+            "timestamp": 1000u64,
+            "ancillary_data": [116, 101, 115, 116]
+        }))
+        .transact()
+        .await?
+        .json()?;
+
+    // commit_hash = sha256(request_id || price || salt)
+    let price: i128 = 42;
+    let salt = [3u8; 32];
+    let mut preimage = request_id.to_vec();
+    preimage.extend_from_slice(&price.to_le_bytes());
+    preimage.extend_from_slice(&salt);
+    let commit_hash: [u8; 32] = Sha256::digest(&preimage).into();
+    let commit_msg = json!({
+        "action": "CommitVote",
+        "request_id": request_id,
+        "commit_hash": commit_hash
+    });
+    voter
+        .call(token.id(), "ft_transfer_call")
+        .args_json(json!({
+            "receiver_id": voting.id(),
+            "amount": "100000000000000000000",
+            "msg": commit_msg.to_string()
+        }))
+        .deposit(near_workspaces::types::NearToken::from_yoctonear(1))
+        .gas(near_workspaces::types::Gas::from_tgas(100))
+        .transact()
+        .await?
+        .into_result()?;
+
+    sandbox.fast_forward(5).await?;
+    owner
+        .call(voting.id(), "advance_to_reveal")
+        .args_json(json!({ "request_id": request_id }))
+        .transact()
+        .await?
+        .into_result()?;
+    voter
+        .call(voting.id(), "reveal_vote")
+        .args_json(json!({ "request_id": request_id, "price": price, "salt": salt }))
+        .transact()
+        .await?
+        .into_result()?;
+
+    sandbox.fast_forward(5).await?;
+    let outcome = owner
+        .call(voting.id(), "resolve_price")
+        .args_json(json!({ "request_id": request_id }))
+        .gas(near_workspaces::types::Gas::from_tgas(300))
+        .transact()
+        .await?;
+    assert!(outcome
+        .logs()
+        .iter()
+        .any(|log| log.contains("transfer_failed")));
+
+    let failed: String = voting
+        .view("get_failed_transfer")
+        .args_json(json!({ "request_id": request_id, "recipient": treasury.id() }))
+        .await?
+        .json()?;
+    assert_eq!(failed, "10000000000000000000");
+    println!("✅ Failed treasury transfer recorded");
+
+    treasury
+        .call(token.id(), "storage_deposit")
+        .args_json(json!({}))
+        .deposit(near_workspaces::types::NearToken::from_millinear(10))
+        .transact()
+        .await?
+        .into_result()?;
+    treasury
+        .call(voting.id(), "retry_transfer")
+        .args_json(json!({ "request_id": request_id, "recipient": treasury.id() }))
+        .gas(near_workspaces::types::Gas::from_tgas(100))
+        .transact()
+        .await?
+        .into_result()?;
+
+    let failed: String = voting
+        .view("get_failed_transfer")
+        .args_json(json!({ "request_id": request_id, "recipient": treasury.id() }))
+        .await?
+        .json()?;
+    assert_eq!(failed, "0");
+    let treasury_balance: String = token
+        .view("ft_balance_of")
+        .args_json(json!({ "account_id": treasury.id() }))
+        .await?
+        .json()?;
+    assert_eq!(treasury_balance, "10000000000000000000");
+    println!("✅ Retried transfer paid the registered treasury");
+
+    Ok(())
+}
+
+/// Test that a resolved callback rejected by the recipient can be re-sent once it is fixed
+#[tokio::test]
+async fn test_retry_failed_resolved_callback() -> Result<(), Box<dyn std::error::Error>> {
+    let sandbox = near_workspaces::sandbox().await?;
+
+    let oracle_wasm = read_wasm(ORACLE_WASM).await;
+    let token_wasm = read_wasm(VOTING_TOKEN_WASM).await;
+    let recipient_wasm = read_wasm(BASIC_ASSERTION_WASM).await;
+
+    let oracle = sandbox.dev_deploy(&oracle_wasm).await?;
+    let token = sandbox.dev_deploy(&token_wasm).await?;
+
+    let owner = sandbox.dev_create_account().await?;
+    let asserter = sandbox.dev_create_account().await?;
+    // No contract deployed yet, so the resolved callback fails
+    let recipient = sandbox.dev_create_account().await?;
+
+    token
+        .call("new")
+        .args_json(json!({
+            "owner": owner.id(),
+            "total_supply": "0"
+        }))
+        .transact()
+        .await?
+        .into_result()?;
+
+    owner
+        .call(token.id(), "set_transfer_restricted")
+        .args_json(json!({ "restricted": false }))
+        .transact()
+        .await?
+        .into_result()?;
+
+    oracle
+        .call("new")
+        .args_json(json!({
+            "owner": owner.id(),
+            "default_currency": token.id()
+        }))
+        .transact()
+        .await?
+        .into_result()?;
+
+    owner
+        .call(oracle.id(), "whitelist_currency")
+        .args_json(json!({
+            "currency": token.id(),
+            "final_fee": "1000000000000000000" // 1 token
+        }))
+        .transact()
+        .await?
+        .into_result()?;
+
+    owner
+        .call(token.id(), "add_minter")
+        .args_json(json!({ "account_id": owner.id() }))
+        .transact()
+        .await?
+        .into_result()?;
+
+    for account in [&asserter, &oracle.as_account()] {
+        account
+            .call(token.id(), "storage_deposit")
+            .args_json(json!({}))
+            .deposit(near_workspaces::types::NearToken::from_millinear(10))
+            .transact()
+            .await?
+            .into_result()?;
+    }
+
+    let bond_amount = "2000000000000000000"; // 2 tokens (min bond)
+    owner
+        .call(token.id(), "mint")
+        .args_json(json!({
+            "account_id": asserter.id(),
+            "amount": bond_amount
+        }))
+        .transact()
+        .await?
+        .into_result()?;
+
+    let assertion_id = [11u8; 32];
+    let claim = [1u8; 32];
+    let assert_msg = json!({
+        "action": "AssertTruth",
+        "claim": claim,
+        "asserter": asserter.id(),
+        "callback_recipient": recipient.id(),
+        "liveness_ns": "1000000000", // 1 second
+        "assertion_id_override": assertion_id
+    });
+    deposit_assertion_storage(&asserter, oracle.id()).await?;
+    asserter
+        .call(token.id(), "ft_transfer_call")
+        .args_json(json!({
+            "receiver_id": oracle.id(),
+            "amount": bond_amount,
+            "msg": assert_msg.to_string()
+        }))
+        .deposit(near_workspaces::types::NearToken::from_yoctonear(1))
+        .gas(near_workspaces::types::Gas::from_tgas(100))
+        .transact()
+        .await?
+        .into_result()?;
+
+    sandbox.fast_forward(10).await?;
+
+    owner
+        .call(oracle.id(), "settle_assertion")
+        .args_json(json!({ "assertion_id": assertion_id }))
+        .gas(near_workspaces::types::Gas::from_tgas(200))
+        .transact()
+        .await?
+        .into_result()?;
+
+    let assertion: serde_json::Value = oracle
+        .view("get_assertion")
+        .args_json(json!({ "assertion_id": assertion_id }))
+        .await?
+        .json()?;
+    assert_eq!(assertion["settled"], true);
+    assert_eq!(assertion["callback_delivered"], false);
+
+    // Recipient deploys its contract, then anyone retries the callback
+    recipient.deploy(&recipient_wasm).await?.into_result()?;
+    recipient
+        .call(recipient.id(), "new")
+        .args_json(json!({
+            "oracle": oracle.id(),
+            "bond_token": token.id(),
+            "min_bond": bond_amount
+        }))
+        .transact()
+        .await?
+        .into_result()?;
+
+    owner
+        .call(oracle.id(), "retry_resolved_callback")
+        .args_json(json!({ "assertion_id": assertion_id }))
+        .gas(near_workspaces::types::Gas::from_tgas(50))
+        .transact()
+        .await?
+        .into_result()?;
+
+    let assertion: serde_json::Value = oracle
+        .view("get_assertion")
+        .args_json(json!({ "assertion_id": assertion_id }))
+        .await?
+        .json()?;
+    assert_eq!(assertion["callback_delivered"], true);
+
+    let last_result: Option<bool> = recipient
+        .view(recipient.id(), "get_last_assertion_result")
+        .await?
+        .json()?;
+    assert_eq!(last_result, Some(true));
+    println!("✅ Failed resolved callback re-sent and delivered");
+
+    Ok(())
+}
+
+/// Settling an assertion notifies its escalation manager
+#[tokio::test]
+async fn test_settlement_notifies_escalation_manager() -> Result<(), Box<dyn std::error::Error>> {
+    let sandbox = near_workspaces::sandbox().await?;
+
+    let oracle_wasm = read_wasm(ORACLE_WASM).await;
+    let token_wasm = read_wasm(VOTING_TOKEN_WASM).await;
+    let manager_wasm = read_wasm(BASE_ESCALATION_MANAGER_WASM).await;
+
+    let oracle = sandbox.dev_deploy(&oracle_wasm).await?;
+    let token = sandbox.dev_deploy(&token_wasm).await?;
+    let manager = sandbox.dev_deploy(&manager_wasm).await?;
+
+    let owner = sandbox.dev_create_account().await?;
+    let asserter = sandbox.dev_create_account().await?;
+
+    token
+        .call("new")
+        .args_json(json!({
+            "owner": owner.id(),
+            "total_supply": "0"
+        }))
+        .transact()
+        .await?
+        .into_result()?;
+
+    owner
+        .call(token.id(), "set_transfer_restricted")
+        .args_json(json!({ "restricted": false }))
+        .transact()
+        .await?
+        .into_result()?;
+
+    oracle
+        .call("new")
+        .args_json(json!({
+            "owner": owner.id(),
+            "default_currency": token.id()
+        }))
+        .transact()
+        .await?
+        .into_result()?;
+
+    manager
+        .call("new")
+        .args_json(json!({ "oracle": oracle.id() }))
+        .transact()
+        .await?
+        .into_result()?;
+
+    owner
+        .call(oracle.id(), "whitelist_currency")
+        .args_json(json!({
+            "currency": token.id(),
+            "final_fee": "1000000000000000000" // 1 token
+        }))
+        .transact()
+        .await?
+        .into_result()?;
+
+    owner
+        .call(token.id(), "add_minter")
+        .args_json(json!({ "account_id": owner.id() }))
+        .transact()
+        .await?
+        .into_result()?;
+
+    for account in [&asserter, &oracle.as_account()] {
+        account
+            .call(token.id(), "storage_deposit")
+            .args_json(json!({}))
+            .deposit(near_workspaces::types::NearToken::from_millinear(10))
+            .transact()
+            .await?
+            .into_result()?;
+    }
+
+    let bond_amount = "2000000000000000000"; // 2 tokens (min bond)
+    owner
+        .call(token.id(), "mint")
+        .args_json(json!({
+            "account_id": asserter.id(),
+            "amount": bond_amount
+        }))
+        .transact()
+        .await?
+        .into_result()?;
+
+    let assertion_id = [12u8; 32];
+    let claim = [2u8; 32];
+    let assert_msg = json!({
+        "action": "AssertTruth",
+        "claim": claim,
+        "asserter": asserter.id(),
+        "escalation_manager": manager.id(),
+        "liveness_ns": "1000000000", // 1 second
+        "assertion_id_override": assertion_id
+    });
+    deposit_assertion_storage(&asserter, oracle.id()).await?;
+    asserter
+        .call(token.id(), "ft_transfer_call")
+        .args_json(json!({
+            "receiver_id": oracle.id(),
+            "amount": bond_amount,
+            "msg": assert_msg.to_string()
+        }))
+        .deposit(near_workspaces::types::NearToken::from_yoctonear(1))
+        .gas(near_workspaces::types::Gas::from_tgas(100))
+        .transact()
+        .await?
+        .into_result()?;
+
+    sandbox.fast_forward(10).await?;
+
+    let settle = owner
+        .call(oracle.id(), "settle_assertion")
+        .args_json(json!({ "assertion_id": assertion_id }))
+        .gas(near_workspaces::types::Gas::from_tgas(200))
+        .transact()
+        .await?;
+    // The base manager rejects callers other than its oracle, so success means the
+    // oracle itself delivered the callback
+    assert!(settle
+        .receipt_outcomes()
+        .iter()
+        .any(|outcome| outcome.executor_id == *manager.id() && outcome.is_success()));
+    settle.into_result()?;
+
+    let assertion: serde_json::Value = oracle
+        .view("get_assertion")
+        .args_json(json!({ "assertion_id": assertion_id }))
+        .await?
+        .json()?;
+    assert_eq!(assertion["settled"], true);
+    println!("✅ Escalation manager notified on settlement");
+
+    Ok(())
+}
+
+/// The oracle's preview of a dispute's DVM request id matches the request it creates
+#[tokio::test]
+async fn test_preview_dispute_request_id_matches_dvm_request(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let sandbox = near_workspaces::sandbox().await?;
+
+    let oracle_wasm = read_wasm(ORACLE_WASM).await;
+    let token_wasm = read_wasm(VOTING_TOKEN_WASM).await;
+    let voting_wasm = read_wasm(VOTING_WASM).await;
+
+    let oracle = sandbox.dev_deploy(&oracle_wasm).await?;
+    let token = sandbox.dev_deploy(&token_wasm).await?;
+    let voting = sandbox.dev_deploy(&voting_wasm).await?;
+
+    let owner = sandbox.dev_create_account().await?;
+    let asserter = sandbox.dev_create_account().await?;
+    let disputer = sandbox.dev_create_account().await?;
+
+    token
+        .call("new")
+        .args_json(json!({
+            "owner": owner.id(),
+            "total_supply": "0"
+        }))
+        .transact()
+        .await?
+        .into_result()?;
+
+    owner
+        .call(token.id(), "set_transfer_restricted")
+        .args_json(json!({ "restricted": false }))
+        .transact()
+        .await?
+        .into_result()?;
+
+    voting
+        .call("new")
+        .args_json(json!({ "owner": owner.id() }))
+        .transact()
+        .await?
+        .into_result()?;
+
+    oracle
+        .call("new")
+        .args_json(json!({
+            "owner": owner.id(),
+            "default_currency": token.id(),
+            "voting_contract": voting.id()
+        }))
+        .transact()
+        .await?
+        .into_result()?;
+
+    owner
+        .call(oracle.id(), "whitelist_currency")
+        .args_json(json!({
+            "currency": token.id(),
+            "final_fee": "1000000000000000000" // 1 token
+        }))
+        .transact()
+        .await?
+        .into_result()?;
+
+    owner
+        .call(token.id(), "add_minter")
+        .args_json(json!({ "account_id": owner.id() }))
+        .transact()
+        .await?
+        .into_result()?;
+
+    let bond_amount = "2000000000000000000"; // 2 tokens (min bond)
+    for account in [&asserter, &disputer, &oracle.as_account()] {
+        account
+            .call(token.id(), "storage_deposit")
+            .args_json(json!({}))
+            .deposit(near_workspaces::types::NearToken::from_millinear(10))
+            .transact()
+            .await?
+            .into_result()?;
+    }
+    for account in [&asserter, &disputer] {
+        owner
+            .call(token.id(), "mint")
+            .args_json(json!({
+                "account_id": account.id(),
+                "amount": bond_amount
+            }))
+            .transact()
+            .await?
+            .into_result()?;
+    }
+
+    let assertion_id = [13u8; 32];
+    let claim = [3u8; 32];
+    let assert_msg = json!({
+        "action": "AssertTruth",
+        "claim": claim,
+        "asserter": asserter.id(),
+        "assertion_id_override": assertion_id
+    });
+    deposit_assertion_storage(&asserter, oracle.id()).await?;
+    asserter
+        .call(token.id(), "ft_transfer_call")
+        .args_json(json!({
+            "receiver_id": oracle.id(),
+            "amount": bond_amount,
+            "msg": assert_msg.to_string()
+        }))
+        .deposit(near_workspaces::types::NearToken::from_yoctonear(1))
+        .gas(near_workspaces::types::Gas::from_tgas(100))
+        .transact()
+        .await?
+        .into_result()?;
+
+    let request_nonce: u64 = voting.view("get_request_nonce").await?.json()?;
+
+    let evidence_uri = "ipfs://dispute-evidence";
+    let dispute_msg = json!({
+        "action": "DisputeAssertion",
+        "assertion_id": assertion_id,
+        "disputer": disputer.id(),
+        "evidence_uri": evidence_uri
+    });
+    disputer
+        .call(token.id(), "ft_transfer_call")
+        .args_json(json!({
+            "receiver_id": oracle.id(),
+            "amount": bond_amount,
+            "msg": dispute_msg.to_string()
+        }))
+        .deposit(near_workspaces::types::NearToken::from_yoctonear(1))
+        .gas(near_workspaces::types::Gas::from_tgas(200))
+        .transact()
+        .await?
+        .into_result()?;
+
+    let request_id: Option<[u8; 32]> = oracle
+        .view("get_dispute_request")
+        .args_json(json!({ "assertion_id": assertion_id }))
+        .await?
+        .json()?;
+    let request_id = request_id.expect("dispute should escalate to the DVM");
+
+    // The DVM request records the dispute time the oracle sent
+    let request: serde_json::Value = voting
+        .view("get_request")
+        .args_json(json!({ "request_id": request_id }))
+        .await?
+        .json()?;
+    let dispute_time_ns = request["timestamp"].as_u64().unwrap();
+
+    let preview: Option<[u8; 32]> = oracle
+        .view("preview_dispute_request_id")
+        .args_json(json!({
+            "assertion_id": assertion_id,
+            "dispute_time_ns": dispute_time_ns.to_string(),
+            "request_nonce": request_nonce.to_string(),
+            "evidence_uri": evidence_uri
+        }))
+        .await?
+        .json()?;
+    assert_eq!(preview, Some(request_id));
+    println!("✅ Previewed DVM request id matches the escalated dispute");
+
+    Ok(())
+}
+
+/// Resolving the DVM vote settles the disputed assertion without a `settle_assertion` call
+#[tokio::test]
+async fn test_dvm_resolution_settles_assertion() -> Result<(), Box<dyn std::error::Error>> {
+    let sandbox = near_workspaces::sandbox().await?;
+
+    let oracle_wasm = read_wasm(ORACLE_WASM).await;
+    let token_wasm = read_wasm(VOTING_TOKEN_WASM).await;
+    let voting_wasm = read_wasm(VOTING_WASM).await;
+
+    let oracle = sandbox.dev_deploy(&oracle_wasm).await?;
+    let token = sandbox.dev_deploy(&token_wasm).await?;
+    let voting = sandbox.dev_deploy(&voting_wasm).await?;
+
+    let owner = sandbox.dev_create_account().await?;
+    let asserter = sandbox.dev_create_account().await?;
+    let disputer = sandbox.dev_create_account().await?;
+    let voter = sandbox.dev_create_account().await?;
+
+    token
+        .call("new")
+        .args_json(json!({
+            "owner": owner.id(),
+            "total_supply": "0"
+        }))
+        .transact()
+        .await?
+        .into_result()?;
+
+    owner
+        .call(token.id(), "set_transfer_restricted")
+        .args_json(json!({ "restricted": false }))
+        .transact()
+        .await?
+        .into_result()?;
+
+    owner
+        .call(token.id(), "add_minter")
+        .args_json(json!({ "account_id": owner.id() }))
+        .transact()
+        .await?
+        .into_result()?;
+
+    voting
+        .call("new")
+        .args_json(json!({ "owner": owner.id() }))
+        .transact()
+        .await?
+        .into_result()?;
+
+    for (method, args) in [
+        (
+            "set_commit_phase_duration",
+            json!({ "duration_ns": 1_000_000_000u64 }),
+        ),
+        (
+            "set_reveal_phase_duration",
+            json!({ "duration_ns": 1_000_000_000u64 }),
+        ),
+        ("set_voting_token", json!({ "voting_token": token.id() })),
+        ("set_treasury", json!({ "treasury": owner.id() })),
+    ] {
+        owner
+            .call(voting.id(), method)
+            .args_json(args)
+            .transact()
+            .await?
+            .into_result()?;
+    }
+
+    oracle
+        .call("new")
+        .args_json(json!({
+            "owner": owner.id(),
+            "default_currency": token.id(),
+            "voting_contract": voting.id()
+        }))
+        .transact()
+        .await?
+        .into_result()?;
+
+    owner
+        .call(oracle.id(), "whitelist_currency")
+        .args_json(json!({
+            "currency": token.id(),
+            "final_fee": "1000000000000000000" // 1 token
+        }))
+        .transact()
+        .await?
+        .into_result()?;
+
+    let bond_amount = "2000000000000000000"; // 2 tokens (min bond)
+    for account in [
+        &owner,
+        &asserter,
+        &disputer,
+        &voter,
+        &oracle.as_account(),
+        &voting.as_account(),
+    ] {
+        account
+            .call(token.id(), "storage_deposit")
+            .args_json(json!({}))
+            .deposit(near_workspaces::types::NearToken::from_millinear(10))
+            .transact()
+            .await?
+            .into_result()?;
+    }
+    for account in [&asserter, &disputer, &voter] {
+        owner
+            .call(token.id(), "mint")
+            .args_json(json!({
+                "account_id": account.id(),
+                "amount": bond_amount
+            }))
+            .transact()
+            .await?
+            .into_result()?;
+    }
+
+    let assertion_id = [14u8; 32];
+    let claim = [4u8; 32];
+    let assert_msg = json!({
+        "action": "AssertTruth",
+        "claim": claim,
+        "asserter": asserter.id(),
+        "assertion_id_override": assertion_id
+    });
+    deposit_assertion_storage(&asserter, oracle.id()).await?;
+    asserter
+        .call(token.id(), "ft_transfer_call")
+        .args_json(json!({
+            "receiver_id": oracle.id(),
+            "amount": bond_amount,
+            "msg": assert_msg.to_string()
+        }))
+        .deposit(near_workspaces::types::NearToken::from_yoctonear(1))
+        .gas(near_workspaces::types::Gas::from_tgas(100))
+        .transact()
+        .await?
+        .into_result()?;
+
+    let dispute_msg = json!({
+        "action": "DisputeAssertion",
+        "assertion_id": assertion_id,
+        "disputer": disputer.id()
+    });
+    disputer
+        .call(token.id(), "ft_transfer_call")
+        .args_json(json!({
+            "receiver_id": oracle.id(),
+            "amount": bond_amount,
+            "msg": dispute_msg.to_string()
+        }))
+        .deposit(near_workspaces::types::NearToken::from_yoctonear(1))
+        .gas(near_workspaces::types::Gas::from_tgas(200))
+        .transact()
+        .await?
+        .into_result()?;
+
+    let request_id: Option<[u8; 32]> = oracle
+        .view("get_dispute_request")
+        .args_json(json!({ "assertion_id": assertion_id }))
+        .await?
+        .json()?;
+    let request_id = request_id.expect("dispute should escalate to the DVM");
+
+    // The voter sides with the disputer: commit_hash = sha256(request_id || price || salt)
+    let price: i128 = 0;
+    let salt = [9u8; 32];
+    let mut preimage = request_id.to_vec();
+    preimage.extend_from_slice(&price.to_le_bytes());
+    preimage.extend_from_slice(&salt);
+    let commit_hash: [u8; 32] = Sha256::digest(&preimage).into();
+    let commit_msg = json!({
+        "action": "CommitVote",
+        "request_id": request_id,
+        "commit_hash": commit_hash
+    });
+    voter
+        .call(token.id(), "ft_transfer_call")
+        .args_json(json!({
+            "receiver_id": voting.id(),
+            "amount": bond_amount,
+            "msg": commit_msg.to_string()
+        }))
+        .deposit(near_workspaces::types::NearToken::from_yoctonear(1))
+        .gas(near_workspaces::types::Gas::from_tgas(100))
+        .transact()
+        .await?
+        .into_result()?;
+
+    sandbox.fast_forward(5).await?;
+    owner
+        .call(voting.id(), "advance_to_reveal")
+        .args_json(json!({ "request_id": request_id }))
+        .transact()
+        .await?
+        .into_result()?;
+    voter
+        .call(voting.id(), "reveal_vote")
+        .args_json(json!({ "request_id": request_id, "price": price, "salt": salt }))
+        .transact()
+        .await?
+        .into_result()?;
+
+    sandbox.fast_forward(5).await?;
+    owner
+        .call(voting.id(), "resolve_price")
+        .args_json(json!({ "request_id": request_id }))
+        .gas(near_workspaces::types::Gas::from_tgas(300))
+        .transact()
+        .await?
+        .into_result()?;
+
+    // No settle_assertion call: the voting contract's callback settled the assertion
+    let assertion: serde_json::Value = oracle
+        .view("get_assertion")
+        .args_json(json!({ "assertion_id": assertion_id }))
+        .await?
+        .json()?;
+    assert_eq!(assertion["settled"], true);
+    assert_eq!(assertion["settlement_resolution"], false);
+    println!("✅ DVM resolution settled the assertion for the disputer");
+
+    Ok(())
+}
+
 /// Document the full conceptual flow
 #[tokio::test]
 async fn test_full_flow_documentation() -> Result<(), Box<dyn std::error::Error>> {
@@ -548,6 +2084,7 @@ async fn test_full_flow_documentation() -> Result<(), Box<dyn std::error::Error>
     println!("│ 2. DVM calculates stake-weighted median                         │");
     println!("│ 3. Result: 1e18 = TRUE (asserter wins), 0 = FALSE (disputer)    │");
     println!("│ 4. Event: PriceResolved                                         │");
+    println!("│ 5. Winning voters withdraw: voting.claim_reward(request_id)     │");
     println!("└─────────────────────────────────────────────────────────────────┘\n");
 
     println!("┌─────────────────────────────────────────────────────────────────┐");
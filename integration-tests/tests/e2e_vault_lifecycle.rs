@@ -56,6 +56,14 @@ async fn test_vault_collateral_stake_redeem_lifecycle() -> Result<(), Box<dyn st
         .await?
         .into_result()?;
 
+    owner
+        .call(vault.id(), "verify_tokens")
+        .args_json(json!({}))
+        .gas(near_workspaces::types::Gas::from_tgas(50))
+        .transact()
+        .await?
+        .into_result()?;
+
     owner
         .call(voting.id(), "set_voting_token")
         .args_json(json!({ "voting_token": nest.id() }))
@@ -235,3 +243,489 @@ async fn test_vault_collateral_stake_redeem_lifecycle() -> Result<(), Box<dyn st
 
     Ok(())
 }
+
+#[tokio::test]
+#[ignore = "Flaky under constrained CI sandboxes; run manually for end-to-end lifecycle validation"]
+async fn test_vault_scales_mint_across_token_decimals() -> Result<(), Box<dyn std::error::Error>> {
+    let sandbox = near_workspaces::sandbox().await?;
+
+    let token_wasm = near_workspaces::compile_project("../contracts/dvm/voting-token").await?;
+    let mock_ft_wasm = near_workspaces::compile_project("../contracts/examples/mock-ft").await?;
+    let vault_wasm = near_workspaces::compile_project("../contracts/dvm/vault").await?;
+
+    let collateral = sandbox.dev_deploy(&mock_ft_wasm).await?;
+    let nest = sandbox.dev_deploy(&token_wasm).await?;
+    let vault = sandbox.dev_deploy(&vault_wasm).await?;
+
+    let owner = sandbox.dev_create_account().await?;
+    let user = sandbox.dev_create_account().await?;
+
+    // 6-decimal collateral (USDC-like) against 24-decimal NEST.
+    collateral
+        .call("new")
+        .args_json(json!({
+            "owner": owner.id(),
+            "total_supply": "10000000",
+            "decimals": 6
+        }))
+        .transact()
+        .await?
+        .into_result()?;
+
+    nest.call("new")
+        .args_json(json!({
+            "owner": owner.id(),
+            "total_supply": "0"
+        }))
+        .transact()
+        .await?
+        .into_result()?;
+
+    vault
+        .call("new")
+        .args_json(json!({
+            "owner": owner.id(),
+            "collateral_token": collateral.id(),
+            "nest_token": nest.id(),
+            "emergency_recipient": owner.id()
+        }))
+        .transact()
+        .await?
+        .into_result()?;
+
+    owner
+        .call(vault.id(), "verify_tokens")
+        .args_json(json!({}))
+        .gas(near_workspaces::types::Gas::from_tgas(50))
+        .transact()
+        .await?
+        .into_result()?;
+
+    let decimals: Option<(u8, u8)> = vault
+        .view("get_token_decimals")
+        .args_json(json!({}))
+        .await?
+        .json()?;
+    assert_eq!(decimals, Some((6, 24)));
+
+    owner
+        .call(nest.id(), "set_vault_account")
+        .args_json(json!({ "vault_account": vault.id() }))
+        .transact()
+        .await?
+        .into_result()?;
+
+    for account in [&user, &vault.as_account()] {
+        account
+            .call(collateral.id(), "storage_deposit")
+            .args_json(json!({
+                "account_id": account.id(),
+                "registration_only": true
+            }))
+            .deposit(near_workspaces::types::NearToken::from_millinear(10))
+            .transact()
+            .await?
+            .into_result()?;
+        account
+            .call(nest.id(), "storage_deposit")
+            .args_json(json!({
+                "account_id": account.id(),
+                "registration_only": true
+            }))
+            .deposit(near_workspaces::types::NearToken::from_millinear(10))
+            .transact()
+            .await?
+            .into_result()?;
+    }
+
+    owner
+        .call(collateral.id(), "ft_transfer")
+        .args_json(json!({
+            "receiver_id": user.id(),
+            "amount": "5000000"
+        }))
+        .deposit(near_workspaces::types::NearToken::from_yoctonear(1))
+        .transact()
+        .await?
+        .into_result()?;
+
+    // Deposit 2.5 collateral units (6 decimals).
+    let deposit_outcome = user
+        .call(collateral.id(), "ft_transfer_call")
+        .args_json(json!({
+            "receiver_id": vault.id(),
+            "amount": "2500000",
+            "msg": serde_json::to_string(&json!({"action":"DepositCollateral"}))?
+        }))
+        .deposit(near_workspaces::types::NearToken::from_yoctonear(1))
+        .gas(near_workspaces::types::Gas::from_tgas(100))
+        .transact()
+        .await?;
+    assert!(deposit_outcome.is_success(), "{deposit_outcome:?}");
+
+    // 2.5 NEST at 24 decimals.
+    let user_nest: String = nest
+        .view("ft_balance_of")
+        .args_json(json!({ "account_id": user.id() }))
+        .await?
+        .json()?;
+    assert_eq!(user_nest, "2500000000000000000000000");
+
+    let diagnostics: serde_json::Value = vault
+        .view("get_invariant_diagnostics")
+        .args_json(json!({}))
+        .await?
+        .json()?;
+    assert_eq!(diagnostics["total_locked_collateral"], "2500000");
+    assert_eq!(
+        diagnostics["total_minted_liability"],
+        "2500000000000000000000000"
+    );
+    assert_eq!(diagnostics["backing_ratio_bps"], "10000");
+    assert_eq!(diagnostics["invariant_ok"], true);
+
+    Ok(())
+}
+
+#[tokio::test]
+#[ignore = "Flaky under constrained CI sandboxes; run manually for end-to-end lifecycle validation"]
+async fn test_vault_values_collateral_with_numerical_oracle_result(
+) -> Result<(), Box<dyn std::error::Error>> {
+    use sha2::{Digest, Sha256};
+
+    let sandbox = near_workspaces::sandbox().await?;
+
+    let token_wasm = near_workspaces::compile_project("../contracts/dvm/voting-token").await?;
+    let voting_wasm = near_workspaces::compile_project("../contracts/dvm/voting").await?;
+    let oracle_wasm = near_workspaces::compile_project("../contracts/optimistic-oracle").await?;
+    let vault_wasm = near_workspaces::compile_project("../contracts/dvm/vault").await?;
+
+    let bond_token = sandbox.dev_deploy(&token_wasm).await?;
+    let collateral = sandbox.dev_deploy(&token_wasm).await?;
+    let nest = sandbox.dev_deploy(&token_wasm).await?;
+    let voting = sandbox.dev_deploy(&voting_wasm).await?;
+    let oracle = sandbox.dev_deploy(&oracle_wasm).await?;
+    let vault = sandbox.dev_deploy(&vault_wasm).await?;
+
+    let owner = sandbox.dev_create_account().await?;
+    let asserter = sandbox.dev_create_account().await?;
+    let disputer = sandbox.dev_create_account().await?;
+    let voter = sandbox.dev_create_account().await?;
+    let user = sandbox.dev_create_account().await?;
+
+    for (token, total_supply) in [(&bond_token, "0"), (&collateral, "1000000"), (&nest, "0")] {
+        token
+            .call("new")
+            .args_json(json!({
+                "owner": owner.id(),
+                "total_supply": total_supply
+            }))
+            .transact()
+            .await?
+            .into_result()?;
+        owner
+            .call(token.id(), "set_transfer_restricted")
+            .args_json(json!({ "restricted": false }))
+            .transact()
+            .await?
+            .into_result()?;
+    }
+    owner
+        .call(bond_token.id(), "add_minter")
+        .args_json(json!({ "account_id": owner.id() }))
+        .transact()
+        .await?
+        .into_result()?;
+
+    // DVM and oracle, with the default identifier resolved as a numerical price
+    voting
+        .call("new")
+        .args_json(json!({ "owner": owner.id() }))
+        .transact()
+        .await?
+        .into_result()?;
+    for (method, args) in [
+        (
+            "set_commit_phase_duration",
+            json!({ "duration_ns": 1_000_000_000u64 }),
+        ),
+        (
+            "set_reveal_phase_duration",
+            json!({ "duration_ns": 1_000_000_000u64 }),
+        ),
+        (
+            "set_voting_token",
+            json!({ "voting_token": bond_token.id() }),
+        ),
+        ("set_treasury", json!({ "treasury": owner.id() })),
+    ] {
+        owner
+            .call(voting.id(), method)
+            .args_json(args)
+            .transact()
+            .await?
+            .into_result()?;
+    }
+
+    oracle
+        .call("new")
+        .args_json(json!({
+            "owner": owner.id(),
+            "default_currency": bond_token.id(),
+            "voting_contract": voting.id()
+        }))
+        .transact()
+        .await?
+        .into_result()?;
+    owner
+        .call(oracle.id(), "whitelist_currency")
+        .args_json(json!({
+            "currency": bond_token.id(),
+            "final_fee": "1000000000000000000"
+        }))
+        .transact()
+        .await?
+        .into_result()?;
+    let identifier: [u8; 32] = oracle
+        .view("default_identifier")
+        .args_json(json!({}))
+        .await?
+        .json()?;
+    owner
+        .call(oracle.id(), "set_resolution_threshold")
+        .args_json(json!({ "identifier": identifier, "threshold": 1 }))
+        .transact()
+        .await?
+        .into_result()?;
+
+    let bond_amount = "2000000000000000000";
+    for account in [
+        &owner,
+        &asserter,
+        &disputer,
+        &voter,
+        &oracle.as_account(),
+        &voting.as_account(),
+    ] {
+        account
+            .call(bond_token.id(), "storage_deposit")
+            .args_json(json!({}))
+            .deposit(near_workspaces::types::NearToken::from_millinear(10))
+            .transact()
+            .await?
+            .into_result()?;
+    }
+    for account in [&asserter, &disputer, &voter] {
+        owner
+            .call(bond_token.id(), "mint")
+            .args_json(json!({
+                "account_id": account.id(),
+                "amount": bond_amount
+            }))
+            .transact()
+            .await?
+            .into_result()?;
+    }
+
+    // Assert, dispute, and have the DVM price the collateral at 2 NEST
+    let assertion_id = [21u8; 32];
+    let claim = [5u8; 32];
+    let assert_msg = json!({
+        "action": "AssertTruth",
+        "claim": claim,
+        "asserter": asserter.id(),
+        "assertion_id_override": assertion_id
+    });
+    asserter
+        .call(oracle.id(), "deposit_assertion_storage")
+        .args_json(json!({}))
+        .deposit(near_workspaces::types::NearToken::from_millinear(100))
+        .transact()
+        .await?
+        .into_result()?;
+    asserter
+        .call(bond_token.id(), "ft_transfer_call")
+        .args_json(json!({
+            "receiver_id": oracle.id(),
+            "amount": bond_amount,
+            "msg": assert_msg.to_string()
+        }))
+        .deposit(near_workspaces::types::NearToken::from_yoctonear(1))
+        .gas(near_workspaces::types::Gas::from_tgas(100))
+        .transact()
+        .await?
+        .into_result()?;
+
+    let dispute_msg = json!({
+        "action": "DisputeAssertion",
+        "assertion_id": assertion_id,
+        "disputer": disputer.id()
+    });
+    disputer
+        .call(bond_token.id(), "ft_transfer_call")
+        .args_json(json!({
+            "receiver_id": oracle.id(),
+            "amount": bond_amount,
+            "msg": dispute_msg.to_string()
+        }))
+        .deposit(near_workspaces::types::NearToken::from_yoctonear(1))
+        .gas(near_workspaces::types::Gas::from_tgas(200))
+        .transact()
+        .await?
+        .into_result()?;
+
+    let request_id: Option<[u8; 32]> = oracle
+        .view("get_dispute_request")
+        .args_json(json!({ "assertion_id": assertion_id }))
+        .await?
+        .json()?;
+    let request_id = request_id.expect("dispute should escalate to the DVM");
+
+    let price: i128 = 2_000_000_000_000_000_000;
+    let salt = [3u8; 32];
+    let mut preimage = request_id.to_vec();
+    preimage.extend_from_slice(&price.to_le_bytes());
+    preimage.extend_from_slice(&salt);
+    let commit_hash: [u8; 32] = Sha256::digest(&preimage).into();
+    voter
+        .call(bond_token.id(), "ft_transfer_call")
+        .args_json(json!({
+            "receiver_id": voting.id(),
+            "amount": bond_amount,
+            "msg": json!({
+                "action": "CommitVote",
+                "request_id": request_id,
+                "commit_hash": commit_hash
+            })
+            .to_string()
+        }))
+        .deposit(near_workspaces::types::NearToken::from_yoctonear(1))
+        .gas(near_workspaces::types::Gas::from_tgas(100))
+        .transact()
+        .await?
+        .into_result()?;
+
+    sandbox.fast_forward(5).await?;
+    owner
+        .call(voting.id(), "advance_to_reveal")
+        .args_json(json!({ "request_id": request_id }))
+        .transact()
+        .await?
+        .into_result()?;
+    voter
+        .call(voting.id(), "reveal_vote")
+        .args_json(json!({ "request_id": request_id, "price": price, "salt": salt }))
+        .transact()
+        .await?
+        .into_result()?;
+
+    sandbox.fast_forward(5).await?;
+    owner
+        .call(voting.id(), "resolve_price")
+        .args_json(json!({ "request_id": request_id }))
+        .gas(near_workspaces::types::Gas::from_tgas(300))
+        .transact()
+        .await?
+        .into_result()?;
+
+    let numeric_result: Option<i128> = oracle
+        .view("get_assertion_numeric_result")
+        .args_json(json!({ "assertion_id": assertion_id }))
+        .await?
+        .json()?;
+    assert_eq!(numeric_result, Some(price));
+
+    // Vault with 100 collateral locked against 100 NEST
+    vault
+        .call("new")
+        .args_json(json!({
+            "owner": owner.id(),
+            "collateral_token": collateral.id(),
+            "nest_token": nest.id(),
+            "emergency_recipient": owner.id()
+        }))
+        .transact()
+        .await?
+        .into_result()?;
+    owner
+        .call(vault.id(), "verify_tokens")
+        .args_json(json!({}))
+        .gas(near_workspaces::types::Gas::from_tgas(50))
+        .transact()
+        .await?
+        .into_result()?;
+    owner
+        .call(nest.id(), "set_vault_account")
+        .args_json(json!({ "vault_account": vault.id() }))
+        .transact()
+        .await?
+        .into_result()?;
+
+    for account in [&user, &vault.as_account()] {
+        for token in [&collateral, &nest] {
+            account
+                .call(token.id(), "storage_deposit")
+                .args_json(json!({
+                    "account_id": account.id(),
+                    "registration_only": true
+                }))
+                .deposit(near_workspaces::types::NearToken::from_millinear(10))
+                .transact()
+                .await?
+                .into_result()?;
+        }
+    }
+    owner
+        .call(collateral.id(), "ft_transfer")
+        .args_json(json!({
+            "receiver_id": user.id(),
+            "amount": "100"
+        }))
+        .deposit(near_workspaces::types::NearToken::from_yoctonear(1))
+        .transact()
+        .await?
+        .into_result()?;
+    let deposit_outcome = user
+        .call(collateral.id(), "ft_transfer_call")
+        .args_json(json!({
+            "receiver_id": vault.id(),
+            "amount": "100",
+            "msg": serde_json::to_string(&json!({"action":"DepositCollateral"}))?
+        }))
+        .deposit(near_workspaces::types::NearToken::from_yoctonear(1))
+        .gas(near_workspaces::types::Gas::from_tgas(100))
+        .transact()
+        .await?;
+    assert!(deposit_outcome.is_success(), "{deposit_outcome:?}");
+
+    owner
+        .call(vault.id(), "set_price_oracle")
+        .args_json(json!({ "price_oracle": oracle.id(), "price_decimals": 18 }))
+        .transact()
+        .await?
+        .into_result()?;
+    owner
+        .call(vault.id(), "consume_oracle_price")
+        .args_json(json!({ "assertion_id": assertion_id }))
+        .gas(near_workspaces::types::Gas::from_tgas(50))
+        .transact()
+        .await?
+        .into_result()?;
+
+    let collateral_price: Option<String> = vault
+        .view("get_collateral_price")
+        .args_json(json!({}))
+        .await?
+        .json()?;
+    assert_eq!(collateral_price.as_deref(), Some("2000000000000000000"));
+
+    let diagnostics: serde_json::Value = vault
+        .view("get_invariant_diagnostics")
+        .args_json(json!({}))
+        .await?
+        .json()?;
+    assert_eq!(diagnostics["backing_ratio_bps"], "10000");
+    assert_eq!(diagnostics["valued_backing_ratio_bps"], "20000");
+
+    Ok(())
+}
@@ -63,9 +63,9 @@ function bytesToHex(bytes) {
 }
 
 /**
- * Compute vote hash: sha256(price_le_bytes || salt)
+ * Compute vote hash: sha256(request_id || price_le_bytes || salt)
  */
-function computeVoteHash(price, salt) {
+function computeVoteHash(requestId, price, salt) {
   // Convert price to i128 little-endian bytes (16 bytes)
   const priceBigInt = BigInt(price);
   const priceBuffer = Buffer.alloc(16);
@@ -77,9 +77,9 @@ function computeVoteHash(price, salt) {
     val >>= 8n;
   }
 
-  // Concatenate price bytes + salt bytes
+  // Concatenate request id + price bytes + salt bytes
   const saltBuffer = Buffer.from(salt);
-  const data = Buffer.concat([priceBuffer, saltBuffer]);
+  const data = Buffer.concat([Buffer.from(requestId), priceBuffer, saltBuffer]);
 
   // SHA256 hash
   const hash = createHash('sha256').update(data).digest();
@@ -141,6 +141,15 @@ async function main() {
     liveness_ns: '60000000000', // 1 minute liveness
   });
 
+  // Prepay the storage the assertion uses on the oracle
+  await account.functionCall({
+    contractId: CONFIG.oracleContract,
+    methodName: 'deposit_assertion_storage',
+    args: {},
+    gas: '30000000000000', // 30 TGas
+    attachedDeposit: '10000000000000000000000', // 0.01 NEAR
+  });
+
   const assertResult = await account.functionCall({
     contractId: CONFIG.tokenContract,
     methodName: 'ft_transfer_call',
@@ -235,7 +244,7 @@ async function main() {
   // In production, the contract would use I128 wrapper for safe serialization
   const votePrice = 0;  // 0 = FALSE (disputer wins), SCALE = TRUE (asserter wins)
   const salt = Array.from(createHash('sha256').update(`salt-${Date.now()}`).digest());
-  const commitHash = computeVoteHash(votePrice.toString(), salt);
+  const commitHash = computeVoteHash(dvmRequestId, votePrice.toString(), salt);
 
   console.log(`  Vote: TRUE (${votePrice})`);
   console.log(`  Commit hash: ${bytesToHex(commitHash)}`);
@@ -197,8 +197,12 @@ function i128ToLe16Bytes(price) {
   return out;
 }
 
-function computeCommitHash(price, saltBytes32) {
-  const payload = Buffer.concat([i128ToLe16Bytes(price), Buffer.from(saltBytes32)]);
+function computeCommitHash(requestId, price, saltBytes32) {
+  const payload = Buffer.concat([
+    Buffer.from(requestId),
+    i128ToLe16Bytes(price),
+    Buffer.from(saltBytes32),
+  ]);
   return Array.from(createHash('sha256').update(payload).digest());
 }
 
@@ -408,8 +412,8 @@ async function runDisputedBranch(marketId, assertionHex, bondAmount) {
 
   const voter1Salt = Array.from(randomBytes(32));
   const voter2Salt = Array.from(randomBytes(32));
-  const voter1Hash = computeCommitHash(CONFIG.voter1Price, voter1Salt);
-  const voter2Hash = computeCommitHash(CONFIG.voter2Price, voter2Salt);
+  const voter1Hash = computeCommitHash(requestId, CONFIG.voter1Price, voter1Salt);
+  const voter2Hash = computeCommitHash(requestId, CONFIG.voter2Price, voter2Salt);
 
   tx(
     CONFIG.votingToken,
@@ -254,6 +254,15 @@ async function makeAssertion(account, bondAmount, claimText) {
   console.log(`Bond amount: ${bondAmount.toString()} (${Number(bondAmount) / 1e24} tokens)`);
   console.log(`Liveness: 5 minutes (for testing)`);
 
+  // Prepay the storage the assertion uses on the oracle
+  await account.functionCall({
+    contractId: CONFIG.oracleContract,
+    methodName: 'deposit_assertion_storage',
+    args: {},
+    gas: '30000000000000', // 30 TGas
+    attachedDeposit: '10000000000000000000000', // 0.01 NEAR
+  });
+
   const result = await account.functionCall({
     contractId: CONFIG.tokenContract,
     methodName: 'ft_transfer_call',
@@ -420,6 +429,7 @@ async function settleDisputedAssertion(account, assertionId, resolution) {
     args: {
       assertion_id: assertionId,
       resolution: resolution,
+      reason: 'Manual fallback resolution from test-oracle-flow script',
     },
     gas: '100000000000000', // 100 TGas
   });